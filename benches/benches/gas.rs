@@ -0,0 +1,32 @@
+//! Gas snapshot regression check, runnable with `cargo bench -p benches`.
+//!
+//! Compares gas usage against `benches/gas-snapshot.json`, or records it
+//! there if no snapshot exists yet. The allowed increase in percent can be set
+//! with the `GAS_SNAPSHOT_THRESHOLD` environment variable.
+use std::path::Path;
+
+use benches::snapshot::DEFAULT_THRESHOLD_PERCENT;
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let snapshot_path = manifest_dir.join("gas-snapshot.json");
+    let threshold = match std::env::var("GAS_SNAPSHOT_THRESHOLD") {
+        Ok(threshold) => threshold.parse()?,
+        Err(_) => DEFAULT_THRESHOLD_PERCENT,
+    };
+
+    // Contracts are looked up relative to the workspace root.
+    std::env::set_current_dir(manifest_dir.join(".."))?;
+
+    let report = benches::run().await?;
+    println!("{report}");
+
+    let snapshot = report.snapshot();
+    if snapshot_path.exists() {
+        snapshot.check(&snapshot_path, threshold)
+    } else {
+        println!("Saving gas snapshot to {}", snapshot_path.display());
+        snapshot.save(&snapshot_path)
+    }
+}
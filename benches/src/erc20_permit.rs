@@ -0,0 +1,118 @@
+#![allow(clippy::too_many_arguments)]
+use alloy::{
+    network::{AnyNetwork, AnyTransactionReceipt, EthereumWallet},
+    primitives::{keccak256, Address, B256, U256},
+    providers::ProviderBuilder,
+    sol,
+    sol_types::{SolCall, SolType},
+    uint,
+};
+use e2e::{receipt, Account};
+use openzeppelin_stylus::utils::cryptography::eip712::to_typed_data_hash;
+
+use crate::{
+    report::{ContractReport, FunctionReport},
+    Opt,
+};
+
+sol!(
+    #[sol(rpc)]
+    contract Erc20Permit {
+        function permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s) external;
+        function DOMAIN_SEPARATOR() external view returns (bytes32 domainSeparator);
+    }
+);
+
+/// Number of permits signed against each deployment.
+const PERMITS: u64 = 10;
+
+// Wednesday, 1 January 3000 00:00:00
+const DEADLINE: U256 = uint!(32_503_680_000_U256);
+
+const PERMIT_TYPEHASH: [u8; 32] =
+    keccak_const::Keccak256::new()
+        .update(b"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)")
+        .finalize();
+
+type PermitStructHashTuple = sol! {
+    tuple(bytes32, address, address, uint256, uint256, uint256)
+};
+
+pub async fn bench() -> eyre::Result<ContractReport> {
+    ContractReport::generate("Erc20Permit", run).await
+}
+
+/// Compares `PERMITS` permits against a deployment without `constructor.sol`,
+/// whose domain separator is recomputed on each call, and one caching it at
+/// construction.
+pub async fn run(cache_opt: Opt) -> eyre::Result<Vec<FunctionReport>> {
+    let alice = Account::new().await?;
+    let bob = Account::new().await?;
+
+    let recomputed_addr =
+        crate::deploy(&alice, "erc20-permit", None, cache_opt.clone()).await?;
+    let cached_addr =
+        crate::deploy(&alice, "erc20-permit", Some(String::new()), cache_opt)
+            .await?;
+
+    let recomputed = permits(&alice, bob.address(), recomputed_addr).await?;
+    let cached = permits(&alice, bob.address(), cached_addr).await?;
+
+    let sig = Erc20Permit::permitCall::SIGNATURE;
+    Ok(vec![
+        FunctionReport::total(
+            &format!("{sig} x{PERMITS} recomputed domain separator"),
+            &recomputed,
+        )?,
+        FunctionReport::total(
+            &format!("{sig} x{PERMITS} cached domain separator"),
+            &cached,
+        )?,
+    ])
+}
+
+/// Submits `PERMITS` permits signed by `owner` for `spender`.
+async fn permits(
+    owner: &Account,
+    spender: Address,
+    contract_addr: Address,
+) -> eyre::Result<Vec<AnyTransactionReceipt>> {
+    let owner_addr = owner.address();
+    let owner_wallet = ProviderBuilder::new()
+        .network::<AnyNetwork>()
+        .with_recommended_fillers()
+        .wallet(EthereumWallet::from(owner.signer.clone()))
+        .on_http(owner.url().parse()?);
+    let contract = Erc20Permit::new(contract_addr, &owner_wallet);
+
+    let Erc20Permit::DOMAIN_SEPARATORReturn { domainSeparator } =
+        contract.DOMAIN_SEPARATOR().call().await?;
+
+    let mut receipts = Vec::new();
+    for nonce in 0..PERMITS {
+        let value = U256::from(nonce + 1);
+        let struct_hash = keccak256(PermitStructHashTuple::abi_encode(&(
+            PERMIT_TYPEHASH,
+            owner_addr,
+            spender,
+            value,
+            U256::from(nonce),
+            DEADLINE,
+        )));
+        let hash =
+            B256::from(to_typed_data_hash(&domainSeparator, &struct_hash));
+        let signature = owner.sign_hash(&hash).await;
+
+        receipts.push(receipt!(contract.permit(
+            owner_addr,
+            spender,
+            value,
+            DEADLINE,
+            u8::from(signature.v()) + 27,
+            signature.r().into(),
+            signature.s().into()
+        ))?);
+    }
+
+    Ok(receipts)
+}
@@ -1,17 +1,25 @@
-use std::process::Command;
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
 
 use alloy::primitives::Address;
 use alloy_primitives::U128;
 use e2e::{Account, ReceiptExt};
 use eyre::WrapErr;
+use futures::FutureExt;
+use itertools::Itertools;
 use koba::config::{Deploy, Generate, PrivateKey};
 use serde::Deserialize;
 
+use crate::{report::BenchmarkReport, retry::Retry};
+
 pub mod access_control;
 pub mod erc1155;
 pub mod erc1155_metadata_uri;
 pub mod erc1155_supply;
 pub mod erc20;
+pub mod erc20_permit;
 pub mod erc721;
 pub mod merkle_proofs;
 pub mod ownable;
@@ -19,8 +27,40 @@ pub mod poseidon;
 pub mod poseidon_asm_sol;
 pub mod poseidon_sol;
 pub mod report;
+mod retry;
+pub mod snapshot;
 pub mod vesting_wallet;
 
+/// Run all benchmarks and collect their gas usage.
+pub async fn run() -> eyre::Result<BenchmarkReport> {
+    let benchmarks = [
+        access_control::bench().boxed(),
+        erc20::bench().boxed(),
+        erc20_permit::bench().boxed(),
+        erc721::bench().boxed(),
+        merkle_proofs::bench().boxed(),
+        ownable::bench().boxed(),
+        erc1155::bench().boxed(),
+        erc1155_metadata_uri::bench().boxed(),
+        poseidon_sol::bench().boxed(),
+        poseidon_asm_sol::bench().boxed(),
+        poseidon::bench().boxed(),
+    ];
+
+    // Run benchmarks max 3 at the same time.
+    // Otherwise, nitro test node can overload and revert transaction.
+    const MAX_PARALLEL: usize = 3;
+    let mut report = BenchmarkReport::default();
+    for chunk in &benchmarks.into_iter().chunks(MAX_PARALLEL) {
+        report = futures::future::try_join_all(chunk)
+            .await?
+            .into_iter()
+            .fold(report, BenchmarkReport::merge_with);
+    }
+
+    Ok(report)
+}
+
 #[derive(Debug, Deserialize)]
 struct ArbOtherFields {
     #[serde(rename = "gasUsedForL1")]
@@ -32,12 +72,23 @@ struct ArbOtherFields {
 
 /// Optimisation options for the contract.
 ///
-/// Cache or cache optimized WASM.
+/// Cache or cache optimized WASM, bidding `bid` for a cache slot.
 #[derive(Clone)]
 pub enum Opt {
     None,
-    Cache,
-    CacheWasmOpt,
+    Cache { bid: u32 },
+    CacheWasmOpt { bid: u32 },
+}
+
+impl Opt {
+    /// Load the cache bid from the `CACHE_BID` environment variable, `0` if
+    /// unset.
+    pub fn cache_bid() -> eyre::Result<u32> {
+        match std::env::var("CACHE_BID") {
+            Ok(bid) => bid.parse().wrap_err("failed to parse CACHE_BID"),
+            Err(_) => Ok(0),
+        }
+    }
 }
 
 async fn deploy(
@@ -49,20 +100,7 @@ async fn deploy(
     let manifest_dir =
         std::env::current_dir().context("should get current dir from env")?;
 
-    let contract_type = match opt {
-        Opt::CacheWasmOpt => "example_opt",
-        Opt::None | Opt::Cache => "example",
-    };
-
-    let wasm_path = manifest_dir
-        .join("target")
-        .join("wasm32-unknown-unknown")
-        .join("release")
-        .join(format!(
-            "{}_{}.wasm",
-            contract_name.replace('-', "_"),
-            contract_type
-        ));
+    let wasm_path = wasm_path(&manifest_dir, contract_name, &opt)?;
     let sol_path = args.as_ref().map(|_| {
         manifest_dir
             .join("examples")
@@ -90,14 +128,14 @@ async fn deploy(
         quiet: true,
     };
 
-    let address = koba::deploy(&config)
-        .await
-        .expect("should deploy contract")
+    let address = Retry::from_env()?
+        .run("deploying contract", || koba::deploy(&config))
+        .await?
         .address()?;
 
     match opt {
-        Opt::Cache | Opt::CacheWasmOpt => {
-            cache_contract(account, address, 0)?;
+        Opt::Cache { bid } | Opt::CacheWasmOpt { bid } => {
+            cache_contract(account, address, bid)?;
         }
         Opt::None => {}
     }
@@ -105,6 +143,56 @@ async fn deploy(
     Ok(address)
 }
 
+/// Returns the path to the compiled WASM of `contract_name` for `opt`.
+///
+/// Falls back to the non-optimized WASM with a warning, if the optimized one
+/// is requested but was not built.
+///
+/// # Errors
+///
+/// If the WASM to deploy doesn't exist.
+fn wasm_path(
+    manifest_dir: &Path,
+    contract_name: &str,
+    opt: &Opt,
+) -> eyre::Result<PathBuf> {
+    let release_dir = manifest_dir
+        .join("target")
+        .join("wasm32-unknown-unknown")
+        .join("release");
+    let path = |contract_type: &str| {
+        release_dir.join(format!(
+            "{}_{contract_type}.wasm",
+            contract_name.replace('-', "_"),
+        ))
+    };
+
+    let example = path("example");
+    if let Opt::CacheWasmOpt { .. } = opt {
+        let example_opt = path("example_opt");
+        if example_opt.exists() {
+            return Ok(example_opt);
+        }
+        if example.exists() {
+            eprintln!(
+                "warning: {} not found, deploying non-optimized {} instead",
+                example_opt.display(),
+                example.display()
+            );
+            return Ok(example);
+        }
+    }
+
+    eyre::ensure!(
+        example.exists(),
+        "{} not found, build it with `./scripts/bench.sh` or `cargo build \
+         --release --target wasm32-unknown-unknown -Z build-std=std,panic_abort \
+         -Z build-std-features=panic_immediate_abort`",
+        example.display()
+    );
+    Ok(example)
+}
+
 /// Try to cache a contract on the stylus network.
 /// Already cached contracts won't be cached, and this function will not return
 /// an error.
@@ -117,17 +205,119 @@ fn cache_contract(
     // We don't need a status code.
     // Since it is not zero when the contract is already cached.
     Command::new("cargo")
-        .args(["stylus", "cache", "bid"])
-        .args(["-e", &env("RPC_URL")?])
-        .args(["--private-key", &format!("0x{}", account.pk())])
-        .arg(contract_addr.to_string())
-        .arg(bid.to_string())
+        .args(cache_bid_args(
+            &env("RPC_URL")?,
+            &account.pk(),
+            contract_addr,
+            bid,
+        ))
         .status()
         .context("failed to execute `cargo stylus cache bid` command")?;
     Ok(())
 }
 
+/// Arguments of the `cargo stylus cache bid` command.
+fn cache_bid_args(
+    rpc_url: &str,
+    pk: &str,
+    contract_addr: Address,
+    bid: u32,
+) -> Vec<String> {
+    vec![
+        "stylus".to_owned(),
+        "cache".to_owned(),
+        "bid".to_owned(),
+        "-e".to_owned(),
+        rpc_url.to_owned(),
+        "--private-key".to_owned(),
+        format!("0x{pk}"),
+        contract_addr.to_string(),
+        bid.to_string(),
+    ]
+}
+
 /// Load the `name` environment variable.
 fn env(name: &str) -> eyre::Result<String> {
     std::env::var(name).wrap_err(format!("failed to load {name}"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release_dir() -> (PathBuf, PathBuf) {
+        let manifest_dir = std::env::temp_dir()
+            .join(format!("benches-wasm-path-{}", std::process::id()));
+        let release_dir = manifest_dir
+            .join("target")
+            .join("wasm32-unknown-unknown")
+            .join("release");
+        std::fs::create_dir_all(&release_dir).unwrap();
+        (manifest_dir, release_dir)
+    }
+
+    #[test]
+    fn cache_bid_args_include_bid() {
+        let contract_addr = Address::repeat_byte(1);
+        let args =
+            cache_bid_args("http://localhost:8547", "ab", contract_addr, 42);
+
+        assert_eq!(
+            vec![
+                "stylus",
+                "cache",
+                "bid",
+                "-e",
+                "http://localhost:8547",
+                "--private-key",
+                "0xab",
+                &contract_addr.to_string(),
+                "42",
+            ],
+            args
+        );
+    }
+
+    #[test]
+    fn wasm_path_errors_on_missing_file() {
+        let (manifest_dir, _) = release_dir();
+
+        let err = wasm_path(&manifest_dir, "missing-contract", &Opt::None)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("missing_contract_example.wasm not found"));
+        assert!(err.contains("./scripts/bench.sh"));
+
+        let err = wasm_path(
+            &manifest_dir,
+            "missing-contract",
+            &Opt::CacheWasmOpt { bid: 0 },
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("missing_contract_example.wasm not found"));
+    }
+
+    #[test]
+    fn wasm_path_falls_back_to_non_optimized() {
+        let (manifest_dir, release_dir) = release_dir();
+        let example = release_dir.join("fallback_example.wasm");
+        std::fs::write(&example, []).unwrap();
+
+        let path =
+            wasm_path(&manifest_dir, "fallback", &Opt::CacheWasmOpt { bid: 0 })
+                .unwrap();
+        assert_eq!(example, path);
+
+        let example_opt = release_dir.join("fallback_example_opt.wasm");
+        std::fs::write(&example_opt, []).unwrap();
+
+        let path =
+            wasm_path(&manifest_dir, "fallback", &Opt::CacheWasmOpt { bid: 0 })
+                .unwrap();
+        assert_eq!(example_opt, path);
+        let path = wasm_path(&manifest_dir, "fallback", &Opt::Cache { bid: 0 })
+            .unwrap();
+        assert_eq!(example, path);
+    }
+}
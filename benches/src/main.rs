@@ -1,39 +1,31 @@
-use benches::{
-    access_control, erc1155, erc1155_metadata_uri, erc20, erc721,
-    merkle_proofs, ownable, poseidon, poseidon_asm_sol, poseidon_sol,
-    report::BenchmarkReport,
-};
-use futures::FutureExt;
-use itertools::Itertools;
+use benches::snapshot::DEFAULT_THRESHOLD_PERCENT;
 
+/// Usage:
+///
+/// * `cargo run -p benches` - print the gas report.
+/// * `cargo run -p benches -- report <path>` - also save the gas snapshot as
+///   JSON to `<path>`.
+/// * `cargo run -p benches -- check <path> [threshold]` - also fail if any
+///   operation uses more than `threshold` percent (default 5) L2 gas over the
+///   snapshot at `<path>`.
 #[tokio::main]
 async fn main() -> eyre::Result<()> {
-    let benchmarks = [
-        access_control::bench().boxed(),
-        erc20::bench().boxed(),
-        erc721::bench().boxed(),
-        merkle_proofs::bench().boxed(),
-        ownable::bench().boxed(),
-        erc1155::bench().boxed(),
-        erc1155_metadata_uri::bench().boxed(),
-        poseidon_sol::bench().boxed(),
-        poseidon_asm_sol::bench().boxed(),
-        poseidon::bench().boxed(),
-    ];
+    let args: Vec<String> = std::env::args().skip(1).collect();
 
-    // Run benchmarks max 3 at the same time.
-    // Otherwise, nitro test node can overload and revert transaction.
-    const MAX_PARALLEL: usize = 3;
-    let mut report = BenchmarkReport::default();
-    for chunk in &benchmarks.into_iter().chunks(MAX_PARALLEL) {
-        report = futures::future::try_join_all(chunk)
-            .await?
-            .into_iter()
-            .fold(report, BenchmarkReport::merge_with);
-    }
+    let report = benches::run().await?;
 
     println!();
     println!("{report}");
 
-    Ok(())
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [] => Ok(()),
+        ["report", path] => report.snapshot().save(path),
+        ["check", path] => {
+            report.snapshot().check(path, DEFAULT_THRESHOLD_PERCENT)
+        }
+        ["check", path, threshold] => {
+            report.snapshot().check(path, threshold.parse()?)
+        }
+        _ => eyre::bail!("unexpected arguments: {args:?}"),
+    }
 }
@@ -2,7 +2,10 @@ use std::{collections::HashMap, fmt::Display, future::Future};
 
 use alloy::network::AnyTransactionReceipt;
 
-use crate::{ArbOtherFields, Opt};
+use crate::{
+    snapshot::{GasEntry, GasSnapshot},
+    ArbOtherFields, Opt,
+};
 
 const SEPARATOR: &str = "::";
 
@@ -10,6 +13,7 @@ const SEPARATOR: &str = "::";
 pub struct FunctionReport {
     sig: String,
     gas: u128,
+    l1_gas: u128,
 }
 
 impl FunctionReport {
@@ -19,8 +23,26 @@ impl FunctionReport {
         Ok(FunctionReport {
             sig: receipt.0.to_owned(),
             gas: get_l2_gas_used(&receipt.1)?,
+            l1_gas: get_l1_gas_used(&receipt.1)?,
         })
     }
+
+    /// Report the gas used by all `receipts` together, under `sig`.
+    pub(crate) fn total(
+        sig: &str,
+        receipts: &[AnyTransactionReceipt],
+    ) -> eyre::Result<Self> {
+        receipts.iter().try_fold(
+            FunctionReport { sig: sig.to_owned(), gas: 0, l1_gas: 0 },
+            |report, receipt| {
+                Ok(FunctionReport {
+                    gas: report.gas + get_l2_gas_used(receipt)?,
+                    l1_gas: report.l1_gas + get_l1_gas_used(receipt)?,
+                    ..report
+                })
+            },
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -38,17 +60,19 @@ impl ContractReport {
         name: &str,
         run: impl Fn(Opt) -> F,
     ) -> eyre::Result<ContractReport> {
+        let bid = Opt::cache_bid()?;
+
         let reports = run(Opt::None).await?;
         let report = reports
             .into_iter()
             .try_fold(ContractReport::new(name), ContractReport::add)?;
 
-        let cached_reports = run(Opt::Cache).await?;
+        let cached_reports = run(Opt::Cache { bid }).await?;
         let report = cached_reports
             .into_iter()
             .try_fold(report, ContractReport::add_cached)?;
 
-        let wasm_opt_cached_reports = run(Opt::CacheWasmOpt).await?;
+        let wasm_opt_cached_reports = run(Opt::CacheWasmOpt { bid }).await?;
         let report = wasm_opt_cached_reports
             .into_iter()
             .try_fold(report, ContractReport::add_wasm_opt_cached)?;
@@ -65,6 +89,7 @@ impl ContractReport {
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn add(mut self, fn_report: FunctionReport) -> eyre::Result<Self> {
         self.functions.push(fn_report);
         Ok(self)
@@ -129,6 +154,31 @@ impl BenchmarkReport {
         self
     }
 
+    /// Collect gas used by every benchmarked operation, for all optimisation
+    /// options.
+    pub fn snapshot(&self) -> GasSnapshot {
+        let mut snapshot = GasSnapshot::default();
+        for report in &self.0 {
+            let modes = [
+                ("", &report.functions),
+                ("::cached", &report.functions_cached),
+                ("::wasm_opt_cached", &report.functions_wasm_opt_cached),
+            ];
+            for (suffix, functions) in modes {
+                for func in functions {
+                    snapshot.insert(
+                        format!(
+                            "{}{SEPARATOR}{}{suffix}",
+                            report.contract, func.sig
+                        ),
+                        GasEntry { l2_gas: func.gas, l1_gas: func.l1_gas },
+                    );
+                }
+            }
+        }
+        snapshot
+    }
+
     pub fn column_width(
         &self,
         column_value: impl FnMut(&ContractReport) -> usize,
@@ -218,7 +268,11 @@ const BASE_GAS_FEE: u128 = 21_000;
 
 fn get_l2_gas_used(receipt: &AnyTransactionReceipt) -> eyre::Result<u128> {
     let l2_gas = receipt.gas_used;
-    let arb_fields: ArbOtherFields = receipt.other.deserialize_as()?;
-    let l1_gas = arb_fields.gas_used_for_l1.to::<u128>();
+    let l1_gas = get_l1_gas_used(receipt)?;
     Ok(l2_gas - l1_gas - BASE_GAS_FEE)
 }
+
+fn get_l1_gas_used(receipt: &AnyTransactionReceipt) -> eyre::Result<u128> {
+    let arb_fields: ArbOtherFields = receipt.other.deserialize_as()?;
+    Ok(arb_fields.gas_used_for_l1.to::<u128>())
+}
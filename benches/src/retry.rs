@@ -0,0 +1,150 @@
+use std::{future::Future, io, time::Duration};
+
+use alloy::transports::{RpcError, TransportError};
+use eyre::WrapErr;
+
+/// Default number of retries after a failed attempt.
+const DEFAULT_RETRIES: u32 = 3;
+
+/// Delay before the first retry, doubled after each following attempt.
+const BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Bounded exponential backoff for operations failing on flaky RPCs.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Retry {
+    retries: u32,
+    base_delay: Duration,
+}
+
+impl Retry {
+    /// Load the number of retries from the `DEPLOY_RETRIES` environment
+    /// variable, [`DEFAULT_RETRIES`] if unset.
+    pub(crate) fn from_env() -> eyre::Result<Self> {
+        let retries = match std::env::var("DEPLOY_RETRIES") {
+            Ok(retries) => {
+                retries.parse().wrap_err("failed to parse DEPLOY_RETRIES")?
+            }
+            Err(_) => DEFAULT_RETRIES,
+        };
+        Ok(Retry { retries, base_delay: BASE_DELAY })
+    }
+
+    /// Run `op`, retrying it while it fails with a transient error.
+    ///
+    /// Deterministic errors, e.g. bad constructor arguments, are returned
+    /// right away.
+    pub(crate) async fn run<T, F, Fut>(
+        self,
+        what: &str,
+        mut op: F,
+    ) -> eyre::Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = eyre::Result<T>>,
+    {
+        let mut delay = self.base_delay;
+        let mut attempt = 0;
+        loop {
+            match op().await {
+                Ok(value) => return Ok(value),
+                Err(err) if attempt < self.retries && is_transient(&err) => {
+                    attempt += 1;
+                    eprintln!(
+                        "warning: {what} failed, retrying in {delay:?} \
+                         ({attempt}/{}): {err}",
+                        self.retries
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                Err(err) => {
+                    return Err(err).wrap_err(format!(
+                        "{what} failed after {} attempt(s)",
+                        attempt + 1
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Whether `err` is caused by the network rather than by the operation itself.
+fn is_transient(err: &eyre::Report) -> bool {
+    err.chain().any(|cause| {
+        if let Some(err) = cause.downcast_ref::<TransportError>() {
+            return matches!(err, RpcError::Transport(_) | RpcError::NullResp);
+        }
+        if let Some(err) = cause.downcast_ref::<io::Error>() {
+            return matches!(
+                err.kind(),
+                io::ErrorKind::TimedOut
+                    | io::ErrorKind::Interrupted
+                    | io::ErrorKind::ConnectionRefused
+                    | io::ErrorKind::ConnectionReset
+            );
+        }
+        false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use alloy::transports::TransportErrorKind;
+
+    use super::*;
+
+    const RETRY: Retry = Retry { retries: 3, base_delay: Duration::ZERO };
+
+    #[tokio::test]
+    async fn retries_transient_errors() {
+        let attempts = Cell::new(0);
+
+        let result = RETRY
+            .run("deploy", || async {
+                attempts.set(attempts.get() + 1);
+                if attempts.get() <= 2 {
+                    Err(TransportErrorKind::custom_str("connection reset"))?;
+                }
+                Ok(attempts.get())
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(3, result);
+        assert_eq!(3, attempts.get());
+    }
+
+    #[tokio::test]
+    async fn fails_after_exhausting_retries() {
+        let attempts = Cell::new(0);
+
+        let err = RETRY
+            .run("deploy", || async {
+                attempts.set(attempts.get() + 1);
+                Err::<(), _>(TransportErrorKind::custom_str("timed out").into())
+            })
+            .await
+            .unwrap_err();
+
+        assert_eq!(4, attempts.get());
+        assert_eq!("deploy failed after 4 attempt(s)", err.to_string());
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_deterministic_errors() {
+        let attempts = Cell::new(0);
+
+        let err = RETRY
+            .run("deploy", || async {
+                attempts.set(attempts.get() + 1);
+                Err::<(), _>(eyre::eyre!("bad constructor args"))
+            })
+            .await
+            .unwrap_err();
+
+        assert_eq!(1, attempts.get());
+        assert!(format!("{err:?}").contains("bad constructor args"));
+    }
+}
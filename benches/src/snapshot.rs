@@ -0,0 +1,159 @@
+use std::{collections::BTreeMap, fmt::Display, path::Path};
+
+use eyre::WrapErr;
+use serde::{Deserialize, Serialize};
+
+/// Default allowed gas increase over the baseline, in percent.
+pub const DEFAULT_THRESHOLD_PERCENT: u128 = 5;
+
+/// Gas used by a single benchmarked operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasEntry {
+    pub l2_gas: u128,
+    pub l1_gas: u128,
+}
+
+/// Gas usage of all benchmarked operations, keyed by
+/// `Contract::function[::mode]`.
+///
+/// Only L2 gas is compared against the baseline, since L1 gas depends on the
+/// L1 pricing of the node and is recorded for reference only.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasSnapshot(BTreeMap<String, GasEntry>);
+
+impl GasSnapshot {
+    pub fn insert(&mut self, key: String, entry: GasEntry) {
+        self.0.insert(key, entry);
+    }
+
+    /// Load a snapshot from the JSON file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).wrap_err(format!(
+            "failed to read gas snapshot at {}",
+            path.display()
+        ))?;
+        serde_json::from_str(&json).wrap_err(format!(
+            "failed to parse gas snapshot at {}",
+            path.display()
+        ))
+    }
+
+    /// Save the snapshot as a JSON file at `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> eyre::Result<()> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json + "\n").wrap_err(format!(
+            "failed to write gas snapshot to {}",
+            path.display()
+        ))
+    }
+
+    /// Returns operations of `self` whose L2 gas exceeds the one recorded in
+    /// `baseline` by more than `threshold_percent`.
+    ///
+    /// Operations missing from `baseline` are not compared.
+    pub fn regressions(
+        &self,
+        baseline: &GasSnapshot,
+        threshold_percent: u128,
+    ) -> Vec<Regression> {
+        self.0
+            .iter()
+            .filter_map(|(key, entry)| {
+                let expected = baseline.0.get(key)?.l2_gas;
+                let actual = entry.l2_gas;
+                (actual * 100 > expected * (100 + threshold_percent))
+                    .then(|| Regression { key: key.clone(), expected, actual })
+            })
+            .collect()
+    }
+
+    /// Compare `self` against the baseline at `path`, failing if any operation
+    /// regressed by more than `threshold_percent`.
+    pub fn check(
+        &self,
+        path: impl AsRef<Path>,
+        threshold_percent: u128,
+    ) -> eyre::Result<()> {
+        let path = path.as_ref();
+        let regressions =
+            self.regressions(&Self::load(path)?, threshold_percent);
+        for regression in &regressions {
+            println!("{regression}");
+        }
+        eyre::ensure!(
+            regressions.is_empty(),
+            "{} operation(s) exceeded the gas snapshot at {} by more than {threshold_percent}%",
+            regressions.len(),
+            path.display()
+        );
+        Ok(())
+    }
+}
+
+/// Operation that used more gas than allowed by the baseline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub key: String,
+    pub expected: u128,
+    pub actual: u128,
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Regression { key, expected, actual } = self;
+        write!(f, "{key}: {actual} L2 gas, baseline is {expected}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(l2_gas: u128) -> GasSnapshot {
+        let mut snapshot = GasSnapshot::default();
+        snapshot.insert(
+            "Erc20::transfer".to_owned(),
+            GasEntry { l2_gas, l1_gas: 7 },
+        );
+        snapshot
+    }
+
+    #[test]
+    fn flags_regression_above_threshold() {
+        let baseline = snapshot(1000);
+
+        assert!(snapshot(1050).regressions(&baseline, 5).is_empty());
+        assert!(snapshot(900).regressions(&baseline, 5).is_empty());
+
+        let regressions = snapshot(1051).regressions(&baseline, 5);
+        assert_eq!(
+            vec![Regression {
+                key: "Erc20::transfer".to_owned(),
+                expected: 1000,
+                actual: 1051,
+            }],
+            regressions
+        );
+    }
+
+    #[test]
+    fn ignores_operations_missing_from_baseline() {
+        assert!(snapshot(1000)
+            .regressions(&GasSnapshot::default(), 0)
+            .is_empty());
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let path = std::env::temp_dir().join("benches-gas-snapshot.json");
+        let expected = snapshot(1000);
+
+        expected.save(&path).unwrap();
+        let actual = GasSnapshot::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -9,8 +9,9 @@
 //! `pub const` hash digests:
 //!
 //! ```no_run
-//! pub const MY_ROLE: [u8; 32] =
-//!     keccak_const::Keccak256::new().update(b"MY_ROLE").finalize();
+//! use openzeppelin_stylus::access::control::AccessControl;
+//!
+//! pub const MY_ROLE: [u8; 32] = AccessControl::role_id("MY_ROLE");
 //! ```
 //!
 //! Roles can be used to represent a set of permissions. To restrict access to a
@@ -46,12 +47,15 @@ use openzeppelin_stylus_proc::interface_id;
 pub use sol::*;
 use stylus_sdk::{
     call::MethodError,
-    evm, msg,
+    evm,
     prelude::*,
     storage::{StorageBool, StorageFixedBytes, StorageMap},
 };
 
-use crate::utils::introspection::erc165::{Erc165, IErc165};
+use crate::utils::{
+    introspection::erc165::{Erc165, IErc165},
+    Context,
+};
 
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod sol {
@@ -150,7 +154,7 @@ pub trait IAccessControl {
     /// * `account` - The account to check for membership.
     fn has_role(&self, role: B256, account: Address) -> bool;
 
-    /// Checks if [`msg::sender`] has been granted `role`.
+    /// Checks if [`Context::_msg_sender`] has been granted `role`.
     ///
     /// # Arguments
     ///
@@ -159,8 +163,8 @@ pub trait IAccessControl {
     ///
     /// # Errors
     ///
-    /// * [`Error::UnauthorizedAccount`] - If [`msg::sender`] has not been
-    ///   granted `role`.
+    /// * [`Error::UnauthorizedAccount`] - If [`Context::_msg_sender`] has not
+    ///   been granted `role`.
     fn only_role(&self, role: B256) -> Result<(), Self::Error>;
 
     /// Returns the admin role that controls `role`. See
@@ -187,8 +191,8 @@ pub trait IAccessControl {
     ///
     /// # Errors
     ///
-    /// * [`Error::UnauthorizedAccount`] - If [`msg::sender`] has not been
-    ///   granted `role`.
+    /// * [`Error::UnauthorizedAccount`] - If [`Context::_msg_sender`] has not
+    ///   been granted `role`.
     ///
     /// # Events
     ///
@@ -211,8 +215,8 @@ pub trait IAccessControl {
     ///
     /// # Errors
     ///
-    /// * [`Error::UnauthorizedAccount`] - If [`msg::sender`] has not been
-    ///   granted `role`.
+    /// * [`Error::UnauthorizedAccount`] - If [`Context::_msg_sender`] has not
+    ///   been granted `role`.
     ///
     /// # Events
     ///
@@ -238,7 +242,7 @@ pub trait IAccessControl {
     ///
     /// # Errors
     ///
-    /// * [`Error::BadConfirmation`]  - If [`msg::sender`] is not the
+    /// * [`Error::BadConfirmation`]  - If [`Context::_msg_sender`] is not the
     ///   `confirmation` address.
     ///
     /// # Events
@@ -260,7 +264,7 @@ impl IAccessControl for AccessControl {
     }
 
     fn only_role(&self, role: B256) -> Result<(), Self::Error> {
-        self._check_role(role, msg::sender())
+        self._check_role(role, self._msg_sender())
     }
 
     fn get_role_admin(&self, role: B256) -> B256 {
@@ -272,10 +276,7 @@ impl IAccessControl for AccessControl {
         role: B256,
         account: Address,
     ) -> Result<(), Self::Error> {
-        let admin_role = self.get_role_admin(role);
-        self.only_role(admin_role)?;
-        self._grant_role(role, account);
-        Ok(())
+        self.grant_role_with_sender(self._msg_sender(), role, account)
     }
 
     fn revoke_role(
@@ -283,10 +284,7 @@ impl IAccessControl for AccessControl {
         role: B256,
         account: Address,
     ) -> Result<(), Self::Error> {
-        let admin_role = self.get_role_admin(role);
-        self.only_role(admin_role)?;
-        self._revoke_role(role, account);
-        Ok(())
+        self.revoke_role_with_sender(self._msg_sender(), role, account)
     }
 
     fn renounce_role(
@@ -294,14 +292,7 @@ impl IAccessControl for AccessControl {
         role: B256,
         confirmation: Address,
     ) -> Result<(), Self::Error> {
-        if msg::sender() != confirmation {
-            return Err(Error::BadConfirmation(
-                AccessControlBadConfirmation {},
-            ));
-        }
-
-        self._revoke_role(role, confirmation);
-        Ok(())
+        self.renounce_role_with_sender(self._msg_sender(), role, confirmation)
     }
 }
 
@@ -309,6 +300,17 @@ impl AccessControl {
     /// The default admin role. `[0; 32]` by default.
     pub const DEFAULT_ADMIN_ROLE: [u8; 32] = [0; 32];
 
+    /// Returns the identifier of the role `name`, i.e. `keccak256(name)`, the
+    /// same as Solidity's `keccak256("NAME")` role constants.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the role.
+    #[must_use]
+    pub const fn role_id(name: &str) -> [u8; 32] {
+        keccak_const::Keccak256::new().update(name.as_bytes()).finalize()
+    }
+
     /// Sets `admin_role` as `role`'s admin role.
     ///
     /// # Arguments
@@ -340,8 +342,8 @@ impl AccessControl {
     ///
     /// # Errors
     ///
-    /// * [`Error::UnauthorizedAccount`] - If [`msg::sender`] has not been
-    ///   granted `role`.
+    /// * [`Error::UnauthorizedAccount`] - If `account` has not been granted
+    ///   `role`.
     pub fn _check_role(
         &self,
         role: B256,
@@ -371,13 +373,7 @@ impl AccessControl {
     ///
     /// * [`RoleGranted`].
     pub fn _grant_role(&mut self, role: B256, account: Address) -> bool {
-        if self.has_role(role, account) {
-            false
-        } else {
-            self.roles.setter(role).has_role.insert(account, true);
-            evm::log(RoleGranted { role, account, sender: msg::sender() });
-            true
-        }
+        self.grant(role, account, self._msg_sender())
     }
 
     /// Attempts to revoke `role` from `account` and returns a boolean
@@ -395,9 +391,125 @@ impl AccessControl {
     ///
     /// * [`RoleRevoked`].
     pub fn _revoke_role(&mut self, role: B256, account: Address) -> bool {
+        self.revoke(role, account, self._msg_sender())
+    }
+
+    /// Same as [`IAccessControl::grant_role`], acting for `sender` rather
+    /// than [`Context::_msg_sender`].
+    ///
+    /// A contract overriding [`Context`] forwards its own `grant_role` here
+    /// with the resolved sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account granting the role.
+    /// * `role` - The role identifier.
+    /// * `account` - The account which will be granted the role.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedAccount`] - If `sender` has not been granted the
+    ///   admin role of `role`.
+    ///
+    /// # Events
+    ///
+    /// * [`RoleGranted`].
+    pub fn grant_role_with_sender(
+        &mut self,
+        sender: Address,
+        role: B256,
+        account: Address,
+    ) -> Result<(), Error> {
+        self._check_role(self.get_role_admin(role), sender)?;
+        self.grant(role, account, sender);
+        Ok(())
+    }
+
+    /// Same as [`IAccessControl::revoke_role`], acting for `sender` rather
+    /// than [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account revoking the role.
+    /// * `role` - The role identifier.
+    /// * `account` - The account which will be revoked the role.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::UnauthorizedAccount`] - If `sender` has not been granted the
+    ///   admin role of `role`.
+    ///
+    /// # Events
+    ///
+    /// * [`RoleRevoked`].
+    pub fn revoke_role_with_sender(
+        &mut self,
+        sender: Address,
+        role: B256,
+        account: Address,
+    ) -> Result<(), Error> {
+        self._check_role(self.get_role_admin(role), sender)?;
+        self.revoke(role, account, sender);
+        Ok(())
+    }
+
+    /// Same as [`IAccessControl::renounce_role`], acting for `sender` rather
+    /// than [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account renouncing the role.
+    /// * `role` - The role identifier.
+    /// * `confirmation` - The account renouncing the role, i.e. `sender`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::BadConfirmation`] - If `sender` is not the `confirmation`
+    ///   address.
+    ///
+    /// # Events
+    ///
+    /// * [`RoleRevoked`].
+    pub fn renounce_role_with_sender(
+        &mut self,
+        sender: Address,
+        role: B256,
+        confirmation: Address,
+    ) -> Result<(), Error> {
+        if sender != confirmation {
+            return Err(Error::BadConfirmation(
+                AccessControlBadConfirmation {},
+            ));
+        }
+
+        self.revoke(role, confirmation, sender);
+        Ok(())
+    }
+
+    /// Grants `role` to `account` on behalf of `sender`, if not granted yet.
+    fn grant(&mut self, role: B256, account: Address, sender: Address) -> bool {
+        if self.has_role(role, account) {
+            false
+        } else {
+            self.roles.setter(role).has_role.insert(account, true);
+            evm::log(RoleGranted { role, account, sender });
+            true
+        }
+    }
+
+    /// Revokes `role` from `account` on behalf of `sender`, if granted.
+    fn revoke(
+        &mut self,
+        role: B256,
+        account: Address,
+        sender: Address,
+    ) -> bool {
         if self.has_role(role, account) {
             self.roles.setter(role).has_role.insert(account, false);
-            evm::log(RoleRevoked { role, account, sender: msg::sender() });
+            evm::log(RoleRevoked { role, account, sender });
             true
         } else {
             false
@@ -405,6 +517,8 @@ impl AccessControl {
     }
 }
 
+impl Context for AccessControl {}
+
 impl IErc165 for AccessControl {
     fn supports_interface(interface_id: FixedBytes<4>) -> bool {
         <Self as IAccessControl>::INTERFACE_ID
@@ -413,13 +527,48 @@ impl IErc165 for AccessControl {
     }
 }
 
+/// Solidity interface of an [`AccessControl`] contract, to query the roles
+/// granted by another contract, e.g. a shared access-control hub:
+///
+/// ```rust,ignore
+/// use openzeppelin_stylus::access::control::interface::IAccessControl as IAuthority;
+///
+/// fn only_authorized(&mut self) -> Result<(), Error> {
+///     let authority = IAuthority::new(self.authority.get());
+///     let authorized = authority
+///         .has_role(Call::new_in(self), MY_ROLE.into(), msg::sender())
+///         .map_err(|_| Error::InvalidAuthority(InvalidAuthority {}))?;
+///     // ...
+/// }
+/// ```
+pub mod interface {
+    #![allow(missing_docs)]
+    #![cfg_attr(coverage_nightly, coverage(off))]
+
+    use alloc::vec;
+
+    use stylus_sdk::prelude::sol_interface;
+
+    sol_interface! {
+        /// Solidity interface of the role queries of an [`super::AccessControl`]
+        /// contract.
+        interface IAccessControl {
+            function hasRole(bytes32 role, address account) external view returns (bool);
+            function getRoleAdmin(bytes32 role) external view returns (bytes32);
+        }
+    }
+}
+
 #[cfg(all(test, feature = "std"))]
 mod tests {
-    use alloy_primitives::Address;
+    use alloy_primitives::{Address, B256};
     use motsu::prelude::Contract;
-    use stylus_sdk::prelude::TopLevelStorage;
+    use stylus_sdk::{call::Call, prelude::*, storage::StorageAddress};
 
-    use super::{AccessControl, Error, IAccessControl};
+    use super::{
+        interface, AccessControl, Error, IAccessControl, RoleGranted,
+        RoleRevoked,
+    };
     use crate::utils::introspection::erc165::IErc165;
 
     /// Shorthand for declaring variables converted from a hex literal to a
@@ -441,6 +590,30 @@ mod tests {
 
     unsafe impl TopLevelStorage for AccessControl {}
 
+    #[test]
+    fn default_admin_role_is_zero() {
+        assert_eq!(DEFAULT_ADMIN_ROLE, [0; 32]);
+        assert!(B256::from(DEFAULT_ADMIN_ROLE).is_zero());
+    }
+
+    #[test]
+    fn role_id_is_keccak_of_name() {
+        assert_eq!(
+            AccessControl::role_id("MINTER_ROLE"),
+            alloy_primitives::hex!(
+                "9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6"
+            )
+        );
+        assert_eq!(
+            AccessControl::role_id("PAUSER_ROLE"),
+            *alloy_primitives::keccak256("PAUSER_ROLE")
+        );
+        assert_ne!(
+            AccessControl::role_id("MINTER_ROLE"),
+            AccessControl::role_id("minter_role")
+        );
+    }
+
     #[motsu::test]
     fn default_role_is_default_admin(
         contract: Contract<AccessControl>,
@@ -725,6 +898,125 @@ mod tests {
         assert!(!role_revoked);
     }
 
+    #[motsu::test]
+    fn granting_held_role_emits_no_event(
+        contract: Contract<AccessControl>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract.sender(alice)._grant_role(DEFAULT_ADMIN_ROLE.into(), alice);
+        contract.sender(alice)._grant_role(DEFAULT_ADMIN_ROLE.into(), charlie);
+
+        contract.sender(alice).grant_role(ROLE.into(), bob).unwrap();
+        contract.assert_emitted(&RoleGranted {
+            role: ROLE.into(),
+            account: bob,
+            sender: alice,
+        });
+
+        // Granting the role again changes nothing, and emits no event.
+        contract.sender(charlie).grant_role(ROLE.into(), bob).unwrap();
+        assert!(!contract.emitted(&RoleGranted {
+            role: ROLE.into(),
+            account: bob,
+            sender: charlie,
+        }));
+        assert!(!contract.sender(charlie)._grant_role(ROLE.into(), bob));
+        assert!(!contract.emitted(&RoleGranted {
+            role: ROLE.into(),
+            account: bob,
+            sender: charlie,
+        }));
+    }
+
+    #[motsu::test]
+    fn revoking_missing_role_emits_no_event(
+        contract: Contract<AccessControl>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract.sender(alice)._grant_role(DEFAULT_ADMIN_ROLE.into(), alice);
+        contract.sender(alice)._grant_role(DEFAULT_ADMIN_ROLE.into(), charlie);
+        contract.sender(alice)._grant_role(ROLE.into(), bob);
+
+        contract.sender(alice).revoke_role(ROLE.into(), bob).unwrap();
+        contract.assert_emitted(&RoleRevoked {
+            role: ROLE.into(),
+            account: bob,
+            sender: alice,
+        });
+
+        // Revoking the role again changes nothing, and emits no event.
+        contract.sender(charlie).revoke_role(ROLE.into(), bob).unwrap();
+        assert!(!contract.sender(charlie)._revoke_role(ROLE.into(), bob));
+        assert!(!contract.emitted(&RoleRevoked {
+            role: ROLE.into(),
+            account: bob,
+            sender: charlie,
+        }));
+    }
+
+    /// Contract gating `can_call` on the roles granted by an `authority`
+    /// [`AccessControl`] contract.
+    #[storage]
+    struct RoleGated {
+        authority: StorageAddress,
+    }
+
+    unsafe impl TopLevelStorage for RoleGated {}
+
+    #[public]
+    impl RoleGated {
+        fn can_call(&mut self, account: Address) -> Result<bool, Vec<u8>> {
+            let authority =
+                interface::IAccessControl::new(self.authority.get());
+            authority
+                .has_role(Call::new_in(self), ROLE.into(), account)
+                .map_err(|_| Vec::new())
+        }
+
+        fn role_admin(&mut self, role: B256) -> Result<B256, Vec<u8>> {
+            let authority =
+                interface::IAccessControl::new(self.authority.get());
+            authority
+                .get_role_admin(Call::new_in(self), role)
+                .map_err(|_| Vec::new())
+        }
+    }
+
+    #[motsu::test]
+    fn queries_roles_of_another_contract(
+        authority: Contract<AccessControl>,
+        gated: Contract<RoleGated>,
+        alice: Address,
+        bob: Address,
+    ) {
+        authority.init(alice, |contract| {
+            contract._grant_role(ROLE.into(), alice);
+            contract._set_role_admin(ROLE.into(), OTHER_ROLE.into());
+        });
+        gated.init(alice, |contract| {
+            contract.authority.set(authority.address());
+        });
+
+        assert!(gated.sender(bob).can_call(alice).unwrap());
+        assert!(!gated.sender(bob).can_call(bob).unwrap());
+        assert_eq!(
+            B256::from(OTHER_ROLE),
+            gated.sender(bob).role_admin(ROLE.into()).unwrap()
+        );
+        assert_eq!(
+            B256::from(DEFAULT_ADMIN_ROLE),
+            gated.sender(bob).role_admin(OTHER_ROLE.into()).unwrap()
+        );
+
+        // Roles granted later on the authority are seen by the gated contract.
+        authority.sender(alice)._grant_role(ROLE.into(), bob);
+        assert!(gated.sender(bob).can_call(bob).unwrap());
+    }
+
     #[motsu::test]
     fn interface_id() {
         let actual = <AccessControl as IAccessControl>::INTERFACE_ID;
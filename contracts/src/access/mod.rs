@@ -2,3 +2,4 @@
 pub mod control;
 pub mod ownable;
 pub mod ownable_two_step;
+pub mod roles;
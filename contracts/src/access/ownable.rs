@@ -8,6 +8,9 @@
 //! This module is used through inheritance. It will make available the
 //! [`Ownable::only_owner`] function, which can be called to restrict operations
 //! to the owner.
+//!
+//! Behind a proxy, the initial owner is set with [`Ownable::_init`] instead,
+//! from the initialization function of the contract.
 use alloc::{vec, vec::Vec};
 
 use alloy_primitives::{Address, FixedBytes};
@@ -17,7 +20,10 @@ use stylus_sdk::{
     call::MethodError, evm, msg, prelude::*, storage::StorageAddress,
 };
 
-use crate::utils::introspection::erc165::{Erc165, IErc165};
+use crate::utils::{
+    initializable::{Initializable, NotInitializing},
+    introspection::erc165::{Erc165, IErc165},
+};
 
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod sol {
@@ -56,6 +62,8 @@ pub enum Error {
     UnauthorizedAccount(OwnableUnauthorizedAccount),
     /// The owner is not a valid owner account. (eg. `Address::ZERO`)
     InvalidOwner(OwnableInvalidOwner),
+    /// The contract is not initializing.
+    NotInitializing(NotInitializing),
 }
 
 impl MethodError for Error {
@@ -87,6 +95,11 @@ pub trait IOwnable {
     /// Transfers ownership of the contract to a new account (`new_owner`).
     /// Can only be called by the current owner.
     ///
+    /// Transferring to `Address::ZERO` is rejected, use
+    /// [`Self::renounce_ownership`] to leave the contract without owner.
+    /// Transferring to the current owner keeps it as the owner and still emits
+    /// [`OwnershipTransferred`].
+    ///
     /// # Arguments
     ///
     /// * `&mut self` - Write access to the contract's state.
@@ -95,6 +108,7 @@ pub trait IOwnable {
     /// # Errors
     ///
     /// * [`OwnableInvalidOwner`] - If `new_owner` is the `Address::ZERO`.
+    /// * [`Error::UnauthorizedAccount`] - If not called by the owner.
     ///
     /// # Events
     ///
@@ -179,6 +193,46 @@ impl Ownable {
         Ok(())
     }
 
+    /// Sets the initial owner of the contract to `initial_owner`.
+    ///
+    /// Must be called from within [`Initializable::initializer`], which
+    /// guarantees that the initial owner is only set once.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `initializable` - Read access to the contract's [`Initializable`]
+    ///   state.
+    /// * `initial_owner` - The first owner of this contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NotInitializing`] - If the contract is not being initialized.
+    /// * [`Error::InvalidOwner`] - If `initial_owner` is the `Address::ZERO`.
+    ///
+    /// # Events
+    ///
+    /// * [`OwnershipTransferred`].
+    pub fn _init(
+        &mut self,
+        initializable: &Initializable,
+        initial_owner: Address,
+    ) -> Result<(), Error> {
+        // `only_initializing` only fails with `NotInitializing`.
+        initializable
+            .only_initializing()
+            .map_err(|_| Error::NotInitializing(NotInitializing {}))?;
+
+        if initial_owner.is_zero() {
+            return Err(Error::InvalidOwner(OwnableInvalidOwner {
+                owner: Address::ZERO,
+            }));
+        }
+
+        self._transfer_ownership(initial_owner);
+        Ok(())
+    }
+
     /// Transfers ownership of the contract to a new account (`new_owner`).
     /// Internal function without access restriction.
     ///
@@ -207,11 +261,16 @@ impl IErc165 for Ownable {
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use alloy_primitives::Address;
-    use motsu::prelude::Contract;
-    use stylus_sdk::prelude::TopLevelStorage;
+    use motsu::prelude::*;
+    use stylus_sdk::prelude::*;
 
-    use super::{Error, IOwnable, Ownable};
-    use crate::utils::introspection::erc165::IErc165;
+    use super::{
+        Error, IOwnable, Ownable, OwnableInvalidOwner, OwnershipTransferred,
+    };
+    use crate::utils::{
+        initializable::{self, Initializable},
+        introspection::erc165::IErc165,
+    };
 
     unsafe impl TopLevelStorage for Ownable {}
 
@@ -264,6 +323,65 @@ mod tests {
         assert!(matches!(err, Error::InvalidOwner(_)));
     }
 
+    #[motsu::test]
+    fn keeps_owner_when_transferring_to_zero(
+        contract: Contract<Ownable>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| contract.owner.set(alice));
+
+        let err = contract
+            .sender(alice)
+            .transfer_ownership(Address::ZERO)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidOwner(OwnableInvalidOwner { owner })
+                if owner.is_zero()
+        ));
+        assert_eq!(alice, contract.sender(alice).owner());
+    }
+
+    #[motsu::test]
+    fn emits_ownership_transferred(
+        contract: Contract<Ownable>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| contract.owner.set(alice));
+
+        contract
+            .sender(alice)
+            .transfer_ownership(bob)
+            .expect("should transfer ownership");
+        contract.assert_emitted(&OwnershipTransferred {
+            previous_owner: alice,
+            new_owner: bob,
+        });
+
+        // The previous owner lost access.
+        let err = contract.sender(alice).transfer_ownership(alice).unwrap_err();
+        assert!(matches!(err, Error::UnauthorizedAccount(_)));
+    }
+
+    #[motsu::test]
+    fn transfers_ownership_to_current_owner(
+        contract: Contract<Ownable>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| contract.owner.set(alice));
+
+        contract
+            .sender(alice)
+            .transfer_ownership(alice)
+            .expect("should transfer ownership to current owner");
+        assert_eq!(alice, contract.sender(alice).owner());
+        contract.assert_emitted(&OwnershipTransferred {
+            previous_owner: alice,
+            new_owner: alice,
+        });
+    }
+
     #[motsu::test]
     fn loses_ownership_after_renouncing(
         contract: Contract<Ownable>,
@@ -279,6 +397,23 @@ mod tests {
         assert_eq!(owner, Address::ZERO);
     }
 
+    #[motsu::test]
+    fn emits_ownership_transferred_when_renouncing(
+        contract: Contract<Ownable>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| contract.owner.set(alice));
+
+        contract
+            .sender(alice)
+            .renounce_ownership()
+            .expect("should renounce ownership");
+        contract.assert_emitted(&OwnershipTransferred {
+            previous_owner: alice,
+            new_owner: Address::ZERO,
+        });
+    }
+
     #[motsu::test]
     fn prevents_non_owners_from_renouncing(
         contract: Contract<Ownable>,
@@ -304,6 +439,82 @@ mod tests {
         assert_eq!(owner, bob);
     }
 
+    #[derive(SolidityError, Debug)]
+    enum InitError {
+        Initializable(initializable::Error),
+        Ownable(Error),
+    }
+
+    /// Ownable contract meant to be deployed behind a proxy.
+    #[storage]
+    struct OwnableUpgradeable {
+        initializable: Initializable,
+        ownable: Ownable,
+    }
+
+    unsafe impl TopLevelStorage for OwnableUpgradeable {}
+
+    #[public]
+    impl OwnableUpgradeable {
+        fn initialize(&mut self, owner: Address) -> Result<(), InitError> {
+            self.initializable.initializer(|initializable| {
+                self.ownable._init(initializable, owner)?;
+                Ok(())
+            })
+        }
+    }
+
+    #[motsu::test]
+    fn initializes_owner_once(
+        contract: Contract<OwnableUpgradeable>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice).initialize(alice).motsu_unwrap();
+
+        assert_eq!(alice, contract.sender(alice).ownable.owner());
+        contract.assert_emitted(&OwnershipTransferred {
+            previous_owner: Address::ZERO,
+            new_owner: alice,
+        });
+
+        let err = contract.sender(bob).initialize(bob).motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            InitError::Initializable(
+                initializable::Error::InvalidInitialization(_)
+            )
+        ));
+        assert_eq!(alice, contract.sender(alice).ownable.owner());
+    }
+
+    #[motsu::test]
+    fn init_rejects_zero_owner(
+        contract: Contract<OwnableUpgradeable>,
+        alice: Address,
+    ) {
+        let err =
+            contract.sender(alice).initialize(Address::ZERO).motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            InitError::Ownable(Error::InvalidOwner(OwnableInvalidOwner {
+                owner
+            })) if owner.is_zero()
+        ));
+    }
+
+    #[motsu::test]
+    fn init_requires_initializing(
+        contract: Contract<OwnableUpgradeable>,
+        alice: Address,
+    ) {
+        let err = contract.init(alice, |contract| {
+            contract.ownable._init(&contract.initializable, alice)
+        });
+        assert!(matches!(err, Err(Error::NotInitializing(_))));
+        assert_eq!(Address::ZERO, contract.sender(alice).ownable.owner());
+    }
+
     #[motsu::test]
     fn interface_id() {
         let actual = <Ownable as IOwnable>::INTERFACE_ID;
@@ -0,0 +1,350 @@
+//! Reusable roles built on top of [`AccessControl`].
+//!
+//! [`MinterRole`] gates minting functions behind [`MINTER_ROLE`]:
+//!
+//! ```rust,ignore
+//! fn mint(&mut self, account: Address, value: U256) -> Result<(), Error> {
+//!     self.minter.only_minter()?;
+//!     self.erc20._mint(account, value)?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! NOTE: Minters should only be managed through [`IMinterRole`] functions,
+//! since granting or revoking [`MINTER_ROLE`] directly on [`AccessControl`]
+//! bypasses the minters count.
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use openzeppelin_stylus_proc::interface_id;
+pub use sol::*;
+use stylus_sdk::{evm, msg, prelude::*, storage::StorageU256};
+
+use crate::{
+    access::control::{self, AccessControl, IAccessControl},
+    utils::introspection::erc165::{Erc165, IErc165},
+};
+
+/// Role allowed to mint tokens.
+pub const MINTER_ROLE: [u8; 32] = AccessControl::role_id("MINTER_ROLE");
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when the last minter `account` lost [`super::MINTER_ROLE`],
+        /// leaving no account able to mint.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event LastMinterRemoved(address indexed account);
+    }
+}
+
+/// State of a [`MinterRole`] contract.
+#[storage]
+pub struct MinterRole {
+    /// [`AccessControl`] contract.
+    pub access_control: AccessControl,
+    /// Number of accounts with [`MINTER_ROLE`].
+    pub(crate) minters_count: StorageU256,
+}
+
+/// Interface for a [`MinterRole`] contract.
+#[interface_id]
+pub trait IMinterRole {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Returns `true` if `account` has been granted [`MINTER_ROLE`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - The account to check for membership.
+    fn is_minter(&self, account: Address) -> bool;
+
+    /// Checks if [`msg::sender`] has been granted [`MINTER_ROLE`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// * [`control::Error::UnauthorizedAccount`] - If [`msg::sender`] is not a
+    ///   minter.
+    fn only_minter(&self) -> Result<(), Self::Error>;
+
+    /// Grants [`MINTER_ROLE`] to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - The account which will be granted the role.
+    ///
+    /// # Errors
+    ///
+    /// * [`control::Error::UnauthorizedAccount`] - If [`msg::sender`] has not
+    ///   been granted the admin role of [`MINTER_ROLE`].
+    ///
+    /// # Events
+    ///
+    /// * [`control::RoleGranted`].
+    fn grant_minter(&mut self, account: Address) -> Result<(), Self::Error>;
+
+    /// Revokes [`MINTER_ROLE`] from `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - The account which will be revoked the role.
+    ///
+    /// # Errors
+    ///
+    /// * [`control::Error::UnauthorizedAccount`] - If [`msg::sender`] has not
+    ///   been granted the admin role of [`MINTER_ROLE`].
+    ///
+    /// # Events
+    ///
+    /// * [`control::RoleRevoked`].
+    /// * [`LastMinterRemoved`] - If `account` was the last minter.
+    fn revoke_minter(&mut self, account: Address) -> Result<(), Self::Error>;
+
+    /// Revokes [`MINTER_ROLE`] from the calling account.
+    ///
+    /// Renouncing the role as the last minter is allowed, and leaves no
+    /// account able to mint.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    ///
+    /// # Events
+    ///
+    /// * [`control::RoleRevoked`].
+    /// * [`LastMinterRemoved`] - If the caller was the last minter.
+    fn renounce_minter(&mut self);
+}
+
+#[public]
+impl IMinterRole for MinterRole {
+    type Error = control::Error;
+
+    fn is_minter(&self, account: Address) -> bool {
+        self.access_control.has_role(MINTER_ROLE.into(), account)
+    }
+
+    fn only_minter(&self) -> Result<(), Self::Error> {
+        self.access_control.only_role(MINTER_ROLE.into())
+    }
+
+    fn grant_minter(&mut self, account: Address) -> Result<(), Self::Error> {
+        self.only_minter_admin()?;
+        self._grant_minter(account);
+        Ok(())
+    }
+
+    fn revoke_minter(&mut self, account: Address) -> Result<(), Self::Error> {
+        self.only_minter_admin()?;
+        self._revoke_minter(account);
+        Ok(())
+    }
+
+    fn renounce_minter(&mut self) {
+        self._revoke_minter(msg::sender());
+    }
+}
+
+impl MinterRole {
+    /// Checks if [`msg::sender`] has been granted the admin role of
+    /// [`MINTER_ROLE`].
+    fn only_minter_admin(&self) -> Result<(), control::Error> {
+        let admin_role = self.access_control.get_role_admin(MINTER_ROLE.into());
+        self.access_control.only_role(admin_role)
+    }
+
+    /// Attempts to grant [`MINTER_ROLE`] to `account` and returns a boolean
+    /// indicating if the role was granted.
+    ///
+    /// Internal function without access restriction.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - The account which will be granted the role.
+    ///
+    /// # Events
+    ///
+    /// * [`control::RoleGranted`].
+    pub fn _grant_minter(&mut self, account: Address) -> bool {
+        let granted =
+            self.access_control._grant_role(MINTER_ROLE.into(), account);
+        if granted {
+            // Overflow not possible: there are fewer accounts than `U256::MAX`.
+            let count = self.minters_count.get();
+            self.minters_count.set(count + U256::from(1));
+        }
+        granted
+    }
+
+    /// Attempts to revoke [`MINTER_ROLE`] from `account` and returns a boolean
+    /// indicating if the role was revoked.
+    ///
+    /// Internal function without access restriction.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - The account which will be revoked the role.
+    ///
+    /// # Events
+    ///
+    /// * [`control::RoleRevoked`].
+    /// * [`LastMinterRemoved`] - If `account` was the last minter.
+    pub fn _revoke_minter(&mut self, account: Address) -> bool {
+        let revoked =
+            self.access_control._revoke_role(MINTER_ROLE.into(), account);
+        if revoked {
+            // Underflow not possible: `account` was counted when granted.
+            let count = self.minters_count.get() - U256::from(1);
+            self.minters_count.set(count);
+            if count.is_zero() {
+                evm::log(LastMinterRemoved { account });
+            }
+        }
+        revoked
+    }
+}
+
+impl IErc165 for MinterRole {
+    fn supports_interface(interface_id: FixedBytes<4>) -> bool {
+        <Self as IMinterRole>::INTERFACE_ID == u32::from_be_bytes(*interface_id)
+            || Erc165::supports_interface(interface_id)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::Address;
+    use motsu::prelude::*;
+    use stylus_sdk::prelude::TopLevelStorage;
+
+    use super::{IMinterRole, LastMinterRemoved, MinterRole, MINTER_ROLE};
+    use crate::{
+        access::control::{AccessControl, Error, RoleGranted, RoleRevoked},
+        utils::introspection::erc165::IErc165,
+    };
+
+    unsafe impl TopLevelStorage for MinterRole {}
+
+    fn init_admin(contract: &mut MinterRole, admin: Address) {
+        contract
+            .access_control
+            ._grant_role(AccessControl::DEFAULT_ADMIN_ROLE.into(), admin);
+    }
+
+    #[motsu::test]
+    fn admin_can_grant_minter(
+        contract: Contract<MinterRole>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| init_admin(contract, alice));
+
+        contract.sender(alice).grant_minter(bob).motsu_unwrap();
+
+        assert!(contract.sender(alice).is_minter(bob));
+        contract.sender(bob).only_minter().motsu_unwrap();
+        contract.assert_emitted(&RoleGranted {
+            role: MINTER_ROLE.into(),
+            account: bob,
+            sender: alice,
+        });
+    }
+
+    #[motsu::test]
+    fn non_minter_fails_only_minter(
+        contract: Contract<MinterRole>,
+        bob: Address,
+    ) {
+        let err = contract.sender(bob).only_minter().motsu_unwrap_err();
+        assert!(matches!(err, Error::UnauthorizedAccount(_)));
+    }
+
+    #[motsu::test]
+    fn non_admin_cannot_grant_minter(
+        contract: Contract<MinterRole>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let err = contract.sender(alice).grant_minter(bob).motsu_unwrap_err();
+        assert!(matches!(err, Error::UnauthorizedAccount(_)));
+        assert!(!contract.sender(alice).is_minter(bob));
+    }
+
+    #[motsu::test]
+    fn admin_can_revoke_minter(
+        contract: Contract<MinterRole>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| init_admin(contract, alice));
+        contract.sender(alice).grant_minter(alice).motsu_unwrap();
+        contract.sender(alice).grant_minter(bob).motsu_unwrap();
+
+        contract.sender(alice).revoke_minter(bob).motsu_unwrap();
+
+        assert!(!contract.sender(alice).is_minter(bob));
+        let err = contract.sender(bob).only_minter().motsu_unwrap_err();
+        assert!(matches!(err, Error::UnauthorizedAccount(_)));
+        contract.assert_emitted(&RoleRevoked {
+            role: MINTER_ROLE.into(),
+            account: bob,
+            sender: alice,
+        });
+        assert!(!contract.emitted(&LastMinterRemoved { account: bob }));
+    }
+
+    #[motsu::test]
+    fn last_minter_can_renounce(
+        contract: Contract<MinterRole>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.init(alice, |contract| init_admin(contract, alice));
+        contract.sender(alice).grant_minter(bob).motsu_unwrap();
+
+        contract.sender(bob).renounce_minter();
+
+        assert!(!contract.sender(bob).is_minter(bob));
+        contract.assert_emitted(&LastMinterRemoved { account: bob });
+
+        // Renouncing again is a no-op.
+        contract.sender(bob).renounce_minter();
+        assert_eq!(
+            alloy_primitives::U256::ZERO,
+            contract.sender(bob).minters_count.get()
+        );
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual = <MinterRole as IMinterRole>::INTERFACE_ID;
+        let expected = 0xf633d53d;
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface() {
+        assert!(MinterRole::supports_interface(
+            <MinterRole as IMinterRole>::INTERFACE_ID.into()
+        ));
+        assert!(MinterRole::supports_interface(
+            <MinterRole as IErc165>::INTERFACE_ID.into()
+        ));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!MinterRole::supports_interface(fake_interface_id.into()));
+    }
+}
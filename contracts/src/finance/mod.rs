@@ -1,2 +1,3 @@
 //! Primitives for financial systems.
 pub mod vesting_wallet;
+pub mod vesting_wallet_shares;
@@ -486,26 +486,44 @@ impl VestingWallet {
     ///
     /// * If scaled, total allocation (mid calculation) exceeds `U256::MAX`.
     fn vesting_schedule(&self, total_allocation: U256, timestamp: U64) -> U256 {
-        let timestamp = U256::from(timestamp);
-
-        if timestamp < self.start() {
-            U256::ZERO
-        } else if timestamp >= self.end() {
-            total_allocation
-        } else {
-            // SAFETY: `timestamp` is guaranteed to be greater than
-            // `self.start()` as checked by earlier bounds.
-            let elapsed = timestamp - self.start();
-
-            let scaled_allocation = total_allocation
-                .checked_mul(elapsed)
-                .expect("scaled allocation exceeds `U256::MAX`");
-
-            // SAFETY: `self.duration()` is non-zero. If `self.duration()` were
-            // zero, then `end == start`, meaning that `timestamp >= self.end()`
-            // and the function would have returned earlier.
-            scaled_allocation / self.duration()
-        }
+        linear_vesting(
+            total_allocation,
+            U256::from(timestamp),
+            self.start(),
+            self.duration(),
+        )
+    }
+}
+
+/// Returns the amount of `total_allocation` vested at `timestamp`, vesting
+/// linearly over `duration` seconds from `start`.
+///
+/// # Panics
+///
+/// * If scaled, total allocation (mid calculation) exceeds `U256::MAX`.
+pub(crate) fn linear_vesting(
+    total_allocation: U256,
+    timestamp: U256,
+    start: U256,
+    duration: U256,
+) -> U256 {
+    if timestamp < start {
+        U256::ZERO
+    } else if timestamp >= start + duration {
+        total_allocation
+    } else {
+        // SAFETY: `timestamp` is guaranteed to be greater than `start` as
+        // checked by earlier bounds.
+        let elapsed = timestamp - start;
+
+        let scaled_allocation = total_allocation
+            .checked_mul(elapsed)
+            .expect("scaled allocation exceeds `U256::MAX`");
+
+        // SAFETY: `duration` is non-zero. If `duration` were zero, then
+        // `timestamp >= start + duration` and the function would have
+        // returned earlier.
+        scaled_allocation / duration
     }
 }
 
@@ -520,11 +538,14 @@ impl IErc165 for VestingWallet {
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use alloy_primitives::{uint, Address, U256, U64};
-    use motsu::prelude::Contract;
+    use motsu::prelude::*;
     use stylus_sdk::block;
 
-    use super::{IVestingWallet, VestingWallet};
-    use crate::{token::erc20::Erc20, utils::introspection::erc165::IErc165};
+    use super::{ERC20Released, IVestingWallet, VestingWallet};
+    use crate::{
+        token::erc20::{Erc20, IErc20},
+        utils::introspection::erc165::IErc165,
+    };
 
     const BALANCE: u64 = 1000;
 
@@ -655,6 +676,41 @@ mod tests {
         }
     }
 
+    #[motsu::test]
+    fn release_erc20_emits_released_amount(
+        vesting_wallet: Contract<VestingWallet>,
+        erc20: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let start = block::timestamp() - DURATION / 2;
+        vesting_wallet.init(alice, |contract| {
+            contract.init(start, DURATION);
+            contract.ownable._transfer_ownership(bob);
+        });
+        erc20
+            .sender(alice)
+            ._mint(vesting_wallet.address(), U256::from(BALANCE))
+            .motsu_unwrap();
+
+        let token = erc20.address();
+        let amount = U256::from(BALANCE / 2);
+        assert_eq!(
+            amount,
+            vesting_wallet.sender(alice).releasable_erc20(token).motsu_unwrap()
+        );
+
+        vesting_wallet.sender(alice).release_erc20(token).motsu_unwrap();
+
+        vesting_wallet.assert_emitted(&ERC20Released { token, amount });
+        assert_eq!(amount, vesting_wallet.sender(alice).released_erc20(token));
+        assert_eq!(amount, erc20.sender(alice).balance_of(bob));
+        assert_eq!(
+            U256::ZERO,
+            vesting_wallet.sender(alice).releasable_erc20(token).motsu_unwrap()
+        );
+    }
+
     #[motsu::test]
     fn interface_id() {
         let actual = <VestingWallet as IVestingWallet>::INTERFACE_ID;
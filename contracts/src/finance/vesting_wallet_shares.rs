@@ -0,0 +1,772 @@
+//! A vesting wallet shared by several payees.
+//!
+//! This contract receives [`crate::token::erc20::Erc20`] tokens and vests them
+//! linearly over time, following the same schedule as
+//! [`super::vesting_wallet::VestingWallet`]. Instead of releasing the vested
+//! assets to a single beneficiary, the vested amount is split among payees
+//! proportionally to their shares.
+//!
+//! Each payee releases their own part with [`IVestingWalletShares::release`],
+//! at any time: the amount due to an account is its share of the total amount
+//! vested so far, minus what it already received. Payees releasing late don't
+//! lose anything, and the total released never exceeds the vested amount.
+//!
+//! The vesting schedule, the payees and their shares are set once, during
+//! construction with `koba` (stylus construction tooling) within solidity
+//! constructor file, or when initializing the contract with
+//! [`VestingWalletShares::_init`] and [`VestingWalletShares::_add_payee`]:
+//!
+//! ```rust,ignore
+//! self.vesting_wallet_shares._init(start, duration);
+//! self.vesting_wallet_shares._add_payee(alice, U256::from(1))?;
+//! self.vesting_wallet_shares._add_payee(bob, U256::from(3))?;
+//! ```
+//!
+//! NOTE: Shares can't be updated once assigned. Adding payees after tokens
+//! have been released would make the previous releases unfair to the new
+//! payees.
+//!
+//! NOTE: Ether sent to this contract is not vested: only ERC-20 tokens can be
+//! released.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, FixedBytes, U256, U64};
+use openzeppelin_stylus_proc::interface_id;
+pub use sol::*;
+use stylus_sdk::{
+    block,
+    call::{Call, MethodError},
+    contract, evm,
+    prelude::*,
+    storage::{StorageMap, StorageU256, StorageU64},
+};
+
+use super::vesting_wallet::{linear_vesting, InvalidToken};
+use crate::{
+    token::erc20::utils::{
+        safe_erc20::{self, ISafeErc20, SafeErc20},
+        IErc20,
+    },
+    utils::{
+        introspection::erc165::{Erc165, IErc165},
+        math::storage::AddAssignChecked,
+    },
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when `account` is added as a payee with `shares`.
+        ///
+        /// * `account` - Address of the payee.
+        /// * `shares` - Number of shares owned by the payee.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event PayeeAdded(address indexed account, uint256 shares);
+
+        /// Emitted when `amount` of ERC-20 `token` has been released to `to`.
+        ///
+        /// * `token` - Address of the token being released.
+        /// * `to` - Address of the payee.
+        /// * `amount` - Number of tokens released.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event ERC20PaymentReleased(address indexed token, address indexed to, uint256 amount);
+    }
+
+    sol! {
+        /// Indicates that `account` can't be added as a payee, because it is
+        /// `Address::ZERO` or already has shares.
+        ///
+        /// * `account` - Address of the payee.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error VestingWalletInvalidPayee(address account);
+
+        /// Indicates that `account` can't be added as a payee without shares.
+        ///
+        /// * `account` - Address of the payee.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error VestingWalletInvalidShares(address account);
+
+        /// Indicates that no `token` is due to `account`.
+        ///
+        /// * `token` - Address of the token being released.
+        /// * `account` - Address of the payee.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error VestingWalletNoPaymentDue(address token, address account);
+    }
+}
+
+/// An error that occurred in the [`VestingWalletShares`] contract.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Error type from [`SafeErc20`] contract [`safe_erc20::Error`].
+    SafeErc20(safe_erc20::Error),
+    /// The token address is not valid. (eg. `Address::ZERO`).
+    InvalidToken(InvalidToken),
+    /// The account can't be added as a payee.
+    InvalidPayee(VestingWalletInvalidPayee),
+    /// The payee can't be added without shares.
+    InvalidShares(VestingWalletInvalidShares),
+    /// No payment is due to the account.
+    NoPaymentDue(VestingWalletNoPaymentDue),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of a [`VestingWalletShares`] Contract.
+#[storage]
+pub struct VestingWalletShares {
+    /// Start timestamp.
+    pub(crate) start: StorageU64,
+    /// Vesting duration.
+    pub(crate) duration: StorageU64,
+    /// Total number of shares of all payees.
+    pub(crate) total_shares: StorageU256,
+    /// Number of shares of each payee.
+    pub(crate) shares: StorageMap<Address, StorageU256>,
+    /// Amount of each ERC-20 token already released to all payees.
+    pub(crate) erc20_total_released: StorageMap<Address, StorageU256>,
+    /// Amount of each ERC-20 token already released to each payee.
+    pub(crate) erc20_released:
+        StorageMap<Address, StorageMap<Address, StorageU256>>,
+    /// [`SafeErc20`] contract.
+    safe_erc20: SafeErc20,
+}
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for VestingWalletShares {}
+
+/// Required interface of a [`VestingWalletShares`] compliant contract.
+#[interface_id]
+pub trait IVestingWalletShares {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Getter for the start timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn start(&self) -> U256;
+
+    /// Getter for the vesting duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn duration(&self) -> U256;
+
+    /// Getter for the end timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn end(&self) -> U256;
+
+    /// Getter for the total number of shares of all payees.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn total_shares(&self) -> U256;
+
+    /// Getter for the number of shares of `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Address of the payee.
+    fn shares(&self, account: Address) -> U256;
+
+    /// Amount of `token` already released to all payees.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `token` - Address of the token being released.
+    fn total_released(&self, token: Address) -> U256;
+
+    /// Amount of `token` already released to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `token` - Address of the token being released.
+    /// * `account` - Address of the payee.
+    fn released(&self, token: Address, account: Address) -> U256;
+
+    /// Getter for the amount of `token` that can be released to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the token being released.
+    /// * `account` - Address of the payee.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidToken`] - If the `token` address is not a contract.
+    ///
+    /// # Panics
+    ///
+    /// * If total allocation exceeds `U256::MAX`.
+    /// * If scaled, total allocation (mid calculation) exceeds `U256::MAX`.
+    fn releasable(
+        &mut self,
+        token: Address,
+        account: Address,
+    ) -> Result<U256, Self::Error>;
+
+    /// Release the amount of `token` that is due to `account`, according to
+    /// its shares and the vesting schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the token being released.
+    /// * `account` - Address of the payee.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidToken`] - If the `token` address is not a contract.
+    /// * [`Error::NoPaymentDue`] - If no `token` is due to `account`.
+    /// * [`Error::SafeErc20`] - If the transfer fails.
+    ///
+    /// # Events
+    ///
+    /// * [`ERC20PaymentReleased`].
+    ///
+    /// # Panics
+    ///
+    /// * If total allocation exceeds `U256::MAX`.
+    /// * If scaled, total allocation (mid calculation) exceeds `U256::MAX`.
+    /// * If total released amount exceeds `U256::MAX`.
+    fn release(
+        &mut self,
+        token: Address,
+        account: Address,
+    ) -> Result<(), Self::Error>;
+
+    /// Calculates the amount of `token` that has already vested for all
+    /// payees.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the token being released.
+    /// * `timestamp` - Point in time for which to check the vested amount.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidToken`] - If the `token` address is not a contract.
+    ///
+    /// # Panics
+    ///
+    /// * If total allocation exceeds `U256::MAX`.
+    /// * If scaled, total allocation (mid calculation) exceeds `U256::MAX`.
+    fn vested_amount(
+        &mut self,
+        token: Address,
+        timestamp: u64,
+    ) -> Result<U256, Self::Error>;
+}
+
+#[public]
+impl IVestingWalletShares for VestingWalletShares {
+    type Error = Error;
+
+    fn start(&self) -> U256 {
+        U256::from(self.start.get())
+    }
+
+    fn duration(&self) -> U256 {
+        U256::from(self.duration.get())
+    }
+
+    fn end(&self) -> U256 {
+        // SAFETY: both `start` and `duration` are stored as u64,
+        // so they cannot exceed `U256::MAX`
+        self.start() + self.duration()
+    }
+
+    fn total_shares(&self) -> U256 {
+        self.total_shares.get()
+    }
+
+    fn shares(&self, account: Address) -> U256 {
+        self.shares.get(account)
+    }
+
+    fn total_released(&self, token: Address) -> U256 {
+        self.erc20_total_released.get(token)
+    }
+
+    fn released(&self, token: Address, account: Address) -> U256 {
+        self.erc20_released.get(token).get(account)
+    }
+
+    fn releasable(
+        &mut self,
+        token: Address,
+        account: Address,
+    ) -> Result<U256, Self::Error> {
+        let vested = self.vested_amount(token, block::timestamp())?;
+        Ok(self.pending_payment(vested, token, account))
+    }
+
+    fn release(
+        &mut self,
+        token: Address,
+        account: Address,
+    ) -> Result<(), Self::Error> {
+        let amount = self.releasable(token, account)?;
+        if amount.is_zero() {
+            return Err(VestingWalletNoPaymentDue { token, account }.into());
+        }
+
+        self.erc20_total_released.setter(token).add_assign_checked(
+            amount,
+            "total released should not exceed `U256::MAX`",
+        );
+        // Overflow not possible: released to `account` is at most the total
+        // released.
+        let released = self.released(token, account);
+        self.erc20_released
+            .setter(token)
+            .setter(account)
+            .set(released + amount);
+
+        self.safe_erc20.safe_transfer(token, account, amount)?;
+
+        evm::log(ERC20PaymentReleased { token, to: account, amount });
+
+        Ok(())
+    }
+
+    fn vested_amount(
+        &mut self,
+        token: Address,
+        timestamp: u64,
+    ) -> Result<U256, Self::Error> {
+        let erc20 = IErc20::new(token);
+        let balance = erc20
+            .balance_of(Call::new_in(self), contract::address())
+            .map_err(|_| InvalidToken { token })?;
+
+        let total_allocation = balance
+            .checked_add(self.total_released(token))
+            .expect("total allocation should not exceed `U256::MAX`");
+
+        Ok(linear_vesting(
+            total_allocation,
+            U256::from(U64::from(timestamp)),
+            self.start(),
+            self.duration(),
+        ))
+    }
+}
+
+impl VestingWalletShares {
+    /// Sets the vesting schedule, starting at the `start` timestamp and
+    /// lasting `duration` seconds.
+    ///
+    /// Internal function meant to be called when initializing the contract,
+    /// before any token is released.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `start` - Start timestamp.
+    /// * `duration` - Vesting duration, in seconds.
+    pub fn _init(&mut self, start: u64, duration: u64) {
+        self.start.set(U64::from(start));
+        self.duration.set(U64::from(duration));
+    }
+
+    /// Adds `account` as a payee owning `shares`.
+    ///
+    /// Internal function meant to be called when initializing the contract,
+    /// along with [`Self::_init`], before any token is released.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Address of the payee.
+    /// * `shares` - Number of shares owned by the payee.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidPayee`] - If `account` is `Address::ZERO` or already
+    ///   has shares.
+    /// * [`Error::InvalidShares`] - If `shares` is zero.
+    ///
+    /// # Events
+    ///
+    /// * [`PayeeAdded`].
+    ///
+    /// # Panics
+    ///
+    /// * If total shares exceed `U256::MAX`.
+    pub fn _add_payee(
+        &mut self,
+        account: Address,
+        shares: U256,
+    ) -> Result<(), Error> {
+        if account.is_zero() || !self.shares(account).is_zero() {
+            return Err(VestingWalletInvalidPayee { account }.into());
+        }
+        if shares.is_zero() {
+            return Err(VestingWalletInvalidShares { account }.into());
+        }
+
+        self.shares.setter(account).set(shares);
+        self.total_shares.add_assign_checked(
+            shares,
+            "total shares should not exceed `U256::MAX`",
+        );
+
+        evm::log(PayeeAdded { account, shares });
+
+        Ok(())
+    }
+
+    /// Returns the amount of `token` due to `account`, given the amount
+    /// `vested` for all payees.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `vested` - Amount of `token` vested for all payees.
+    /// * `token` - Address of the token being released.
+    /// * `account` - Address of the payee.
+    ///
+    /// # Panics
+    ///
+    /// * If `vested` multiplied by the shares of `account` exceeds `U256::MAX`.
+    fn pending_payment(
+        &self,
+        vested: U256,
+        token: Address,
+        account: Address,
+    ) -> U256 {
+        let total_shares = self.total_shares();
+        if total_shares.is_zero() {
+            return U256::ZERO;
+        }
+
+        let share = vested
+            .checked_mul(self.shares(account))
+            .expect("scaled vested amount should not exceed `U256::MAX`")
+            / total_shares;
+        // The share of `account` only shrinks below what it already received
+        // if payees were added after a release, in which case nothing is due.
+        share.saturating_sub(self.released(token, account))
+    }
+}
+
+impl IErc165 for VestingWalletShares {
+    fn supports_interface(interface_id: FixedBytes<4>) -> bool {
+        <Self as IVestingWalletShares>::INTERFACE_ID
+            == u32::from_be_bytes(*interface_id)
+            || Erc165::supports_interface(interface_id)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{uint, Address, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::block;
+
+    use super::*;
+    use crate::token::erc20::{Erc20, IErc20 as _};
+
+    const BALANCE: u64 = 1000;
+
+    const DURATION: u64 = 4 * 365 * 86400; // 4 years
+
+    impl VestingWalletShares {
+        /// Moves the schedule so that `elapsed` seconds of [`DURATION`] have
+        /// passed at the current block.
+        fn set_elapsed(&mut self, elapsed: u64) {
+            self._init(block::timestamp() - elapsed, DURATION);
+        }
+    }
+
+    fn init(
+        contract: &Contract<VestingWalletShares>,
+        erc20: &Contract<Erc20>,
+        payees: &[(Address, u64)],
+    ) {
+        let deployer = payees[0].0;
+        contract.init(deployer, |contract| {
+            contract.set_elapsed(0);
+            for &(account, shares) in payees {
+                contract._add_payee(account, U256::from(shares)).motsu_unwrap();
+            }
+        });
+        erc20
+            .sender(deployer)
+            ._mint(contract.address(), U256::from(BALANCE))
+            .motsu_unwrap();
+    }
+
+    #[motsu::test]
+    fn init_sets_schedule(
+        contract: Contract<VestingWalletShares>,
+        alice: Address,
+    ) {
+        let start = block::timestamp();
+        contract.sender(alice)._init(start, DURATION);
+
+        assert_eq!(U256::from(start), contract.sender(alice).start());
+        assert_eq!(U256::from(DURATION), contract.sender(alice).duration());
+        assert_eq!(U256::from(start + DURATION), contract.sender(alice).end());
+    }
+
+    #[motsu::test]
+    fn adds_payees(
+        contract: Contract<VestingWalletShares>,
+        erc20: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, &erc20, &[(alice, 1), (bob, 3)]);
+
+        assert_eq!(uint!(1_U256), contract.sender(alice).shares(alice));
+        assert_eq!(uint!(3_U256), contract.sender(alice).shares(bob));
+        assert_eq!(uint!(4_U256), contract.sender(alice).total_shares());
+        contract.assert_emitted(&PayeeAdded {
+            account: bob,
+            shares: uint!(3_U256),
+        });
+    }
+
+    #[motsu::test]
+    fn add_payee_reverts_on_invalid_payee(
+        contract: Contract<VestingWalletShares>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._add_payee(Address::ZERO, uint!(1_U256))
+            .motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidPayee(VestingWalletInvalidPayee { account })
+                if account.is_zero()
+        ));
+
+        contract.sender(alice)._add_payee(alice, uint!(1_U256)).motsu_unwrap();
+        let err = contract
+            .sender(alice)
+            ._add_payee(alice, uint!(2_U256))
+            .motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidPayee(VestingWalletInvalidPayee { account })
+                if account == alice
+        ));
+        assert_eq!(uint!(1_U256), contract.sender(alice).total_shares());
+    }
+
+    #[motsu::test]
+    fn add_payee_reverts_on_zero_shares(
+        contract: Contract<VestingWalletShares>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            ._add_payee(alice, U256::ZERO)
+            .motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidShares(VestingWalletInvalidShares { account })
+                if account == alice
+        ));
+    }
+
+    #[motsu::test]
+    fn payees_release_shares_at_different_times(
+        contract: Contract<VestingWalletShares>,
+        erc20: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, &erc20, &[(alice, 1), (bob, 3)]);
+        let token = erc20.address();
+
+        // Half of the tokens vested: Alice releases her part.
+        contract.sender(alice).set_elapsed(DURATION / 2);
+        assert_eq!(
+            uint!(125_U256),
+            contract.sender(alice).releasable(token, alice).motsu_unwrap()
+        );
+        assert_eq!(
+            uint!(375_U256),
+            contract.sender(alice).releasable(token, bob).motsu_unwrap()
+        );
+        contract.sender(alice).release(token, alice).motsu_unwrap();
+        contract.assert_emitted(&ERC20PaymentReleased {
+            token,
+            to: alice,
+            amount: uint!(125_U256),
+        });
+        assert_eq!(uint!(125_U256), erc20.sender(alice).balance_of(alice));
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).releasable(token, alice).motsu_unwrap()
+        );
+
+        // Once all tokens vested, Bob releases his whole part at once, and
+        // Alice the rest of hers.
+        contract.sender(alice).set_elapsed(DURATION);
+        contract.sender(bob).release(token, bob).motsu_unwrap();
+        assert_eq!(uint!(750_U256), erc20.sender(alice).balance_of(bob));
+        contract.sender(bob).release(token, alice).motsu_unwrap();
+        assert_eq!(uint!(250_U256), erc20.sender(alice).balance_of(alice));
+
+        assert_eq!(
+            U256::from(BALANCE),
+            contract.sender(alice).total_released(token)
+        );
+        assert_eq!(
+            uint!(250_U256),
+            contract.sender(alice).released(token, alice)
+        );
+        assert_eq!(
+            uint!(750_U256),
+            contract.sender(alice).released(token, bob)
+        );
+        assert_eq!(
+            U256::ZERO,
+            erc20.sender(alice).balance_of(contract.address())
+        );
+    }
+
+    #[motsu::test]
+    fn released_never_exceeds_vested(
+        contract: Contract<VestingWalletShares>,
+        erc20: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        init(&contract, &erc20, &[(alice, 3), (bob, 5), (charlie, 7)]);
+        let token = erc20.address();
+        let payees = [alice, bob, charlie];
+
+        for i in 1..=16 {
+            contract.sender(alice).set_elapsed(i * DURATION / 16);
+            // Payees release in turns, leaving some payments pending.
+            let account = payees[usize::try_from(i).unwrap() % payees.len()];
+            contract.sender(alice).release(token, account).motsu_unwrap();
+
+            let vested = contract
+                .sender(alice)
+                .vested_amount(token, block::timestamp())
+                .motsu_unwrap();
+            let released = contract.sender(alice).total_released(token);
+            let pending: U256 = payees
+                .iter()
+                .map(|&payee| {
+                    contract
+                        .sender(alice)
+                        .releasable(token, payee)
+                        .motsu_unwrap()
+                })
+                .sum();
+            assert!(released + pending <= vested);
+        }
+        assert_eq!(
+            U256::from(BALANCE),
+            contract
+                .sender(alice)
+                .vested_amount(token, block::timestamp())
+                .motsu_unwrap()
+        );
+    }
+
+    #[motsu::test]
+    fn nothing_due_when_payee_added_after_release(
+        contract: Contract<VestingWalletShares>,
+        erc20: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, &erc20, &[(alice, 1)]);
+        let token = erc20.address();
+
+        contract.sender(alice).set_elapsed(DURATION / 2);
+        contract.sender(alice).release(token, alice).motsu_unwrap();
+        assert_eq!(uint!(500_U256), erc20.sender(alice).balance_of(alice));
+
+        // Alice's share of the vested amount drops below what she received.
+        contract.sender(alice)._add_payee(bob, uint!(3_U256)).motsu_unwrap();
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).releasable(token, alice).motsu_unwrap()
+        );
+    }
+
+    #[motsu::test]
+    fn release_reverts_when_no_payment_due(
+        contract: Contract<VestingWalletShares>,
+        erc20: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init(&contract, &erc20, &[(alice, 1)]);
+        let token = erc20.address();
+
+        // Nothing vested yet.
+        let err =
+            contract.sender(alice).release(token, alice).motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            Error::NoPaymentDue(VestingWalletNoPaymentDue { token: t, account })
+                if t == token && account == alice
+        ));
+
+        // Bob is not a payee.
+        contract.sender(alice).set_elapsed(DURATION);
+        let err = contract.sender(alice).release(token, bob).motsu_unwrap_err();
+        assert!(matches!(err, Error::NoPaymentDue(_)));
+    }
+
+    #[motsu::test]
+    fn interface_id() {
+        let actual =
+            <VestingWalletShares as IVestingWalletShares>::INTERFACE_ID;
+        let expected = 0x30614332;
+        assert_eq!(actual, expected);
+    }
+
+    #[motsu::test]
+    fn supports_interface() {
+        assert!(VestingWalletShares::supports_interface(
+            <VestingWalletShares as IVestingWalletShares>::INTERFACE_ID.into()
+        ));
+        assert!(VestingWalletShares::supports_interface(
+            <VestingWalletShares as IErc165>::INTERFACE_ID.into()
+        ));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!VestingWalletShares::supports_interface(
+            fake_interface_id.into()
+        ));
+    }
+}
@@ -28,9 +28,12 @@ use stylus_sdk::{
     storage::{StorageAddress, StorageMap},
 };
 
-use crate::utils::{
-    introspection::erc165::{Erc165, IErc165},
-    structs::checkpoints::{Size, S160},
+use crate::{
+    token::erc721::{self, Erc721},
+    utils::{
+        introspection::erc165::{Erc165, IErc165},
+        structs::checkpoints::{Size, S160},
+    },
 };
 
 type U96 = <S160 as Size>::Key;
@@ -320,6 +323,43 @@ impl Erc2981 {
     pub fn _reset_token_royalty(&mut self, token_id: U256) {
         self.token_royalty_info.delete(token_id);
     }
+
+    /// Destroys the ERC-721 `token_id` and clears its royalty information.
+    ///
+    /// The default royalty applies to the token id if it is minted again,
+    /// unless its royalty information is set again.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token_id` - Id of the token to burn.
+    /// * `erc721` - Write access to an [`Erc721`] contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc721::Error::NonexistentToken`] - If token does not exist.
+    ///
+    /// # Events
+    ///
+    /// * [`erc721::Transfer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// fn burn(&mut self, token_id: U256) -> Result<(), Error> {
+    ///     self.erc2981._burn_erc721(token_id, &mut self.erc721)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn _burn_erc721(
+        &mut self,
+        token_id: U256,
+        erc721: &mut Erc721,
+    ) -> Result<(), erc721::Error> {
+        erc721._burn(token_id)?;
+        self._reset_token_royalty(token_id);
+        Ok(())
+    }
 }
 
 #[cfg(all(test, feature = "std"))]
@@ -328,7 +368,7 @@ mod tests {
     use stylus_sdk::alloy_primitives::{uint, Address, U256};
 
     use super::*;
-    use crate::utils::introspection::erc165::IErc165;
+    use crate::{token::erc721::Erc721, utils::introspection::erc165::IErc165};
 
     const TOKEN_ID: U256 = uint!(1_U256);
     const SALE_PRICE: U256 = uint!(1000_U256);
@@ -764,6 +804,88 @@ mod tests {
         assert_eq!(U96::ZERO, *token_royalty.royalty_fraction);
     }
 
+    #[storage]
+    struct Erc721RoyaltyExample {
+        erc721: Erc721,
+        erc2981: Erc2981,
+    }
+
+    unsafe impl TopLevelStorage for Erc721RoyaltyExample {}
+
+    #[public]
+    impl Erc721RoyaltyExample {
+        fn burn(&mut self, token_id: U256) -> Result<(), erc721::Error> {
+            self.erc2981._burn_erc721(token_id, &mut self.erc721)
+        }
+    }
+
+    #[motsu::test]
+    fn burn_erc721_clears_token_royalty(
+        contract: Contract<Erc721RoyaltyExample>,
+        bob: Address,
+        dave: Address,
+    ) {
+        contract.init(bob, |contract| {
+            contract.erc2981.fee_denominator.set(DEFAULT_FEE_DENOMINATOR);
+            contract
+                .erc2981
+                ._set_default_royalty(bob, DEFAULT_FEE_NUMERATOR)
+                .expect("should set default royalty");
+            contract.erc721._mint(bob, TOKEN_ID).expect("should mint a token");
+            contract
+                .erc2981
+                ._set_token_royalty(TOKEN_ID, dave, uint!(8000_U96))
+                .expect("should set token royalty");
+        });
+
+        contract.sender(bob).burn(TOKEN_ID).expect("should burn the token");
+
+        {
+            let contract = contract.sender(bob);
+            let token_royalty =
+                contract.erc2981.token_royalty_info.get(TOKEN_ID);
+            assert!(token_royalty.receiver.is_zero());
+            assert_eq!(U96::ZERO, *token_royalty.royalty_fraction);
+        }
+
+        // A re-minted token falls back to the default royalty.
+        contract
+            .sender(bob)
+            .erc721
+            ._mint(dave, TOKEN_ID)
+            .expect("should mint the token again");
+        let (royalty_receiver, royalty_amount) =
+            contract.sender(bob).erc2981.royalty_info(TOKEN_ID, SALE_PRICE);
+        assert_eq!(bob, royalty_receiver);
+        assert_eq!(uint!(900_U256), royalty_amount);
+    }
+
+    #[motsu::test]
+    fn burn_erc721_reverts_for_nonexistent_token(
+        contract: Contract<Erc721RoyaltyExample>,
+        bob: Address,
+        dave: Address,
+    ) {
+        contract.init(bob, |contract| {
+            contract.erc2981.fee_denominator.set(DEFAULT_FEE_DENOMINATOR);
+            contract
+                .erc2981
+                ._set_token_royalty(TOKEN_ID, dave, uint!(8000_U96))
+                .expect("should set token royalty");
+        });
+
+        let err = contract
+            .sender(bob)
+            .burn(TOKEN_ID)
+            .expect_err("should not burn a nonexistent token");
+        assert!(matches!(err, erc721::Error::NonexistentToken(_)));
+
+        // The royalty information is left untouched.
+        let (royalty_receiver, _) =
+            contract.sender(bob).erc2981.royalty_info(TOKEN_ID, SALE_PRICE);
+        assert_eq!(dave, royalty_receiver);
+    }
+
     #[motsu::test]
     #[should_panic = "division by zero in `royalty_amount` calculation."]
     fn royalty_info_reverts_on_division_by_zero_in_default_royalty(
@@ -3,10 +3,10 @@
 use alloc::vec::Vec;
 
 use alloy_primitives::{Address, U256};
-use stylus_sdk::msg;
 
-use crate::token::erc1155::{
-    self, ERC1155MissingApprovalForAll, Erc1155, IErc1155,
+use crate::{
+    token::erc1155::{self, ERC1155MissingApprovalForAll, Erc1155, IErc1155},
+    utils::Context,
 };
 
 /// Extension of [`Erc1155`] that allows token holders to destroy both their
@@ -74,8 +74,7 @@ impl IErc1155Burnable for Erc1155 {
         token_id: U256,
         value: U256,
     ) -> Result<(), Self::Error> {
-        self.ensure_approved_or_owner(account)?;
-        self._burn(account, token_id, value)
+        self.burn_with_sender(self._msg_sender(), account, token_id, value)
     }
 
     fn burn_batch(
@@ -84,17 +83,90 @@ impl IErc1155Burnable for Erc1155 {
         token_ids: Vec<U256>,
         values: Vec<U256>,
     ) -> Result<(), Self::Error> {
-        self.ensure_approved_or_owner(account)?;
-        self._burn_batch(account, token_ids, values)
+        self.burn_batch_with_sender(
+            self._msg_sender(),
+            account,
+            token_ids,
+            values,
+        )
     }
 }
 
 impl Erc1155 {
+    /// Same as [`IErc1155Burnable::burn`], acting for `sender` rather than
+    /// [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account burning the tokens.
+    /// * `account` - Account to burn tokens from.
+    /// * `token_id` - Token id to be burnt.
+    /// * `value` - Amount to be burnt.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc1155::Error::MissingApprovalForAll`] - If `sender` is neither
+    ///   `account` nor approved for all of its tokens.
+    /// * [`erc1155::Error::InvalidSender`] - If `account` is `Address::ZERO`.
+    /// * [`erc1155::Error::InsufficientBalance`] - If `value` is greater than
+    ///   the balance of `account`.
+    ///
+    /// # Events
+    ///
+    /// * [`erc1155::TransferSingle`].
+    pub fn burn_with_sender(
+        &mut self,
+        sender: Address,
+        account: Address,
+        token_id: U256,
+        value: U256,
+    ) -> Result<(), erc1155::Error> {
+        self.ensure_approved_or_owner(sender, account)?;
+        self._burn(account, token_id, value)
+    }
+
+    /// Same as [`IErc1155Burnable::burn_batch`], acting for `sender` rather
+    /// than [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account burning the tokens.
+    /// * `account` - Account to burn tokens from.
+    /// * `token_ids` - Array of all token ids to be burnt.
+    /// * `values` - Array of all amount of tokens to be burnt.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc1155::Error::MissingApprovalForAll`] - If `sender` is neither
+    ///   `account` nor approved for all of its tokens.
+    /// * [`erc1155::Error::InvalidSender`] - If `account` is `Address::ZERO`.
+    /// * [`erc1155::Error::InvalidArrayLength`] - If length of `token_ids` is
+    ///   not equal to length of `values`.
+    /// * [`erc1155::Error::InsufficientBalance`] - If any of the `values` is
+    ///   greater than the balance of `account`.
+    ///
+    /// # Events
+    ///
+    /// * [`erc1155::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc1155::TransferBatch`] - If the arrays contain multiple elements.
+    pub fn burn_batch_with_sender(
+        &mut self,
+        sender: Address,
+        account: Address,
+        token_ids: Vec<U256>,
+        values: Vec<U256>,
+    ) -> Result<(), erc1155::Error> {
+        self.ensure_approved_or_owner(sender, account)?;
+        self._burn_batch(account, token_ids, values)
+    }
+
     fn ensure_approved_or_owner(
         &self,
+        sender: Address,
         account: Address,
     ) -> Result<(), erc1155::Error> {
-        let sender = msg::sender();
         if account != sender && !self.is_approved_for_all(account, sender) {
             return Err(erc1155::Error::MissingApprovalForAll(
                 ERC1155MissingApprovalForAll {
@@ -116,8 +188,9 @@ mod tests {
     use super::IErc1155Burnable;
     use crate::token::erc1155::{
         tests::{random_token_ids, random_values},
-        ERC1155InsufficientBalance, ERC1155InvalidSender,
-        ERC1155MissingApprovalForAll, Erc1155, Error, IErc1155,
+        ERC1155InsufficientBalance, ERC1155InvalidArrayLength,
+        ERC1155InvalidSender, ERC1155MissingApprovalForAll, Erc1155, Error,
+        IErc1155,
     };
 
     fn init(
@@ -365,6 +438,31 @@ mod tests {
         ));
     }
 
+    #[motsu::test]
+    fn error_when_not_equal_arrays_burn_batch(
+        contract: Contract<Erc1155>,
+        alice: Address,
+    ) {
+        let (token_ids, values) =
+            contract.init(alice, |contract| init(contract, alice, 3));
+
+        let err = contract
+            .sender(alice)
+            .burn_batch(alice, token_ids.clone(), values[..2].to_vec())
+            .expect_err("should return `ERC1155InvalidArrayLength`");
+
+        assert!(matches!(
+            err,
+            Error::InvalidArrayLength(ERC1155InvalidArrayLength {
+                ids_length, values_length
+            }) if ids_length == U256::from(3) && values_length == U256::from(2)
+        ));
+        for (&token_id, &value) in token_ids.iter().zip(values.iter()) {
+            let balance = contract.sender(alice).balance_of(alice, token_id);
+            assert_eq!(value, balance);
+        }
+    }
+
     #[motsu::test]
     fn error_when_insufficient_balance_burn_batch(
         contract: Contract<Erc1155>,
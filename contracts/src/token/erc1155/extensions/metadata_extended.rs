@@ -0,0 +1,112 @@
+//! Optional collection name and symbol of an ERC-1155 token, stored alongside
+//! its URI metadata.
+//!
+//! The name and symbol are not part of the [ERC], but some marketplaces read
+//! them from ERC-1155 contracts the same way they do for ERC-721.
+//!
+//! [ERC]: https://eips.ethereum.org/EIPS/eip-1155#metadata-extensions
+
+use alloc::{string::String, vec, vec::Vec};
+
+use alloy_primitives::FixedBytes;
+use stylus_sdk::prelude::*;
+
+use crate::{
+    token::erc1155::extensions::Erc1155MetadataUri,
+    utils::{introspection::erc165::IErc165, Metadata},
+};
+
+/// State of an [`Erc1155MetadataExtended`] contract.
+#[storage]
+pub struct Erc1155MetadataExtended {
+    /// [`Erc1155MetadataUri`] contract.
+    pub metadata_uri: Erc1155MetadataUri,
+    /// [`Metadata`] contract.
+    pub(crate) metadata: Metadata,
+}
+
+/// Interface for the collection name and symbol of an ERC-1155 token.
+pub trait IErc1155MetadataExtended {
+    /// Returns the token collection name.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn name(&self) -> String;
+
+    /// Returns the token collection symbol.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn symbol(&self) -> String;
+}
+
+#[public]
+impl IErc1155MetadataExtended for Erc1155MetadataExtended {
+    fn name(&self) -> String {
+        self.metadata.name()
+    }
+
+    fn symbol(&self) -> String {
+        self.metadata.symbol()
+    }
+}
+
+/// Only the [`Erc1155MetadataUri`] interface is advertised, since the name and
+/// symbol are not part of any standard interface.
+impl IErc165 for Erc1155MetadataExtended {
+    fn supports_interface(interface_id: FixedBytes<4>) -> bool {
+        Erc1155MetadataUri::supports_interface(interface_id)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::string::String;
+
+    use alloy_primitives::Address;
+    use motsu::prelude::Contract;
+    use stylus_sdk::{alloy_primitives::uint, prelude::TopLevelStorage};
+
+    use super::{Erc1155MetadataExtended, IErc1155MetadataExtended};
+    use crate::{
+        token::erc1155::extensions::{Erc1155MetadataUri, IErc1155MetadataUri},
+        utils::introspection::erc165::IErc165,
+    };
+
+    unsafe impl TopLevelStorage for Erc1155MetadataExtended {}
+
+    #[motsu::test]
+    fn reads_name_symbol_and_uri(
+        contract: Contract<Erc1155MetadataExtended>,
+        alice: Address,
+    ) {
+        let uri = String::from("https://token-cdn-domain/{id}.json");
+        contract.init(alice, |contract| {
+            contract.metadata.name.set_str("Game Items");
+            contract.metadata.symbol.set_str("ITEMS");
+            contract.metadata_uri.uri.set_str(uri.clone());
+        });
+
+        assert_eq!("Game Items", contract.sender(alice).name());
+        assert_eq!("ITEMS", contract.sender(alice).symbol());
+        // The URI is left untouched by the extension.
+        assert_eq!(uri, contract.sender(alice).metadata_uri.uri(uint!(1_U256)));
+    }
+
+    #[motsu::test]
+    fn supports_interface() {
+        assert!(Erc1155MetadataExtended::supports_interface(
+            <Erc1155MetadataUri as IErc1155MetadataUri>::INTERFACE_ID.into()
+        ));
+        assert!(Erc1155MetadataExtended::supports_interface(
+            <Erc1155MetadataUri as IErc165>::INTERFACE_ID.into()
+        ));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!Erc1155MetadataExtended::supports_interface(
+            fake_interface_id.into()
+        ));
+    }
+}
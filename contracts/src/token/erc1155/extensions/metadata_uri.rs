@@ -3,7 +3,7 @@
 //!
 //! [ERC]: https://eips.ethereum.org/EIPS/eip-1155#metadata-extensions
 
-use alloc::{string::String, vec, vec::Vec};
+use alloc::{format, string::String, vec, vec::Vec};
 
 use alloy_primitives::{FixedBytes, U256};
 use openzeppelin_stylus_proc::interface_id;
@@ -63,6 +63,29 @@ impl IErc1155MetadataUri for Erc1155MetadataUri {
     }
 }
 
+impl Erc1155MetadataUri {
+    /// Returns the URI for token type `id`, with every `{id}` substring of the
+    /// URI replaced by the actual token type ID, as clients are expected to
+    /// do.
+    ///
+    /// The ID is formatted as lowercase hexadecimal, zero-padded to 64
+    /// characters and without `0x` prefix, as required by the [ERC].
+    ///
+    /// NOTE: This function is not part of the ABI. [`IErc1155MetadataUri::uri`]
+    /// keeps returning the raw URI template.
+    ///
+    /// [ERC]: https://eips.ethereum.org/EIPS/eip-1155#metadata
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `id` - Token id.
+    #[must_use]
+    pub fn uri_with_id(&self, id: U256) -> String {
+        self.uri.get_string().replace("{id}", &format!("{id:064x}"))
+    }
+}
+
 impl IErc165 for Erc1155MetadataUri {
     fn supports_interface(interface_id: FixedBytes<4>) -> bool {
         <Self as IErc1155MetadataUri>::INTERFACE_ID
@@ -73,7 +96,7 @@ impl IErc165 for Erc1155MetadataUri {
 
 #[cfg(all(test, feature = "std"))]
 mod tests {
-    use alloy_primitives::Address;
+    use alloy_primitives::{Address, U256};
     use motsu::prelude::Contract;
     use stylus_sdk::{alloy_primitives::uint, prelude::TopLevelStorage};
 
@@ -98,6 +121,47 @@ mod tests {
         assert_eq!(uri, contract.sender(alice).uri(token_id));
     }
 
+    #[motsu::test]
+    fn uri_with_id_substitutes_token_id(
+        contract: Contract<Erc1155MetadataUri>,
+        alice: Address,
+    ) {
+        let uri = String::from("https://token-cdn-domain/{id}.json");
+        contract.init(alice, |contract| {
+            contract.uri.set_str(uri.clone());
+        });
+
+        assert_eq!(
+            "https://token-cdn-domain/000000000000000000000000000000000000000000000000000000000000004d.json",
+            contract.sender(alice).uri_with_id(uint!(77_U256))
+        );
+        assert_eq!(
+            "https://token-cdn-domain/ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff.json",
+            contract.sender(alice).uri_with_id(U256::MAX)
+        );
+        assert_eq!(
+            "https://token-cdn-domain/0000000000000000000000000000000100000000000000000000000000000abc.json",
+            contract.sender(alice).uri_with_id(
+                uint!(0x100000000000000000000000000000abc_U256)
+            )
+        );
+        // The raw template is left untouched.
+        assert_eq!(uri, contract.sender(alice).uri(uint!(77_U256)));
+    }
+
+    #[motsu::test]
+    fn uri_with_id_without_placeholder(
+        contract: Contract<Erc1155MetadataUri>,
+        alice: Address,
+    ) {
+        let uri = String::from("https://token-cdn-domain/collection.json");
+        contract.init(alice, |contract| {
+            contract.uri.set_str(uri.clone());
+        });
+
+        assert_eq!(uri, contract.sender(alice).uri_with_id(uint!(1_U256)));
+    }
+
     #[motsu::test]
     fn interface_id() {
         let actual = <Erc1155MetadataUri as IErc1155MetadataUri>::INTERFACE_ID;
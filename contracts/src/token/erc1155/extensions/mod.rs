@@ -1,10 +1,16 @@
 //! Common extensions to the ERC-1155 standard.
 pub mod burnable;
+pub mod metadata_extended;
 pub mod metadata_uri;
+pub mod split_id;
 pub mod supply;
 pub mod uri_storage;
 
 pub use burnable::IErc1155Burnable;
+pub use metadata_extended::{
+    Erc1155MetadataExtended, IErc1155MetadataExtended,
+};
 pub use metadata_uri::{Erc1155MetadataUri, IErc1155MetadataUri};
+pub use split_id::IErc1155SplitId;
 pub use supply::{Erc1155Supply, IErc1155Supply};
 pub use uri_storage::Erc1155UriStorage;
@@ -0,0 +1,308 @@
+//! Optional helpers for ERC-1155 token ids that encode a token type and an
+//! index, following the split-bit id scheme.
+//!
+//! The upper 128 bits of an id hold the base type and the lower 128 bits hold
+//! the index of a non-fungible item within that type. The most significant
+//! bit marks non-fungible types:
+//!
+//! * Fungible tokens have an id equal to their base type, with the non-fungible
+//!   bit unset.
+//! * Non-fungible tokens have an id equal to their base type, with the
+//!   non-fungible bit set, combined with a non-zero index.
+//!
+//! These helpers are opt-in: [`Erc1155`] itself does not interpret ids.
+
+use alloy_primitives::{Address, U256};
+use stylus_sdk::{abi::Bytes, call::MethodError, prelude::*};
+
+use crate::token::erc1155::{self, Erc1155};
+
+/// Bit marking the base type of non-fungible tokens.
+pub const TYPE_NF_BIT: U256 = U256::from_limbs([0, 0, 0, 1 << 63]);
+
+/// Mask selecting the base type of a token id.
+pub const TYPE_MASK: U256 = U256::from_limbs([0, 0, u64::MAX, u64::MAX]);
+
+/// Mask selecting the index of a non-fungible token within its type.
+pub const NF_INDEX_MASK: U256 = U256::from_limbs([u64::MAX, u64::MAX, 0, 0]);
+
+pub use sol::*;
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Indicates that `id` is not a valid base type or token id for the
+        /// requested operation.
+        ///
+        /// * `id` - Identifier number of a token, or of a base type.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC1155InvalidTokenType(uint256 id);
+    }
+}
+
+/// An error that occurred in the split id helpers.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Indicates that `id` is not a valid base type or token id for the
+    /// requested operation.
+    InvalidTokenType(ERC1155InvalidTokenType),
+    /// Error type from [`Erc1155`] contract [`erc1155::Error`].
+    Erc1155(erc1155::Error),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// Returns the base type of the token `id`.
+#[must_use]
+pub fn base_type(id: U256) -> U256 {
+    id & TYPE_MASK
+}
+
+/// Returns the index of the non-fungible token `id` within its base type.
+#[must_use]
+pub fn index(id: U256) -> U256 {
+    id & NF_INDEX_MASK
+}
+
+/// Returns true if the token `id` is a non-fungible token, i.e. has a
+/// non-fungible base type and a non-zero index.
+#[must_use]
+pub fn is_nft(id: U256) -> bool {
+    id & TYPE_NF_BIT == TYPE_NF_BIT && !index(id).is_zero()
+}
+
+/// Returns the id of the token at `index` within `base_type`.
+///
+/// Bits of `base_type` outside of [`TYPE_MASK`] and bits of `index` outside
+/// of [`NF_INDEX_MASK`] are ignored.
+#[must_use]
+pub fn pack(base_type: U256, index: U256) -> U256 {
+    (base_type & TYPE_MASK) | (index & NF_INDEX_MASK)
+}
+
+/// Convenience minting functions for [`Erc1155`] contracts using split ids.
+///
+/// NOTE: These functions are not access controlled, and are meant to be
+/// called from a restricted public minting function.
+pub trait IErc1155SplitId {
+    /// The error type associated to this trait implementation.
+    type Error: Into<alloc::vec::Vec<u8>>;
+
+    /// Mints the non-fungible token at `index` within `base_type` to `to`,
+    /// and returns its id.
+    ///
+    /// NOTE: Uniqueness of `index` within `base_type` is not checked, it is
+    /// up to the caller to not mint the same index twice, e.g. by keeping a
+    /// counter per base type.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `to` - Account of the recipient.
+    /// * `base_type` - Non-fungible base type of the token.
+    /// * `index` - Non-zero index of the token within `base_type`.
+    /// * `data` - Additional data with no specified format, sent in call to
+    ///   `to`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidTokenType`] - If `base_type` is not a non-fungible
+    ///   base type, or `index` is zero or doesn't fit into the index bits.
+    /// * [`erc1155::Error::InvalidReceiver`] - If `to` is `Address::ZERO`, or
+    ///   [`erc1155::IERC1155Receiver::on_erc_1155_received`] hasn't returned
+    ///   its interface id.
+    ///
+    /// # Events
+    ///
+    /// * [`erc1155::TransferSingle`].
+    fn mint_nft(
+        &mut self,
+        to: Address,
+        base_type: U256,
+        index: U256,
+        data: &Bytes,
+    ) -> Result<U256, Self::Error>;
+
+    /// Mints a `value` amount of the fungible token `base_type` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `to` - Account of the recipient.
+    /// * `base_type` - Fungible base type of the token, also its id.
+    /// * `value` - Amount of tokens to be minted.
+    /// * `data` - Additional data with no specified format, sent in call to
+    ///   `to`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidTokenType`] - If `base_type` is not a fungible base
+    ///   type.
+    /// * [`erc1155::Error::InvalidReceiver`] - If `to` is `Address::ZERO`, or
+    ///   [`erc1155::IERC1155Receiver::on_erc_1155_received`] hasn't returned
+    ///   its interface id.
+    ///
+    /// # Events
+    ///
+    /// * [`erc1155::TransferSingle`].
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds `U256::MAX`.
+    fn mint_fungible(
+        &mut self,
+        to: Address,
+        base_type: U256,
+        value: U256,
+        data: &Bytes,
+    ) -> Result<(), Self::Error>;
+}
+
+impl IErc1155SplitId for Erc1155 {
+    type Error = Error;
+
+    fn mint_nft(
+        &mut self,
+        to: Address,
+        base_type: U256,
+        index: U256,
+        data: &Bytes,
+    ) -> Result<U256, Self::Error> {
+        let id = pack(base_type, index);
+        if base_type & TYPE_MASK != base_type
+            || base_type & TYPE_NF_BIT != TYPE_NF_BIT
+            || index & NF_INDEX_MASK != index
+            || index.is_zero()
+        {
+            return Err(Error::InvalidTokenType(ERC1155InvalidTokenType {
+                id,
+            }));
+        }
+
+        self._mint(to, id, U256::from(1), data)?;
+        Ok(id)
+    }
+
+    fn mint_fungible(
+        &mut self,
+        to: Address,
+        base_type: U256,
+        value: U256,
+        data: &Bytes,
+    ) -> Result<(), Self::Error> {
+        if base_type & TYPE_NF_BIT == TYPE_NF_BIT
+            || base_type & TYPE_MASK != base_type
+        {
+            return Err(Error::InvalidTokenType(ERC1155InvalidTokenType {
+                id: base_type,
+            }));
+        }
+
+        Ok(self._mint(to, base_type, value, data)?)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{uint, Address, U256};
+    use motsu::prelude::*;
+
+    use super::*;
+    use crate::token::erc1155::IErc1155;
+
+    const NFT_TYPE: U256 = U256::from_limbs([0, 0, 1, 1 << 63]);
+    const FUNGIBLE_TYPE: U256 = U256::from_limbs([0, 0, 2, 0]);
+
+    #[test]
+    fn packs_and_unpacks_ids() {
+        let index_value = uint!(42_U256);
+        let id = pack(NFT_TYPE, index_value);
+
+        assert_eq!(NFT_TYPE, base_type(id));
+        assert_eq!(index_value, index(id));
+        assert!(is_nft(id));
+
+        assert!(!is_nft(NFT_TYPE));
+        assert!(!is_nft(FUNGIBLE_TYPE));
+        assert_eq!(FUNGIBLE_TYPE, base_type(FUNGIBLE_TYPE));
+        assert_eq!(U256::ZERO, index(FUNGIBLE_TYPE));
+
+        // Bits outside of the masks are ignored.
+        assert_eq!(id, pack(NFT_TYPE | index_value, index_value | NFT_TYPE));
+    }
+
+    #[motsu::test]
+    fn mints_nfts_within_type(contract: Contract<Erc1155>, alice: Address) {
+        let one = uint!(1_U256);
+        let two = uint!(2_U256);
+
+        let first = contract
+            .sender(alice)
+            .mint_nft(alice, NFT_TYPE, one, &vec![].into())
+            .motsu_unwrap();
+        let second = contract
+            .sender(alice)
+            .mint_nft(alice, NFT_TYPE, two, &vec![].into())
+            .motsu_unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(NFT_TYPE, base_type(first));
+        assert_eq!(NFT_TYPE, base_type(second));
+        assert_eq!(one, index(first));
+        assert_eq!(two, index(second));
+        assert_eq!(one, contract.sender(alice).balance_of(alice, first));
+        assert_eq!(one, contract.sender(alice).balance_of(alice, second));
+    }
+
+    #[motsu::test]
+    fn mint_nft_errors_on_invalid_type(
+        contract: Contract<Erc1155>,
+        alice: Address,
+    ) {
+        let one = uint!(1_U256);
+
+        let err = contract
+            .sender(alice)
+            .mint_nft(alice, FUNGIBLE_TYPE, one, &vec![].into())
+            .motsu_unwrap_err();
+        assert!(matches!(err, Error::InvalidTokenType(_)));
+
+        let err = contract
+            .sender(alice)
+            .mint_nft(alice, NFT_TYPE, U256::ZERO, &vec![].into())
+            .motsu_unwrap_err();
+        assert!(matches!(err, Error::InvalidTokenType(_)));
+
+        let err = contract
+            .sender(alice)
+            .mint_nft(alice, NFT_TYPE, TYPE_NF_BIT, &vec![].into())
+            .motsu_unwrap_err();
+        assert!(matches!(err, Error::InvalidTokenType(_)));
+    }
+
+    #[motsu::test]
+    fn mints_fungible(contract: Contract<Erc1155>, alice: Address) {
+        let value = uint!(10_U256);
+
+        contract
+            .sender(alice)
+            .mint_fungible(alice, FUNGIBLE_TYPE, value, &vec![].into())
+            .motsu_unwrap();
+        assert_eq!(
+            value,
+            contract.sender(alice).balance_of(alice, FUNGIBLE_TYPE)
+        );
+
+        let err = contract
+            .sender(alice)
+            .mint_fungible(alice, NFT_TYPE, value, &vec![].into())
+            .motsu_unwrap_err();
+        assert!(matches!(err, Error::InvalidTokenType(_)));
+    }
+}
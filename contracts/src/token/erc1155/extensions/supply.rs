@@ -18,7 +18,6 @@ use alloy_primitives::{Address, FixedBytes, U256};
 use openzeppelin_stylus_proc::interface_id;
 use stylus_sdk::{
     abi::Bytes,
-    msg,
     prelude::*,
     storage::{StorageMap, StorageU256},
 };
@@ -28,6 +27,7 @@ use crate::{
     utils::{
         introspection::erc165::{Erc165, IErc165},
         math::storage::{AddAssignChecked, SubAssignUnchecked},
+        Context,
     },
 };
 
@@ -119,7 +119,11 @@ impl IErc1155 for Erc1155Supply {
         operator: Address,
         approved: bool,
     ) -> Result<(), erc1155::Error> {
-        self.erc1155.set_approval_for_all(operator, approved)
+        self.erc1155.set_approval_for_all_with_sender(
+            self._msg_sender(),
+            operator,
+            approved,
+        )
     }
 
     fn is_approved_for_all(&self, account: Address, operator: Address) -> bool {
@@ -134,8 +138,14 @@ impl IErc1155 for Erc1155Supply {
         value: U256,
         data: Bytes,
     ) -> Result<(), erc1155::Error> {
-        self.erc1155.authorize_transfer(from)?;
-        self.do_safe_transfer_from(from, to, vec![id], vec![value], &data)
+        self.safe_transfer_from_with_sender(
+            self._msg_sender(),
+            from,
+            to,
+            id,
+            value,
+            &data,
+        )
     }
 
     fn safe_batch_transfer_from(
@@ -146,8 +156,113 @@ impl IErc1155 for Erc1155Supply {
         values: Vec<U256>,
         data: Bytes,
     ) -> Result<(), erc1155::Error> {
-        self.erc1155.authorize_transfer(from)?;
-        self.do_safe_transfer_from(from, to, ids, values, &data)
+        self.safe_batch_transfer_from_with_sender(
+            self._msg_sender(),
+            from,
+            to,
+            ids,
+            values,
+            &data,
+        )
+    }
+}
+
+impl Context for Erc1155Supply {}
+
+impl Erc1155Supply {
+    /// Same as [`IErc1155::safe_transfer_from`], acting for `sender` rather
+    /// than [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account performing the transfer.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `value` - Amount of tokens to be transferred.
+    /// * `data` - Additional data with no specified format, sent in call to
+    ///   `to`.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc1155::Error::MissingApprovalForAll`] - If `sender` is not `from`
+    ///   and has not been approved by `from`.
+    /// * [`erc1155::Error::InvalidReceiver`] - If `to` is the `Address::ZERO`.
+    /// * [`erc1155::Error::InvalidSender`] - If `from` is the `Address::ZERO`.
+    /// * [`erc1155::Error::InsufficientBalance`] - If `value` is greater than
+    ///   the balance of the `from` account.
+    /// * [`erc1155::Error::InvalidReceiver`] - If
+    ///   [`erc1155::IERC1155Receiver::on_erc_1155_received`] hasn't returned
+    ///   its interface id or returned with error.
+    ///
+    /// # Events
+    ///
+    /// * [`erc1155::TransferSingle`].
+    pub fn safe_transfer_from_with_sender(
+        &mut self,
+        sender: Address,
+        from: Address,
+        to: Address,
+        id: U256,
+        value: U256,
+        data: &Bytes,
+    ) -> Result<(), erc1155::Error> {
+        self.erc1155.authorize_transfer(sender, from)?;
+        self.do_safe_transfer_from(
+            sender,
+            from,
+            to,
+            vec![id],
+            vec![value],
+            data,
+        )
+    }
+
+    /// Same as [`IErc1155::safe_batch_transfer_from`], acting for `sender`
+    /// rather than [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account performing the transfer.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all tokens ids.
+    /// * `values` - Array of all amount of tokens to be transferred.
+    /// * `data` - Additional data with no specified format, sent in call to
+    ///   `to`.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc1155::Error::MissingApprovalForAll`] - If `sender` is not `from`
+    ///   and has not been approved by `from`.
+    /// * [`erc1155::Error::InvalidReceiver`] - If `to` is the `Address::ZERO`.
+    /// * [`erc1155::Error::InvalidSender`] - If `from` is the `Address::ZERO`.
+    /// * [`erc1155::Error::InvalidArrayLength`] - If length of `ids` is not
+    ///   equal to length of `values`.
+    /// * [`erc1155::Error::InsufficientBalance`] - If any of the `values` is
+    ///   greater than the balance of the respective token of the `from`
+    ///   account.
+    /// * [`erc1155::Error::InvalidReceiver`] - If
+    ///   [`erc1155::IERC1155Receiver::on_erc_1155_batch_received`] hasn't
+    ///   returned its interface id or returned with error.
+    ///
+    /// # Events
+    ///
+    /// * [`erc1155::TransferSingle`] - If the arrays contain one element.
+    /// * [`erc1155::TransferBatch`] - If the arrays contain multiple elements.
+    pub fn safe_batch_transfer_from_with_sender(
+        &mut self,
+        sender: Address,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        data: &Bytes,
+    ) -> Result<(), erc1155::Error> {
+        self.erc1155.authorize_transfer(sender, from)?;
+        self.do_safe_transfer_from(sender, from, to, ids, values, data)
     }
 }
 
@@ -218,6 +333,7 @@ impl Erc1155Supply {
     /// # Arguments
     ///
     /// * `&mut self` - Write access to the contract's state.
+    /// * `operator` - Account performing the update.
     /// * `from` - Account of the sender.
     /// * `to` - Account of the recipient.
     /// * `token_ids` - Array of all token id.
@@ -242,12 +358,19 @@ impl Erc1155Supply {
     ///   during the `mint` operation.
     fn _update(
         &mut self,
+        operator: Address,
         from: Address,
         to: Address,
         token_ids: Vec<U256>,
         values: Vec<U256>,
     ) -> Result<(), erc1155::Error> {
-        self.erc1155._update(from, to, token_ids.clone(), values.clone())?;
+        self.erc1155._update(
+            operator,
+            from,
+            to,
+            token_ids.clone(),
+            values.clone(),
+        )?;
 
         if from.is_zero() {
             for (&token_id, &value) in token_ids.iter().zip(values.iter()) {
@@ -287,17 +410,18 @@ impl Erc1155Supply {
 
     fn _update_with_acceptance_check(
         &mut self,
+        operator: Address,
         from: Address,
         to: Address,
         ids: Vec<U256>,
         values: Vec<U256>,
         data: &Bytes,
     ) -> Result<(), erc1155::Error> {
-        self._update(from, to, ids.clone(), values.clone())?;
+        self._update(operator, from, to, ids.clone(), values.clone())?;
 
         if !to.is_zero() {
             self.erc1155._check_on_erc1155_received(
-                msg::sender(),
+                operator,
                 from,
                 to,
                 erc1155::Erc1155ReceiverData::new(ids, values),
@@ -321,6 +445,7 @@ impl Erc1155Supply {
             ));
         }
         self._update_with_acceptance_check(
+            self._msg_sender(),
             Address::ZERO,
             to,
             ids,
@@ -342,6 +467,7 @@ impl Erc1155Supply {
             ));
         }
         self._update_with_acceptance_check(
+            self._msg_sender(),
             from,
             Address::ZERO,
             ids,
@@ -353,6 +479,7 @@ impl Erc1155Supply {
 
     fn do_safe_transfer_from(
         &mut self,
+        operator: Address,
         from: Address,
         to: Address,
         ids: Vec<U256>,
@@ -369,7 +496,9 @@ impl Erc1155Supply {
                 erc1155::ERC1155InvalidSender { sender: from },
             ));
         }
-        self._update_with_acceptance_check(from, to, ids, values, data)
+        self._update_with_acceptance_check(
+            operator, from, to, ids, values, data,
+        )
     }
 }
 
@@ -385,7 +514,7 @@ impl IErc165 for Erc1155Supply {
 
 #[cfg(all(test, feature = "std"))]
 mod tests {
-    use alloy_primitives::{Address, U256};
+    use alloy_primitives::{uint, Address, U256};
     use motsu::prelude::Contract;
     use stylus_sdk::prelude::TopLevelStorage;
 
@@ -393,7 +522,8 @@ mod tests {
     use crate::{
         token::erc1155::{
             tests::{random_token_ids, random_values},
-            ERC1155InvalidReceiver, ERC1155InvalidSender, Error, IErc1155,
+            ERC1155InvalidArrayLength, ERC1155InvalidReceiver,
+            ERC1155InvalidSender, Error, IErc1155,
         },
         utils::introspection::erc165::IErc165,
     };
@@ -486,6 +616,65 @@ mod tests {
         ));
     }
 
+    #[motsu::test]
+    fn mint_batch_reverts_on_invalid_receiver(
+        contract: Contract<Erc1155Supply>,
+        alice: Address,
+    ) {
+        let token_ids = random_token_ids(2);
+        let values = random_values(2);
+        let invalid_receiver = Address::ZERO;
+
+        let err = contract
+            .sender(alice)
+            ._mint_batch(
+                invalid_receiver,
+                token_ids.clone(),
+                values,
+                &vec![].into(),
+            )
+            .expect_err("should revert with `InvalidReceiver`");
+
+        assert!(matches!(
+            err,
+            Error::InvalidReceiver(ERC1155InvalidReceiver {
+                receiver
+            }) if receiver == invalid_receiver
+        ));
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).total_supply(token_ids[0])
+        );
+        assert_eq!(U256::ZERO, contract.sender(alice).total_supply_all());
+    }
+
+    #[motsu::test]
+    fn mint_batch_reverts_on_not_equal_arrays(
+        contract: Contract<Erc1155Supply>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let token_ids = random_token_ids(3);
+        let values = random_values(2);
+
+        let err = contract
+            .sender(alice)
+            ._mint_batch(bob, token_ids.clone(), values, &vec![].into())
+            .expect_err("should revert with `InvalidArrayLength`");
+
+        assert!(matches!(
+            err,
+            Error::InvalidArrayLength(ERC1155InvalidArrayLength {
+                ids_length, values_length
+            }) if ids_length == uint!(3_U256) && values_length == uint!(2_U256)
+        ));
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).total_supply(token_ids[0])
+        );
+        assert_eq!(U256::ZERO, contract.sender(alice).total_supply_all());
+    }
+
     #[motsu::test]
     #[should_panic = "should not exceed `U256::MAX` for `total_supply`"]
     fn mint_panics_on_total_supply_overflow(
@@ -578,6 +767,89 @@ mod tests {
         assert_eq!(U256::ZERO, contract.sender(alice).total_supply_all());
     }
 
+    #[motsu::test]
+    fn exists_until_whole_supply_is_burnt(
+        contract: Contract<Erc1155Supply>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let token_id = uint!(1_U256);
+        contract
+            .sender(alice)
+            ._mint(bob, token_id, uint!(10_U256), &vec![].into())
+            .expect("should mint");
+
+        contract
+            .sender(alice)
+            ._burn(bob, token_id, uint!(4_U256))
+            .expect("should burn");
+        assert_eq!(
+            uint!(6_U256),
+            contract.sender(alice).total_supply(token_id)
+        );
+        assert!(contract.sender(alice).exists(token_id));
+
+        contract
+            .sender(alice)
+            ._burn(bob, token_id, uint!(6_U256))
+            .expect("should burn");
+        assert_eq!(U256::ZERO, contract.sender(alice).total_supply(token_id));
+        assert_eq!(U256::ZERO, contract.sender(alice).total_supply_all());
+        assert!(!contract.sender(alice).exists(token_id));
+
+        // Minting the token again makes it exist again.
+        contract
+            .sender(alice)
+            ._mint(bob, token_id, uint!(1_U256), &vec![].into())
+            .expect("should mint");
+        assert!(contract.sender(alice).exists(token_id));
+    }
+
+    #[motsu::test]
+    fn batch_operations_update_each_supply_independently(
+        contract: Contract<Erc1155Supply>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let (token_ids, values) =
+            contract.init(alice, |contract| init(contract, bob, 3));
+
+        // Burn the whole supply of the first token, part of the second one,
+        // and none of the third one.
+        let burnt = vec![values[0], values[1] - uint!(1_U256), U256::ZERO];
+        contract
+            .sender(alice)
+            ._burn_batch(bob, token_ids.clone(), burnt)
+            .expect("should burn batch");
+
+        let expected = [U256::ZERO, uint!(1_U256), values[2]];
+        for (&token_id, &supply) in token_ids.iter().zip(expected.iter()) {
+            assert_eq!(supply, contract.sender(alice).total_supply(token_id));
+            assert_eq!(
+                !supply.is_zero(),
+                contract.sender(alice).exists(token_id)
+            );
+        }
+        let total_supply_all: U256 = expected.iter().sum();
+        assert_eq!(total_supply_all, contract.sender(alice).total_supply_all());
+
+        // Transfers move balances without changing any supply.
+        contract
+            .sender(bob)
+            .safe_batch_transfer_from(
+                bob,
+                alice,
+                token_ids[1..].to_vec(),
+                expected[1..].to_vec(),
+                vec![].into(),
+            )
+            .expect("should transfer batch");
+        for (&token_id, &supply) in token_ids.iter().zip(expected.iter()) {
+            assert_eq!(supply, contract.sender(alice).total_supply(token_id));
+        }
+        assert_eq!(total_supply_all, contract.sender(alice).total_supply_all());
+    }
+
     #[motsu::test]
     fn burn_reverts_when_invalid_sender(
         contract: Contract<Erc1155Supply>,
@@ -601,6 +873,33 @@ mod tests {
         ));
     }
 
+    #[motsu::test]
+    fn burn_batch_reverts_on_not_equal_arrays(
+        contract: Contract<Erc1155Supply>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let (token_ids, values) =
+            contract.init(alice, |contract| init(contract, bob, 3));
+        let total_supply_all: U256 = values.iter().sum();
+
+        let err = contract
+            .sender(alice)
+            ._burn_batch(bob, token_ids.clone(), values[..2].to_vec())
+            .expect_err("should revert with `InvalidArrayLength`");
+
+        assert!(matches!(
+            err,
+            Error::InvalidArrayLength(ERC1155InvalidArrayLength {
+                ids_length, values_length
+            }) if ids_length == uint!(3_U256) && values_length == uint!(2_U256)
+        ));
+        for (&token_id, &value) in token_ids.iter().zip(values.iter()) {
+            assert_eq!(value, contract.sender(alice).total_supply(token_id));
+        }
+        assert_eq!(total_supply_all, contract.sender(alice).total_supply_all());
+    }
+
     #[motsu::test]
     fn supply_unaffected_by_no_op(
         contract: Contract<Erc1155Supply>,
@@ -611,7 +910,13 @@ mod tests {
 
         contract
             .sender(alice)
-            ._update(Address::ZERO, Address::ZERO, token_ids.clone(), values)
+            ._update(
+                alice,
+                Address::ZERO,
+                Address::ZERO,
+                token_ids.clone(),
+                values,
+            )
             .expect("should supply");
         assert_eq!(
             U256::ZERO,
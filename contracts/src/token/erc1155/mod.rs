@@ -6,7 +6,7 @@ use openzeppelin_stylus_proc::interface_id;
 use stylus_sdk::{
     abi::Bytes,
     call::{self, Call, MethodError},
-    evm, function_selector, msg,
+    evm, function_selector,
     prelude::*,
     storage::{StorageBool, StorageMap, StorageU256},
 };
@@ -14,6 +14,7 @@ use stylus_sdk::{
 use crate::utils::{
     introspection::erc165::{Erc165, IErc165},
     math::storage::{AddAssignChecked, SubAssignUnchecked},
+    Context,
 };
 
 pub mod extensions;
@@ -151,6 +152,20 @@ mod sol {
         #[derive(Debug)]
         #[allow(missing_docs)]
         error ERC1155InvalidArrayLength(uint256 ids_length, uint256 values_length);
+
+        /// Indicates that an empty array of token identifiers was provided
+        /// where at least one is required.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC1155EmptyIds();
+
+        /// Indicates that the sum of the balances of `account` exceeds
+        /// `U256::MAX`.
+        ///
+        /// * `account` - Account of the tokens' owner.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC1155TotalBalanceOverflow(address account);
     }
 }
 
@@ -188,6 +203,12 @@ pub enum Error {
     /// [`Erc1155::safe_batch_transfer_from`] operation.
     /// Used in batch transfers.
     InvalidArrayLength(ERC1155InvalidArrayLength),
+    /// Indicates that no token identifiers were provided. Used in
+    /// [`Erc1155::total_balance_of`].
+    EmptyIds(ERC1155EmptyIds),
+    /// Indicates that the sum of the balances of an account exceeds
+    /// `U256::MAX`. Used in [`Erc1155::total_balance_of`].
+    TotalBalanceOverflow(ERC1155TotalBalanceOverflow),
 }
 
 impl MethodError for Error {
@@ -228,6 +249,9 @@ pub trait IErc1155: IErc165 {
 
     /// Batched version of [`IErc1155::balance_of`].
     ///
+    /// Returns the balance of each `accounts[i]` for `ids[i]`, in the same
+    /// order as the input arrays.
+    ///
     /// # Arguments
     ///
     /// * `&self` - Read access to the contract's state.
@@ -253,11 +277,12 @@ pub trait IErc1155: IErc165 {
     /// * `operator` - Account to add to the set of authorized operators.
     /// * `approved` - Flag that determines whether or not permission will be
     ///   granted to `operator`. If true, this means `operator` will be allowed
-    ///   to manage `msg::sender()`'s assets.
+    ///   to manage the assets of [`Context::_msg_sender`].
     ///
     /// # Errors
     ///
-    /// * [`Error::InvalidOperator`] - If `operator` is `Address::ZERO`.
+    /// * [`Error::InvalidOperator`] - If `operator` is `Address::ZERO` or
+    ///   [`Context::_msg_sender`].
     ///
     /// # Events
     ///
@@ -298,8 +323,8 @@ pub trait IErc1155: IErc165 {
     ///   interface id or returned with error.
     /// * [`Error::InvalidSender`] - Returned when `from` is `Address::ZERO`.
     /// * [`Error::MissingApprovalForAll`] - Returned when `from` is not the
-    ///   caller (`msg::sender()`), and the caller does not have the right to
-    ///   approve.
+    ///   caller ([`Context::_msg_sender`]), and the caller does not have the
+    ///   right to approve.
     /// * [`Error::InsufficientBalance`] - Returned when `value` is greater than
     ///   the balance of the `from` account.
     ///
@@ -339,8 +364,8 @@ pub trait IErc1155: IErc165 {
     /// * [`Error::InsufficientBalance`] - Returned when any of the `values` is
     ///   greater than the balance of the `from` account.
     /// * [`Error::MissingApprovalForAll`] - Returned when `from` is not the
-    ///   caller (`msg::sender()`), and the caller does not have the right to
-    ///   approve.
+    ///   caller ([`Context::_msg_sender`]), and the caller does not have the
+    ///   right to approve.
     ///
     /// # Events
     ///
@@ -386,7 +411,11 @@ impl IErc1155 for Erc1155 {
         operator: Address,
         approved: bool,
     ) -> Result<(), Self::Error> {
-        self._set_approval_for_all(msg::sender(), operator, approved)
+        self.set_approval_for_all_with_sender(
+            self._msg_sender(),
+            operator,
+            approved,
+        )
     }
 
     fn is_approved_for_all(&self, account: Address, operator: Address) -> bool {
@@ -401,8 +430,14 @@ impl IErc1155 for Erc1155 {
         value: U256,
         data: Bytes,
     ) -> Result<(), Self::Error> {
-        self.authorize_transfer(from)?;
-        self.do_safe_transfer_from(from, to, vec![id], vec![value], &data)
+        self.safe_transfer_from_with_sender(
+            self._msg_sender(),
+            from,
+            to,
+            id,
+            value,
+            &data,
+        )
     }
 
     fn safe_batch_transfer_from(
@@ -413,11 +448,19 @@ impl IErc1155 for Erc1155 {
         values: Vec<U256>,
         data: Bytes,
     ) -> Result<(), Self::Error> {
-        self.authorize_transfer(from)?;
-        self.do_safe_transfer_from(from, to, ids, values, &data)
+        self.safe_batch_transfer_from_with_sender(
+            self._msg_sender(),
+            from,
+            to,
+            ids,
+            values,
+            &data,
+        )
     }
 }
 
+impl Context for Erc1155 {}
+
 impl IErc165 for Erc1155 {
     fn supports_interface(interface_id: FixedBytes<4>) -> bool {
         <Self as IErc1155>::INTERFACE_ID == u32::from_be_bytes(*interface_id)
@@ -426,6 +469,136 @@ impl IErc165 for Erc1155 {
 }
 
 impl Erc1155 {
+    /// Same as [`IErc1155::set_approval_for_all`], acting for `sender` rather
+    /// than [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account whose assets `operator` may manage.
+    /// * `operator` - Account to add to the set of authorized operators.
+    /// * `approved` - Flag that determines whether or not permission will be
+    ///   granted to `operator`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidOperator`] - If `operator` is `Address::ZERO` or
+    ///   `sender`.
+    ///
+    /// # Events
+    ///
+    /// * [`ApprovalForAll`].
+    pub fn set_approval_for_all_with_sender(
+        &mut self,
+        sender: Address,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), Error> {
+        self._set_approval_for_all(sender, operator, approved)
+    }
+
+    /// Same as [`IErc1155::safe_transfer_from`], acting for `sender` rather
+    /// than [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account performing the transfer.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account of the recipient.
+    /// * `id` - Token id as a number.
+    /// * `value` - Amount of tokens to be transferred.
+    /// * `data` - Additional data with no specified format, sent in call to
+    ///   `to`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::MissingApprovalForAll`] - If `sender` is not `from` and has
+    ///   not been approved by `from`.
+    /// * [`Error::InvalidReceiver`] - If `to` is the `Address::ZERO`.
+    /// * [`Error::InvalidSender`] - If `from` is the `Address::ZERO`.
+    /// * [`Error::InsufficientBalance`] - If `value` is greater than the
+    ///   balance of the `from` account.
+    /// * [`Error::InvalidReceiver`] - If
+    ///   [`IERC1155Receiver::on_erc_1155_received`] hasn't returned its
+    ///   interface id or returned with error.
+    ///
+    /// # Events
+    ///
+    /// * [`TransferSingle`].
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds `U256::MAX`.
+    pub fn safe_transfer_from_with_sender(
+        &mut self,
+        sender: Address,
+        from: Address,
+        to: Address,
+        id: U256,
+        value: U256,
+        data: &Bytes,
+    ) -> Result<(), Error> {
+        self.authorize_transfer(sender, from)?;
+        self.do_safe_transfer_from(
+            sender,
+            from,
+            to,
+            vec![id],
+            vec![value],
+            data,
+        )
+    }
+
+    /// Same as [`IErc1155::safe_batch_transfer_from`], acting for `sender`
+    /// rather than [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account performing the transfer.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account of the recipient.
+    /// * `ids` - Array of all tokens ids.
+    /// * `values` - Array of all amount of tokens to be transferred.
+    /// * `data` - Additional data with no specified format, sent in call to
+    ///   `to`.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::MissingApprovalForAll`] - If `sender` is not `from` and has
+    ///   not been approved by `from`.
+    /// * [`Error::InvalidReceiver`] - If `to` is the `Address::ZERO`.
+    /// * [`Error::InvalidSender`] - If `from` is the `Address::ZERO`.
+    /// * [`Error::InvalidArrayLength`] - If length of `ids` is not equal to
+    ///   length of `values`.
+    /// * [`Error::InsufficientBalance`] - If any of the `values` is greater
+    ///   than the balance of the respective token of the `from` account.
+    /// * [`Error::InvalidReceiver`] - If
+    ///   [`IERC1155Receiver::on_erc_1155_batch_received`] hasn't returned its
+    ///   interface id or returned with error.
+    ///
+    /// # Events
+    ///
+    /// * [`TransferSingle`] - If the arrays contain one element.
+    /// * [`TransferBatch`] - If the arrays contain multiple elements.
+    ///
+    /// # Panics
+    ///
+    /// * If updated balance exceeds `U256::MAX`.
+    pub fn safe_batch_transfer_from_with_sender(
+        &mut self,
+        sender: Address,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        data: &Bytes,
+    ) -> Result<(), Error> {
+        self.authorize_transfer(sender, from)?;
+        self.do_safe_transfer_from(sender, from, to, ids, values, data)
+    }
+
     /// Transfers a `value` amount of tokens of type `ids` from `from` to
     /// `to`. Will mint (or burn) if `from` (or `to`) is the `Address::ZERO`.
     ///
@@ -435,6 +608,7 @@ impl Erc1155 {
     /// # Arguments
     ///
     /// * `&mut self` - Write access to the contract's state.
+    /// * `operator` - Account performing the update.
     /// * `from` - Account to transfer tokens from.
     /// * `to` - Account of the recipient.
     /// * `ids` - Array of all tokens ids.
@@ -459,6 +633,7 @@ impl Erc1155 {
     ///   operation.
     fn _update(
         &mut self,
+        operator: Address,
         from: Address,
         to: Address,
         ids: Vec<U256>,
@@ -466,8 +641,6 @@ impl Erc1155 {
     ) -> Result<(), Error> {
         Self::require_equal_arrays_length(&ids, &values)?;
 
-        let operator = msg::sender();
-
         for (&token_id, &value) in ids.iter().zip(values.iter()) {
             self.do_update(from, to, token_id, value)?;
         }
@@ -491,6 +664,7 @@ impl Erc1155 {
     /// # Arguments
     ///
     /// * `&mut self` - Write access to the contract's state.
+    /// * `operator` - Account performing the update.
     /// * `from` - Account to transfer tokens from.
     /// * `to` - Account of the recipient.
     /// * `ids` - Array of all token ids.
@@ -520,17 +694,18 @@ impl Erc1155 {
     ///   operation.
     fn _update_with_acceptance_check(
         &mut self,
+        operator: Address,
         from: Address,
         to: Address,
         ids: Vec<U256>,
         values: Vec<U256>,
         data: &Bytes,
     ) -> Result<(), Error> {
-        self._update(from, to, ids.clone(), values.clone())?;
+        self._update(operator, from, to, ids.clone(), values.clone())?;
 
         if !to.is_zero() {
             self._check_on_erc1155_received(
-                msg::sender(),
+                operator,
                 from,
                 to,
                 Erc1155ReceiverData::new(ids, values),
@@ -677,6 +852,74 @@ impl Erc1155 {
         self._do_burn(from, ids, values)
     }
 
+    /// Version of [`IErc1155::balance_of_batch`] querying the balances of a
+    /// single `account`.
+    ///
+    /// Returns the balance of `account` for each of the `ids`, in the same
+    /// order.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account of the tokens' owner.
+    /// * `ids` - All token identifiers.
+    #[must_use]
+    pub fn balance_of_batch_single_account(
+        &self,
+        account: Address,
+        ids: &[U256],
+    ) -> Vec<U256> {
+        ids.iter().map(|&token_id| self.balance_of(account, token_id)).collect()
+    }
+
+    /// Returns the sum of the balances of `account` for each of the `ids`.
+    ///
+    /// Duplicate `ids` are counted as many times as they appear.
+    ///
+    /// NOTE: To expose this function in your contract's ABI, implement it as
+    /// shown in the Examples section below. The implementation should forward
+    /// the call to your internal storage instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account of the tokens' owner.
+    /// * `ids` - All token identifiers.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::EmptyIds`] - If `ids` is empty.
+    /// * [`Error::TotalBalanceOverflow`] - If the sum of the balances exceeds
+    ///   `U256::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// fn total_balance_of(
+    ///     &self,
+    ///     account: Address,
+    ///     ids: Vec<U256>,
+    /// ) -> Result<U256, erc1155::Error> {
+    ///     self.erc1155.total_balance_of(account, &ids)
+    /// }
+    /// ```
+    pub fn total_balance_of(
+        &self,
+        account: Address,
+        ids: &[U256],
+    ) -> Result<U256, Error> {
+        if ids.is_empty() {
+            return Err(Error::EmptyIds(ERC1155EmptyIds {}));
+        }
+
+        self.balance_of_batch_single_account(account, ids)
+            .into_iter()
+            .try_fold(U256::ZERO, U256::checked_add)
+            .ok_or(Error::TotalBalanceOverflow(ERC1155TotalBalanceOverflow {
+                account,
+            }))
+    }
+
     /// Approve `operator` to operate on all of `owner` tokens.
     ///
     /// # Arguments
@@ -690,7 +933,8 @@ impl Erc1155 {
     ///
     /// # Errors
     ///
-    /// * [`Error::InvalidOperator`] - If `operator` is the `Address::ZERO`.
+    /// * [`Error::InvalidOperator`] - If `operator` is the `Address::ZERO` or
+    ///   `owner`, since approving oneself has no effect.
     ///
     /// # Events
     ///
@@ -701,7 +945,7 @@ impl Erc1155 {
         operator: Address,
         approved: bool,
     ) -> Result<(), Error> {
-        if operator.is_zero() {
+        if operator.is_zero() || operator == owner {
             return Err(Error::InvalidOperator(ERC1155InvalidOperator {
                 operator,
             }));
@@ -839,6 +1083,7 @@ impl Erc1155 {
             }));
         }
         self._update_with_acceptance_check(
+            self._msg_sender(),
             Address::ZERO,
             to,
             ids,
@@ -883,6 +1128,7 @@ impl Erc1155 {
             }));
         }
         self._update_with_acceptance_check(
+            self._msg_sender(),
             from,
             Address::ZERO,
             ids,
@@ -897,6 +1143,7 @@ impl Erc1155 {
     /// # Arguments
     ///
     /// * `&mut self` - Write access to the contract's state.
+    /// * `operator` - Account performing the transfer.
     /// * `from` - Account to transfer tokens from.
     /// * `to` - Account of the recipient.
     /// * `ids` - Array of all token ids.
@@ -929,6 +1176,7 @@ impl Erc1155 {
     /// * If updated balance exceeds `U256::MAX`.
     fn do_safe_transfer_from(
         &mut self,
+        operator: Address,
         from: Address,
         to: Address,
         ids: Vec<U256>,
@@ -945,7 +1193,9 @@ impl Erc1155 {
                 sender: from,
             }));
         }
-        self._update_with_acceptance_check(from, to, ids, values, data)
+        self._update_with_acceptance_check(
+            operator, from, to, ids, values, data,
+        )
     }
 
     /// Transfers a `value` amount of `token_id` from `from` to
@@ -1026,19 +1276,23 @@ impl Erc1155 {
         Ok(())
     }
 
-    /// Checks if `msg::sender()` is authorized to transfer tokens.
+    /// Checks if `sender` is authorized to transfer tokens.
     ///
     /// # Arguments
     ///
     /// * `&self` - Read access to the contract's state.
+    /// * `sender` - Account performing the transfer.
     /// * `from` - Account to transfer tokens from.
     ///
     /// # Errors
     ///
-    /// * [`Error::MissingApprovalForAll`] -  If the `from` is not the caller
-    ///   (`msg::sender()`), and the caller does not have the right to approve.
-    fn authorize_transfer(&self, from: Address) -> Result<(), Error> {
-        let sender = msg::sender();
+    /// * [`Error::MissingApprovalForAll`] -  If the `from` is not `sender`, and
+    ///   `sender` does not have the right to approve.
+    fn authorize_transfer(
+        &self,
+        sender: Address,
+        from: Address,
+    ) -> Result<(), Error> {
         if from != sender && !self.is_approved_for_all(from, sender) {
             return Err(Error::MissingApprovalForAll(
                 ERC1155MissingApprovalForAll { operator: sender, owner: from },
@@ -1132,13 +1386,14 @@ enum Transfer {
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use alloy_primitives::{uint, Address, U256};
-    use motsu::prelude::Contract;
+    use motsu::prelude::*;
 
     use super::{
-        ERC1155InsufficientBalance, ERC1155InvalidArrayLength,
+        ApprovalForAll, ERC1155InsufficientBalance, ERC1155InvalidArrayLength,
         ERC1155InvalidOperator, ERC1155InvalidReceiver, ERC1155InvalidSender,
-        ERC1155MissingApprovalForAll, Erc1155, Erc1155ReceiverData, Error,
-        IErc1155, Transfer, BATCH_TRANSFER_FN_SELECTOR,
+        ERC1155MissingApprovalForAll, ERC1155TotalBalanceOverflow, Erc1155,
+        Erc1155ReceiverData, Error, IErc1155, Transfer, TransferBatch,
+        TransferSingle, BATCH_TRANSFER_FN_SELECTOR,
         SINGLE_TRANSFER_FN_SELECTOR,
     };
     use crate::utils::introspection::erc165::IErc165;
@@ -1246,6 +1501,137 @@ mod tests {
         assert_eq!(expected, balances);
     }
 
+    #[motsu::test]
+    fn balance_of_batch_preserves_input_order(
+        contract: Contract<Erc1155>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let (token_ids, values) = contract.init(alice, |contract| {
+            let (token_ids, values) = init(contract, alice, 3);
+            contract
+                ._mint(bob, token_ids[0], uint!(100_U256), &vec![].into())
+                .expect("Mint failed");
+            (token_ids, values)
+        });
+
+        let balances = contract
+            .sender(alice)
+            .balance_of_batch(
+                vec![bob, alice, alice, bob, alice],
+                vec![
+                    token_ids[0],
+                    token_ids[2],
+                    token_ids[0],
+                    token_ids[1],
+                    token_ids[1],
+                ],
+            )
+            .expect("should return balances in input order");
+
+        assert_eq!(
+            vec![uint!(100_U256), values[2], values[0], U256::ZERO, values[1]],
+            balances
+        );
+    }
+
+    #[motsu::test]
+    fn balance_of_batch_single_account(
+        contract: Contract<Erc1155>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let (token_ids, values) =
+            contract.init(alice, |contract| init(contract, alice, 3));
+        let ids = vec![token_ids[2], token_ids[0], token_ids[1], token_ids[2]];
+
+        let balances =
+            contract.sender(alice).balance_of_batch_single_account(alice, &ids);
+        assert_eq!(vec![values[2], values[0], values[1], values[2]], balances);
+        assert_eq!(
+            balances,
+            contract
+                .sender(alice)
+                .balance_of_batch(vec![alice; ids.len()], ids.clone())
+                .expect("should return balances")
+        );
+
+        assert_eq!(
+            vec![U256::ZERO; ids.len()],
+            contract.sender(alice).balance_of_batch_single_account(bob, &ids)
+        );
+        assert!(contract
+            .sender(alice)
+            .balance_of_batch_single_account(alice, &[])
+            .is_empty());
+    }
+
+    #[motsu::test]
+    fn total_balance_of(
+        contract: Contract<Erc1155>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let (token_ids, values) =
+            contract.init(alice, |contract| init(contract, alice, 3));
+
+        let total = contract
+            .sender(alice)
+            .total_balance_of(alice, &token_ids)
+            .expect("should sum balances");
+        assert_eq!(values[0] + values[1] + values[2], total);
+
+        let total = contract
+            .sender(alice)
+            .total_balance_of(alice, &[token_ids[1], token_ids[1]])
+            .expect("should sum balances");
+        assert_eq!(values[1] + values[1], total);
+
+        let total = contract
+            .sender(alice)
+            .total_balance_of(bob, &token_ids)
+            .expect("should sum balances");
+        assert_eq!(U256::ZERO, total);
+    }
+
+    #[motsu::test]
+    fn error_when_total_balance_of_empty_ids(
+        contract: Contract<Erc1155>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .total_balance_of(alice, &[])
+            .expect_err("should reject empty ids");
+        assert!(matches!(err, Error::EmptyIds(_)));
+    }
+
+    #[motsu::test]
+    fn error_when_total_balance_of_overflows(
+        contract: Contract<Erc1155>,
+        alice: Address,
+    ) {
+        let token_ids = random_token_ids(2);
+        contract.init(alice, |contract| {
+            for &token_id in &token_ids {
+                contract
+                    ._mint(alice, token_id, U256::MAX, &vec![].into())
+                    .expect("should mint tokens");
+            }
+        });
+
+        let err = contract
+            .sender(alice)
+            .total_balance_of(alice, &token_ids)
+            .expect_err("should reject a total balance above `U256::MAX`");
+        assert!(matches!(
+            err,
+            Error::TotalBalanceOverflow(ERC1155TotalBalanceOverflow {
+                account
+            }) if account == alice
+        ));
+    }
+
     #[motsu::test]
     fn set_approval_for_all(
         contract: Contract<Erc1155>,
@@ -1288,6 +1674,30 @@ mod tests {
         ));
     }
 
+    #[motsu::test]
+    fn error_when_self_set_approval_for_all(
+        contract: Contract<Erc1155>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .set_approval_for_all(alice, true)
+            .expect_err("should not approve the caller as its own operator");
+
+        assert!(matches!(
+            err,
+            Error::InvalidOperator(ERC1155InvalidOperator {
+                operator
+            }) if operator == alice
+        ));
+        assert!(!contract.sender(alice).is_approved_for_all(alice, alice));
+        assert!(!contract.emitted(&ApprovalForAll {
+            account: alice,
+            operator: alice,
+            approved: true,
+        }));
+    }
+
     #[motsu::test]
     fn mints(contract: Contract<Erc1155>, alice: Address) {
         let token_id = random_token_ids(1)[0];
@@ -1838,6 +2248,7 @@ mod tests {
         let err = contract
             .sender(alice)
             .do_safe_transfer_from(
+                alice,
                 dave,
                 invalid_receiver,
                 token_ids,
@@ -2351,6 +2762,144 @@ mod tests {
         ));
     }
 
+    #[motsu::test]
+    fn single_and_one_element_batch_operations_match(
+        single: Contract<Erc1155>,
+        batch: Contract<Erc1155>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let id = uint!(1_U256);
+        let value = uint!(10_U256);
+        let amount = uint!(4_U256);
+        let burnt = uint!(3_U256);
+
+        single
+            .sender(alice)
+            ._mint(alice, id, value, &vec![].into())
+            .expect("should mint tokens for Alice");
+        batch
+            .sender(alice)
+            ._mint_batch(alice, vec![id], vec![value], &vec![].into())
+            .expect("should mint tokens for Alice");
+
+        single
+            .sender(alice)
+            .safe_transfer_from(alice, bob, id, amount, vec![].into())
+            .expect("should transfer tokens from Alice to Bob");
+        batch
+            .sender(alice)
+            .safe_batch_transfer_from(
+                alice,
+                bob,
+                vec![id],
+                vec![amount],
+                vec![].into(),
+            )
+            .expect("should transfer tokens from Alice to Bob");
+
+        single
+            .sender(bob)
+            ._burn(bob, id, burnt)
+            .expect("should burn Bob's tokens");
+        batch
+            .sender(bob)
+            ._burn_batch(bob, vec![id], vec![burnt])
+            .expect("should burn Bob's tokens");
+
+        for account in [alice, bob] {
+            assert_eq!(
+                single.sender(alice).balance_of(account, id),
+                batch.sender(alice).balance_of(account, id)
+            );
+        }
+        assert_eq!(value - amount, single.sender(alice).balance_of(alice, id));
+        assert_eq!(amount - burnt, single.sender(alice).balance_of(bob, id));
+
+        // Both paths emit `TransferSingle` for every operation.
+        for contract in [&single, &batch] {
+            contract.assert_emitted(&TransferSingle {
+                operator: alice,
+                from: Address::ZERO,
+                to: alice,
+                id,
+                value,
+            });
+            contract.assert_emitted(&TransferSingle {
+                operator: alice,
+                from: alice,
+                to: bob,
+                id,
+                value: amount,
+            });
+            contract.assert_emitted(&TransferSingle {
+                operator: bob,
+                from: bob,
+                to: Address::ZERO,
+                id,
+                value: burnt,
+            });
+            assert!(!contract.emitted(&TransferBatch {
+                operator: alice,
+                from: alice,
+                to: bob,
+                ids: vec![id],
+                values: vec![amount],
+            }));
+        }
+    }
+
+    #[motsu::test]
+    fn batch_operations_emit_transfer_batch(
+        contract: Contract<Erc1155>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let ids = random_token_ids(2);
+        let values = random_values(2);
+
+        contract
+            .sender(alice)
+            ._mint_batch(alice, ids.clone(), values.clone(), &vec![].into())
+            .expect("should mint tokens for Alice");
+        contract.assert_emitted(&TransferBatch {
+            operator: alice,
+            from: Address::ZERO,
+            to: alice,
+            ids: ids.clone(),
+            values: values.clone(),
+        });
+
+        contract
+            .sender(alice)
+            .safe_batch_transfer_from(
+                alice,
+                bob,
+                ids.clone(),
+                values.clone(),
+                vec![].into(),
+            )
+            .expect("should transfer tokens from Alice to Bob");
+        contract.assert_emitted(&TransferBatch {
+            operator: alice,
+            from: alice,
+            to: bob,
+            ids: ids.clone(),
+            values: values.clone(),
+        });
+
+        for (&id, &value) in ids.iter().zip(values.iter()) {
+            assert_eq!(value, contract.sender(alice).balance_of(bob, id));
+            assert!(!contract.emitted(&TransferSingle {
+                operator: alice,
+                from: alice,
+                to: bob,
+                id,
+                value,
+            }));
+        }
+    }
+
     #[motsu::test]
     fn interface_id() {
         let actual = <Erc1155 as IErc1155>::INTERFACE_ID;
@@ -1,9 +1,11 @@
 //! Optional Burnable extension of the ERC-20 standard.
 
 use alloy_primitives::{Address, U256};
-use stylus_sdk::msg;
 
-use crate::token::erc20::{self, Erc20};
+use crate::{
+    token::erc20::{self, Erc20, FailureMode},
+    utils::Context,
+};
 
 /// Extension of [`Erc20`] that allows token holders to destroy both
 /// their own tokens and those that they have an allowance for,
@@ -56,11 +58,11 @@ pub trait IErc20Burnable {
     ) -> Result<(), Self::Error>;
 }
 
-impl IErc20Burnable for Erc20 {
+impl<M: FailureMode> IErc20Burnable for Erc20<M> {
     type Error = erc20::Error;
 
     fn burn(&mut self, value: U256) -> Result<(), Self::Error> {
-        self._burn(msg::sender(), value)
+        self.burn_with_sender(self._msg_sender(), value)
     }
 
     fn burn_from(
@@ -68,7 +70,65 @@ impl IErc20Burnable for Erc20 {
         account: Address,
         value: U256,
     ) -> Result<(), Self::Error> {
-        self._spend_allowance(account, msg::sender(), value)?;
+        self.burn_from_with_sender(self._msg_sender(), account, value)
+    }
+}
+
+impl<M: FailureMode> Erc20<M> {
+    /// Same as [`IErc20Burnable::burn`], acting for `sender` rather than
+    /// [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account burning its tokens.
+    /// * `value` - Amount to be burnt.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc20::Error::InsufficientBalance`] - If `sender` doesn't have
+    ///   enough tokens.
+    ///
+    /// # Events
+    ///
+    /// * [`erc20::Transfer`].
+    pub fn burn_with_sender(
+        &mut self,
+        sender: Address,
+        value: U256,
+    ) -> Result<(), erc20::Error> {
+        self._burn(sender, value)
+    }
+
+    /// Same as [`IErc20Burnable::burn_from`], acting for `sender` rather than
+    /// [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account spending its allowance.
+    /// * `account` - Owner's address.
+    /// * `value` - Amount to be burnt.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc20::Error::InsufficientAllowance`] - If not enough allowance is
+    ///   available.
+    /// * [`erc20::Error::InvalidSender`] - If the `account` address is
+    ///   `Address::ZERO`.
+    /// * [`erc20::Error::InsufficientBalance`] - If `account` doesn't have
+    ///   enough tokens.
+    ///
+    /// # Events
+    ///
+    /// * [`erc20::Transfer`].
+    pub fn burn_from_with_sender(
+        &mut self,
+        sender: Address,
+        account: Address,
+        value: U256,
+    ) -> Result<(), erc20::Error> {
+        self._spend_allowance(account, sender, value)?;
         self._burn(account, value)
     }
 }
@@ -4,17 +4,29 @@
 //!
 //! Note that they will not be capped by simply including this module,
 //! but only once the checks are put in place.
+//!
+//! The cap is immutable, unless a governance-controlled token exposes
+//! [`Capped::_set_cap`] behind its own access control to raise it.
 
 use alloc::{vec, vec::Vec};
 
 use alloy_primitives::U256;
 pub use sol::*;
-use stylus_sdk::{call::MethodError, prelude::*, storage::StorageU256};
+use stylus_sdk::{call::MethodError, evm, prelude::*, storage::StorageU256};
+
+use crate::token::erc20::{Erc20, IErc20};
 
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod sol {
     use alloy_sol_macro::sol;
 
+    sol! {
+        /// Emitted when the cap is raised from `previous_cap` to `new_cap`.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event CapUpdated(uint256 previous_cap, uint256 new_cap);
+    }
+
     sol! {
         /// Indicates an error related to the operation that failed
         /// because `total_supply` exceeded the `cap`.
@@ -62,13 +74,65 @@ impl Capped {
     }
 }
 
+impl Capped {
+    /// Raises the cap on the token's total supply to `new_cap`.
+    ///
+    /// Internal function without access restriction, meant to be exposed by
+    /// governance-controlled tokens only.
+    ///
+    /// Setting the current cap again is a no-op, and emits no event.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `new_cap` - The new cap on the token's total supply.
+    /// * `erc20` - Read access to the [`Erc20`] contract of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidCap`] - If `new_cap` is zero, or lower than the
+    ///   current cap.
+    /// * [`Error::ExceededCap`] - If `new_cap` is lower than the token's total
+    ///   supply.
+    ///
+    /// # Events
+    ///
+    /// * [`CapUpdated`] - If `new_cap` differs from the current cap.
+    pub fn _set_cap(
+        &mut self,
+        new_cap: U256,
+        erc20: &Erc20,
+    ) -> Result<(), Error> {
+        let previous_cap = self.cap();
+        if new_cap.is_zero() || new_cap < previous_cap {
+            return Err(Error::InvalidCap(ERC20InvalidCap { cap: new_cap }));
+        }
+        if new_cap == previous_cap {
+            return Ok(());
+        }
+
+        let total_supply = erc20.total_supply();
+        if total_supply > new_cap {
+            return Err(Error::ExceededCap(ERC20ExceededCap {
+                increased_supply: total_supply,
+                cap: new_cap,
+            }));
+        }
+
+        self.cap.set(new_cap);
+        evm::log(CapUpdated { previous_cap, new_cap });
+        Ok(())
+    }
+}
+
 #[cfg(all(test, feature = "std"))]
 mod tests {
-    use alloy_primitives::{uint, Address};
-    use motsu::prelude::Contract;
-    use stylus_sdk::prelude::TopLevelStorage;
+    use alloy_primitives::{uint, Address, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::prelude::*;
 
-    use super::Capped;
+    use super::{CapUpdated, Capped, ERC20ExceededCap, ERC20InvalidCap, Error};
+    use crate::token::erc20::{Erc20, IErc20};
 
     unsafe impl TopLevelStorage for Capped {}
 
@@ -82,4 +146,147 @@ mod tests {
         contract.init(alice, |contract| contract.cap.set(value));
         assert_eq!(contract.sender(alice).cap(), value);
     }
+
+    /// Token checking the cap on mint, with a cap raised by governance.
+    #[storage]
+    struct CappedToken {
+        erc20: Erc20,
+        capped: Capped,
+    }
+
+    unsafe impl TopLevelStorage for CappedToken {}
+
+    #[public]
+    impl CappedToken {
+        fn mint(&mut self, account: Address, value: U256) -> Result<(), Error> {
+            let cap = self.capped.cap();
+            let increased_supply = self.erc20.total_supply() + value;
+            if increased_supply > cap {
+                return Err(Error::ExceededCap(ERC20ExceededCap {
+                    increased_supply,
+                    cap,
+                }));
+            }
+            self.erc20._mint(account, value).expect("should mint tokens");
+            Ok(())
+        }
+
+        fn set_cap(&mut self, new_cap: U256) -> Result<(), Error> {
+            self.capped._set_cap(new_cap, &self.erc20)
+        }
+    }
+
+    fn init_token(contract: &Contract<CappedToken>, alice: Address) {
+        contract.init(alice, |contract| {
+            contract.capped.cap.set(uint!(100_U256));
+        });
+        contract.sender(alice).mint(alice, uint!(100_U256)).motsu_unwrap();
+    }
+
+    #[motsu::test]
+    fn raises_cap(contract: Contract<CappedToken>, alice: Address) {
+        init_token(&contract, alice);
+        let err = contract
+            .sender(alice)
+            .mint(alice, uint!(1_U256))
+            .motsu_unwrap_err();
+        assert!(matches!(err, Error::ExceededCap(_)));
+
+        contract.sender(alice).set_cap(uint!(150_U256)).motsu_unwrap();
+
+        assert_eq!(uint!(150_U256), contract.sender(alice).capped.cap());
+        contract.assert_emitted(&CapUpdated {
+            previous_cap: uint!(100_U256),
+            new_cap: uint!(150_U256),
+        });
+    }
+
+    #[motsu::test]
+    fn mint_respects_raised_cap(
+        contract: Contract<CappedToken>,
+        alice: Address,
+    ) {
+        init_token(&contract, alice);
+        contract.sender(alice).set_cap(uint!(150_U256)).motsu_unwrap();
+
+        contract.sender(alice).mint(alice, uint!(50_U256)).motsu_unwrap();
+        assert_eq!(
+            uint!(150_U256),
+            contract.sender(alice).erc20.total_supply()
+        );
+
+        let err = contract
+            .sender(alice)
+            .mint(alice, uint!(1_U256))
+            .motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            Error::ExceededCap(ERC20ExceededCap { increased_supply, cap })
+                if increased_supply == uint!(151_U256) && cap == uint!(150_U256)
+        ));
+    }
+
+    #[motsu::test]
+    fn error_when_lowering_cap(
+        contract: Contract<CappedToken>,
+        alice: Address,
+    ) {
+        init_token(&contract, alice);
+
+        let err =
+            contract.sender(alice).set_cap(uint!(99_U256)).motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidCap(ERC20InvalidCap { cap }) if cap == uint!(99_U256)
+        ));
+        assert_eq!(uint!(100_U256), contract.sender(alice).capped.cap());
+    }
+
+    #[motsu::test]
+    fn error_when_setting_zero_cap(
+        contract: Contract<CappedToken>,
+        alice: Address,
+    ) {
+        let err = contract.sender(alice).set_cap(U256::ZERO).motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidCap(ERC20InvalidCap { cap }) if cap.is_zero()
+        ));
+    }
+
+    #[motsu::test]
+    fn setting_same_cap_is_noop(
+        contract: Contract<CappedToken>,
+        alice: Address,
+    ) {
+        init_token(&contract, alice);
+
+        contract.sender(alice).set_cap(uint!(100_U256)).motsu_unwrap();
+
+        assert_eq!(uint!(100_U256), contract.sender(alice).capped.cap());
+        assert!(!contract.emitted(&CapUpdated {
+            previous_cap: uint!(100_U256),
+            new_cap: uint!(100_U256),
+        }));
+    }
+
+    #[motsu::test]
+    fn error_when_setting_cap_below_supply(
+        contract: Contract<CappedToken>,
+        alice: Address,
+    ) {
+        // Supply minted without checking the cap, e.g. before it was set.
+        contract.init(alice, |contract| {
+            contract.erc20._mint(alice, uint!(100_U256)).motsu_unwrap();
+        });
+
+        let err =
+            contract.sender(alice).set_cap(uint!(50_U256)).motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            Error::ExceededCap(ERC20ExceededCap { increased_supply, cap })
+                if increased_supply == uint!(100_U256) && cap == uint!(50_U256)
+        ));
+        assert_eq!(U256::ZERO, contract.sender(alice).capped.cap());
+    }
 }
@@ -6,6 +6,12 @@
 //! extends the ERC-20 standard. Any additional extensions included along it
 //! would affect the "shares" token represented by this contract and not the
 //! "assets" token which is an independent contract.
+//!
+//! Optional entry and exit fees, in basis points, can be charged on deposits
+//! and withdrawals. Fees are disabled by default, and configured with
+//! [`Erc4626::_set_entry_fee_basis_points`],
+//! [`Erc4626::_set_exit_fee_basis_points`] and
+//! [`Erc4626::_set_fee_recipient`].
 
 use alloc::{vec, vec::Vec};
 
@@ -13,9 +19,9 @@ use alloy_primitives::{uint, Address, FixedBytes, U256, U8};
 pub use sol::*;
 use stylus_sdk::{
     call::{Call, MethodError},
-    contract, evm, msg,
+    contract, evm,
     prelude::*,
-    storage::{StorageAddress, StorageU8},
+    storage::{StorageAddress, StorageU256, StorageU8},
 };
 
 use crate::{
@@ -26,12 +32,14 @@ use crate::{
     },
     utils::{
         introspection::erc165::{Erc165, IErc165},
-        math::alloy::{Math, Rounding},
+        math::{alloy::Math, Rounding},
+        Context,
     },
 };
 
 const ONE: U256 = uint!(1_U256);
 const TEN: U256 = uint!(10_U256);
+const BASIS_POINT_SCALE: U256 = uint!(10_000_U256);
 
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod sol {
@@ -102,6 +110,14 @@ mod sol {
         #[derive(Debug)]
         #[allow(missing_docs)]
         error InvalidAsset(address asset);
+
+        /// Indicates a fee greater than the whole amount it is charged on.
+        ///
+        /// * `fee_basis_points` - Fee in basis points.
+        /// * `max` - Maximum fee in basis points.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC4626InvalidFee(uint256 fee_basis_points, uint256 max);
     }
 }
 
@@ -122,6 +138,8 @@ pub enum Error {
     ExceededMaxRedeem(ERC4626ExceededMaxRedeem),
     /// The address is not a valid ERC-20 token.
     InvalidAsset(InvalidAsset),
+    /// Indicates a fee greater than the whole amount it is charged on.
+    InvalidFee(ERC4626InvalidFee),
     /// Error type from [`SafeErc20`] contract [`safe_erc20::Error`].
     SafeErc20(safe_erc20::Error),
     /// Error type from [`Erc20`] contract [`erc20::Error`].
@@ -143,6 +161,12 @@ pub struct Erc4626 {
     pub(crate) underlying_decimals: StorageU8,
     /// Decimals offset.
     pub(crate) decimals_offset: StorageU8,
+    /// Fee charged on deposits, in basis points.
+    pub(crate) entry_fee_basis_points: StorageU256,
+    /// Fee charged on withdrawals, in basis points.
+    pub(crate) exit_fee_basis_points: StorageU256,
+    /// Account receiving entry and exit fees.
+    pub(crate) fee_recipient: StorageAddress,
     /// [`SafeErc20`] contract.
     safe_erc20: SafeErc20,
 }
@@ -328,6 +352,10 @@ pub trait IErc4626 {
     /// Returns the maximum amount of the underlying asset that can be deposited
     /// into the Vault for the receiver, through a deposit call.
     ///
+    /// NOTE: Contracts restricting deposits, e.g. while paused, should return
+    /// a lower limit from their own `max_deposit`, and deposit through
+    /// [`Erc4626::_deposit_with_max`].
+    ///
     /// # Arguments
     ///
     /// * `&self` - Read access to the contract's state.
@@ -435,6 +463,10 @@ pub trait IErc4626 {
     /// Returns the maximum amount of the Vault shares that can be minted for
     /// the receiver, through a mint call.
     ///
+    /// NOTE: Contracts restricting mints, e.g. while paused, should return a
+    /// lower limit from their own `max_mint`, and mint through
+    /// [`Erc4626::_mint_with_max`].
+    ///
     /// # Arguments
     ///
     /// * `&self` - Read access to the contract's state.
@@ -545,6 +577,10 @@ pub trait IErc4626 {
     /// Returns the maximum amount of the underlying asset that can be withdrawn
     /// from the owner balance in the Vault, through a withdraw call.
     ///
+    /// Contracts restricting withdrawals, e.g. while frozen, should return a
+    /// lower limit from their own `max_withdraw`, and withdraw through
+    /// [`Erc4626::_withdraw_with_max`].
+    ///
     /// NOTE: To expose this function in your contract's ABI, implement it as
     /// shown in the Examples section below, accepting only the `owner`
     /// parameter. The `erc20` reference should come from your contract's state.
@@ -686,6 +722,10 @@ pub trait IErc4626 {
     /// Returns the maximum amount of Vault shares that can be redeemed from the
     /// owner balance in the Vault, through a redeem call.
     ///
+    /// Contracts restricting redemptions, e.g. while frozen, should return a
+    /// lower limit from their own `max_redeem`, and redeem through
+    /// [`Erc4626::_redeem_with_max`].
+    ///
     /// NOTE: To expose this function in your contract's ABI, implement it as
     /// shown in the Examples section below, accepting only the `owner`
     /// parameter. The `erc20` reference should come from your contract's state.
@@ -866,7 +906,8 @@ impl IErc4626 for Erc4626 {
         assets: U256,
         erc20: &Erc20,
     ) -> Result<U256, Self::Error> {
-        self._convert_to_shares(assets, Rounding::Floor, erc20)
+        let fee = fee_on_total(assets, self._entry_fee_basis_points());
+        self._convert_to_shares(assets - fee, Rounding::Floor, erc20)
     }
 
     fn preview_mint(
@@ -874,7 +915,11 @@ impl IErc4626 for Erc4626 {
         shares: U256,
         erc20: &Erc20,
     ) -> Result<U256, Self::Error> {
-        self._convert_to_assets(shares, Rounding::Ceil, erc20)
+        let assets = self._convert_to_assets(shares, Rounding::Ceil, erc20)?;
+        let fee = fee_on_raw(assets, self._entry_fee_basis_points());
+        Ok(assets
+            .checked_add(fee)
+            .expect("assets overflow in `Erc4626::preview_mint`"))
     }
 
     fn preview_withdraw(
@@ -882,6 +927,10 @@ impl IErc4626 for Erc4626 {
         assets: U256,
         erc20: &Erc20,
     ) -> Result<U256, Self::Error> {
+        let fee = fee_on_raw(assets, self._exit_fee_basis_points());
+        let assets = assets
+            .checked_add(fee)
+            .expect("assets overflow in `Erc4626::preview_withdraw`");
         self._convert_to_shares(assets, Rounding::Ceil, erc20)
     }
 
@@ -890,7 +939,9 @@ impl IErc4626 for Erc4626 {
         shares: U256,
         erc20: &Erc20,
     ) -> Result<U256, Self::Error> {
-        self._convert_to_assets(shares, Rounding::Floor, erc20)
+        let assets = self._convert_to_assets(shares, Rounding::Floor, erc20)?;
+        let fee = fee_on_total(assets, self._exit_fee_basis_points());
+        Ok(assets - fee)
     }
 
     fn deposit(
@@ -900,7 +951,150 @@ impl IErc4626 for Erc4626 {
         erc20: &mut Erc20,
     ) -> Result<U256, Self::Error> {
         let max_assets = self.max_deposit(receiver);
+        self._deposit_with_max(
+            self._msg_sender(),
+            assets,
+            receiver,
+            max_assets,
+            erc20,
+        )
+    }
+
+    fn mint(
+        &mut self,
+        shares: U256,
+        receiver: Address,
+        erc20: &mut Erc20,
+    ) -> Result<U256, Error> {
+        let max_shares = self.max_mint(receiver);
+        self._mint_with_max(
+            self._msg_sender(),
+            shares,
+            receiver,
+            max_shares,
+            erc20,
+        )
+    }
+
+    fn withdraw(
+        &mut self,
+        assets: U256,
+        receiver: Address,
+        owner: Address,
+        erc20: &mut Erc20,
+    ) -> Result<U256, Error> {
+        let max_assets = self.max_withdraw(owner, erc20)?;
+        self._withdraw_with_max(
+            self._msg_sender(),
+            assets,
+            receiver,
+            owner,
+            max_assets,
+            erc20,
+        )
+    }
+
+    fn redeem(
+        &mut self,
+        shares: U256,
+        receiver: Address,
+        owner: Address,
+        erc20: &mut Erc20,
+    ) -> Result<U256, Self::Error> {
+        let max_shares = self.max_redeem(owner, erc20);
+        self._redeem_with_max(
+            self._msg_sender(),
+            shares,
+            receiver,
+            owner,
+            max_shares,
+            erc20,
+        )
+    }
+}
+
+impl Context for Erc4626 {}
+
+impl Erc4626 {
+    /// Returns the number of decimals used in representing vault shares. Adds
+    /// the decimals offset to the underlying token's decimals.
+    ///
+    /// NOTE: To expose this function in your contract's ABI, implement it as
+    /// shown in the Examples section below. The implementation should forward
+    /// the call to your internal storage instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    ///
+    /// # Panics
+    ///
+    /// * When adding the offset decimals to the underlying token's decimals
+    ///   would exceed `U8::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    ///     fn decimals(&self) -> U8 {
+    ///         self.erc4626.decimals()
+    ///     }
+    /// ```
+    pub fn decimals(&self) -> U8 {
+        self.underlying_decimals
+            .get()
+            .checked_add(self._decimals_offset())
+            .expect("Decimals should not be greater than `U8::MAX`")
+    }
 
+    /// Deposits `assets` for `receiver` as [`IErc4626::deposit`] does, but
+    /// up to `max_assets` rather than [`IErc4626::max_deposit`].
+    ///
+    /// Contracts restricting deposits, e.g. while paused, expose their own
+    /// `max_deposit` and pass its result here:
+    ///
+    /// ```rust,ignore
+    /// fn max_deposit(&self, receiver: Address) -> U256 {
+    ///     if self.pausable.paused() {
+    ///         U256::ZERO
+    ///     } else {
+    ///         self.erc4626.max_deposit(receiver)
+    ///     }
+    /// }
+    ///
+    /// fn deposit(&mut self, assets: U256, receiver: Address) -> Result<U256, erc4626::Error> {
+    ///     let max_assets = self.max_deposit(receiver);
+    ///     self.erc4626._deposit_with_max(
+    ///         self._msg_sender(),
+    ///         assets,
+    ///         receiver,
+    ///         max_assets,
+    ///         &mut self.erc20,
+    ///     )
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `caller` - Account performing the operation, e.g.
+    ///   [`Context::_msg_sender`].
+    /// * `assets` - Amount of the underlying asset to deposit.
+    /// * `receiver` - The address receiving the shares.
+    /// * `max_assets` - Maximum amount of assets `receiver` may deposit.
+    /// * `erc20` - Write access to an [`Erc20`] contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExceededMaxDeposit`] - If `assets` exceeds `max_assets`.
+    /// * Any error of [`IErc4626::deposit`].
+    pub fn _deposit_with_max(
+        &mut self,
+        caller: Address,
+        assets: U256,
+        receiver: Address,
+        max_assets: U256,
+        erc20: &mut Erc20,
+    ) -> Result<U256, Error> {
         if assets > max_assets {
             return Err(Error::ExceededMaxDeposit(ERC4626ExceededMaxDeposit {
                 receiver,
@@ -911,19 +1105,38 @@ impl IErc4626 for Erc4626 {
 
         let shares = self.preview_deposit(assets, erc20)?;
 
-        self._deposit(msg::sender(), receiver, assets, shares, erc20)?;
+        self._deposit(caller, receiver, assets, shares, erc20)?;
 
         Ok(shares)
     }
 
-    fn mint(
+    /// Mints `shares` for `receiver` as [`IErc4626::mint`] does, but up to
+    /// `max_shares` rather than [`IErc4626::max_mint`].
+    ///
+    /// See [`Self::_deposit_with_max`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `caller` - Account performing the operation, e.g.
+    ///   [`Context::_msg_sender`].
+    /// * `shares` - Amount of vault shares to mint.
+    /// * `receiver` - The address receiving the shares.
+    /// * `max_shares` - Maximum amount of shares `receiver` may mint.
+    /// * `erc20` - Write access to an [`Erc20`] contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExceededMaxMint`] - If `shares` exceeds `max_shares`.
+    /// * Any error of [`IErc4626::mint`].
+    pub fn _mint_with_max(
         &mut self,
+        caller: Address,
         shares: U256,
         receiver: Address,
+        max_shares: U256,
         erc20: &mut Erc20,
     ) -> Result<U256, Error> {
-        let max_shares = self.max_mint(receiver);
-
         if shares > max_shares {
             return Err(Error::ExceededMaxMint(ERC4626ExceededMaxMint {
                 receiver,
@@ -933,20 +1146,41 @@ impl IErc4626 for Erc4626 {
         }
 
         let assets = self.preview_mint(shares, erc20)?;
-        self._deposit(msg::sender(), receiver, assets, shares, erc20)?;
+        self._deposit(caller, receiver, assets, shares, erc20)?;
 
         Ok(assets)
     }
 
-    fn withdraw(
+    /// Withdraws `assets` from `owner` to `receiver` as
+    /// [`IErc4626::withdraw`] does, but up to `max_assets` rather than
+    /// [`IErc4626::max_withdraw`].
+    ///
+    /// See [`Self::_deposit_with_max`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `caller` - Account performing the operation, e.g.
+    ///   [`Context::_msg_sender`].
+    /// * `assets` - Amount of the underlying asset to withdraw.
+    /// * `receiver` - The address receiving the assets.
+    /// * `owner` - The address of the entity owning the shares.
+    /// * `max_assets` - Maximum amount of assets `owner` may withdraw.
+    /// * `erc20` - Write access to an [`Erc20`] contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExceededMaxWithdraw`] - If `assets` exceeds `max_assets`.
+    /// * Any error of [`IErc4626::withdraw`].
+    pub fn _withdraw_with_max(
         &mut self,
+        caller: Address,
         assets: U256,
         receiver: Address,
         owner: Address,
+        max_assets: U256,
         erc20: &mut Erc20,
     ) -> Result<U256, Error> {
-        let max_assets = self.max_withdraw(owner, erc20)?;
-
         if assets > max_assets {
             return Err(Error::ExceededMaxWithdraw(
                 ERC4626ExceededMaxWithdraw { owner, assets, max: max_assets },
@@ -954,19 +1188,40 @@ impl IErc4626 for Erc4626 {
         }
 
         let shares = self.preview_withdraw(assets, erc20)?;
-        self._withdraw(msg::sender(), receiver, owner, assets, shares, erc20)?;
+        self._withdraw(caller, receiver, owner, assets, shares, erc20)?;
 
         Ok(shares)
     }
 
-    fn redeem(
+    /// Redeems `shares` of `owner` for `receiver` as [`IErc4626::redeem`]
+    /// does, but up to `max_shares` rather than [`IErc4626::max_redeem`].
+    ///
+    /// See [`Self::_deposit_with_max`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `caller` - Account performing the operation, e.g.
+    ///   [`Context::_msg_sender`].
+    /// * `shares` - Amount of vault shares to redeem.
+    /// * `receiver` - The address receiving the assets.
+    /// * `owner` - The address of the entity owning the shares.
+    /// * `max_shares` - Maximum amount of shares `owner` may redeem.
+    /// * `erc20` - Write access to an [`Erc20`] contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExceededMaxRedeem`] - If `shares` exceeds `max_shares`.
+    /// * Any error of [`IErc4626::redeem`].
+    pub fn _redeem_with_max(
         &mut self,
+        caller: Address,
         shares: U256,
         receiver: Address,
         owner: Address,
+        max_shares: U256,
         erc20: &mut Erc20,
-    ) -> Result<U256, Self::Error> {
-        let max_shares = self.max_redeem(owner, erc20);
+    ) -> Result<U256, Error> {
         if shares > max_shares {
             return Err(Error::ExceededMaxRedeem(ERC4626ExceededMaxRedeem {
                 owner,
@@ -977,42 +1232,10 @@ impl IErc4626 for Erc4626 {
 
         let assets = self.preview_redeem(shares, erc20)?;
 
-        self._withdraw(msg::sender(), receiver, owner, assets, shares, erc20)?;
+        self._withdraw(caller, receiver, owner, assets, shares, erc20)?;
 
         Ok(assets)
     }
-}
-
-impl Erc4626 {
-    /// Returns the number of decimals used in representing vault shares. Adds
-    /// the decimals offset to the underlying token's decimals.
-    ///
-    /// NOTE: To expose this function in your contract's ABI, implement it as
-    /// shown in the Examples section below. The implementation should forward
-    /// the call to your internal storage instance.
-    ///
-    /// # Arguments
-    ///
-    /// * `&self` - Read access to the contract's state.
-    ///
-    /// # Panics
-    ///
-    /// * When adding the offset decimals to the underlying token's decimals
-    ///   would exceed `U8::MAX`.
-    ///
-    /// # Examples
-    ///
-    /// ```rust,ignore
-    ///     fn decimals(&self) -> U8 {
-    ///         self.erc4626.decimals()
-    ///     }
-    /// ```
-    pub fn decimals(&self) -> U8 {
-        self.underlying_decimals
-            .get()
-            .checked_add(self._decimals_offset())
-            .expect("Decimals should not be greater than `U8::MAX`")
-    }
 
     /// Converts a given amount of assets to shares using the specified
     /// `rounding` mode.
@@ -1140,6 +1363,8 @@ impl Erc4626 {
         // reentrancy would happen before the assets are transferred and before
         // the shares are minted, which is a valid state.
 
+        let fee = fee_on_total(assets, self._entry_fee_basis_points());
+
         self.safe_erc20.safe_transfer_from(
             self.asset(),
             caller,
@@ -1151,7 +1376,7 @@ impl Erc4626 {
 
         evm::log(Deposit { sender: caller, owner: receiver, assets, shares });
 
-        Ok(())
+        self._transfer_fee(fee)
     }
 
     /// Withdraw/redeem common workflow.
@@ -1197,21 +1422,180 @@ impl Erc4626 {
         // reentrancy would happen after the shares are burned and after the
         // assets are transferred, which is a valid state.
 
+        let fee = fee_on_raw(assets, self._exit_fee_basis_points());
+
         erc20._burn(owner, shares)?;
 
         self.safe_erc20.safe_transfer(self.asset(), receiver, assets)?;
 
         evm::log(Withdraw { sender: caller, receiver, owner, assets, shares });
 
+        self._transfer_fee(fee)
+    }
+
+    /// Transfers `fee` assets from the vault to [`Self::_fee_recipient`].
+    ///
+    /// Fees are kept in the vault, i.e. shared among share holders, if the
+    /// recipient is `Address::ZERO` or the vault itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `fee` - Amount of underlying tokens charged as fee.
+    ///
+    /// # Errors
+    ///
+    /// * [`safe_erc20::Error::SafeErc20FailedOperation`] - If transfer fails.
+    fn _transfer_fee(&mut self, fee: U256) -> Result<(), Error> {
+        let recipient = self._fee_recipient();
+        if fee.is_zero()
+            || recipient.is_zero()
+            || recipient == contract::address()
+        {
+            return Ok(());
+        }
+
+        self.safe_erc20.safe_transfer(self.asset(), recipient, fee)?;
         Ok(())
     }
 
     /// Returns the decimals offset between the underlying asset and vault
-    /// shares.
-    /// Currently, always returns `U8::ZERO`.
-    fn _decimals_offset(&self) -> U8 {
+    /// shares. Defaults to zero.
+    ///
+    /// Conversions account for `10^offset` virtual shares and one virtual
+    /// asset, so that a non-zero offset makes inflating the share price with
+    /// a donation to the vault unprofitable for a first depositor.
+    #[must_use]
+    pub fn _decimals_offset(&self) -> U8 {
         self.decimals_offset.get()
     }
+
+    /// Sets the decimals offset between the underlying asset and vault
+    /// shares.
+    ///
+    /// CAUTION: Must be called before the first deposit, since changing the
+    /// offset afterwards changes the value of the shares already minted.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `decimals_offset` - Decimals offset of the vault shares.
+    pub fn _set_decimals_offset(&mut self, decimals_offset: U8) {
+        self.decimals_offset.set(decimals_offset);
+    }
+
+    /// Returns the fee charged on deposits and mints, in basis points.
+    ///
+    /// The fee is included in the assets transferred by the caller, and
+    /// rounded up in favor of the vault. Defaults to zero.
+    #[must_use]
+    pub fn _entry_fee_basis_points(&self) -> U256 {
+        self.entry_fee_basis_points.get()
+    }
+
+    /// Returns the fee charged on withdrawals and redemptions, in basis
+    /// points.
+    ///
+    /// The fee is paid with additional shares burned from the owner, and
+    /// rounded up in favor of the vault. Defaults to zero.
+    #[must_use]
+    pub fn _exit_fee_basis_points(&self) -> U256 {
+        self.exit_fee_basis_points.get()
+    }
+
+    /// Returns the account receiving entry and exit fees.
+    #[must_use]
+    pub fn _fee_recipient(&self) -> Address {
+        self.fee_recipient.get()
+    }
+
+    /// Sets the fee charged on deposits and mints, in basis points.
+    ///
+    /// Internal function without access restriction.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `fee_basis_points` - Entry fee in basis points.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidFee`] - If `fee_basis_points` is greater than
+    ///   `10_000`, i.e. 100%.
+    pub fn _set_entry_fee_basis_points(
+        &mut self,
+        fee_basis_points: U256,
+    ) -> Result<(), Error> {
+        check_fee(fee_basis_points)?;
+        self.entry_fee_basis_points.set(fee_basis_points);
+        Ok(())
+    }
+
+    /// Sets the fee charged on withdrawals and redemptions, in basis points.
+    ///
+    /// Internal function without access restriction.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `fee_basis_points` - Exit fee in basis points.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidFee`] - If `fee_basis_points` is greater than
+    ///   `10_000`, i.e. 100%.
+    pub fn _set_exit_fee_basis_points(
+        &mut self,
+        fee_basis_points: U256,
+    ) -> Result<(), Error> {
+        check_fee(fee_basis_points)?;
+        self.exit_fee_basis_points.set(fee_basis_points);
+        Ok(())
+    }
+
+    /// Sets the account receiving entry and exit fees.
+    ///
+    /// Internal function without access restriction.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `fee_recipient` - Account receiving the fees.
+    pub fn _set_fee_recipient(&mut self, fee_recipient: Address) {
+        self.fee_recipient.set(fee_recipient);
+    }
+}
+
+/// Checks that `fee_basis_points` doesn't exceed [`BASIS_POINT_SCALE`].
+fn check_fee(fee_basis_points: U256) -> Result<(), Error> {
+    if fee_basis_points > BASIS_POINT_SCALE {
+        return Err(Error::InvalidFee(ERC4626InvalidFee {
+            fee_basis_points,
+            max: BASIS_POINT_SCALE,
+        }));
+    }
+    Ok(())
+}
+
+/// Returns the fee to add to `assets` to charge `fee_basis_points`.
+///
+/// Rounds up in favor of the vault.
+fn fee_on_raw(assets: U256, fee_basis_points: U256) -> U256 {
+    assets.mul_div(fee_basis_points, BASIS_POINT_SCALE, Rounding::Ceil)
+}
+
+/// Returns the fee part of `assets`, if they already include a fee of
+/// `fee_basis_points`.
+///
+/// Rounds up in favor of the vault.
+fn fee_on_total(assets: U256, fee_basis_points: U256) -> U256 {
+    assets.mul_div(
+        fee_basis_points,
+        fee_basis_points
+            .checked_add(BASIS_POINT_SCALE)
+            .expect("fee basis points overflow in `Erc4626`"),
+        Rounding::Ceil,
+    )
 }
 
 impl IErc165 for Erc4626 {
@@ -1224,12 +1608,18 @@ impl IErc165 for Erc4626 {
 // TODO: Add missing tests once `motsu` supports calling external contracts.
 #[cfg(all(test, feature = "std"))]
 mod tests {
-    use alloy_primitives::{address, Address, U256, U8};
+    use alloy_primitives::{address, uint, Address, U256, U8};
     use motsu::prelude::*;
-    use stylus_sdk::prelude::*;
+    use stylus_sdk::{prelude::*, storage::StorageBool};
 
-    use super::{Erc4626, IErc4626};
-    use crate::{token::erc20::Erc20, utils::introspection::erc165::IErc165};
+    use super::{
+        ERC4626ExceededMaxDeposit, ERC4626ExceededMaxWithdraw,
+        ERC4626InvalidFee, Erc4626, Error, IErc4626,
+    };
+    use crate::{
+        token::erc20::{Erc20, IErc20},
+        utils::{introspection::erc165::IErc165, Context, Pausable},
+    };
 
     #[storage]
     struct Erc4626TestExample {
@@ -1254,9 +1644,273 @@ mod tests {
         fn max_redeem(&self, owner: Address) -> U256 {
             self.erc4626.max_redeem(owner, &self.erc20)
         }
+
+        fn convert_to_shares(&mut self, assets: U256) -> Result<U256, Error> {
+            self.erc4626.convert_to_shares(assets, &self.erc20)
+        }
+
+        fn convert_to_assets(&mut self, shares: U256) -> Result<U256, Error> {
+            self.erc4626.convert_to_assets(shares, &self.erc20)
+        }
+
+        fn preview_deposit(&mut self, assets: U256) -> Result<U256, Error> {
+            self.erc4626.preview_deposit(assets, &self.erc20)
+        }
+
+        fn preview_mint(&mut self, shares: U256) -> Result<U256, Error> {
+            self.erc4626.preview_mint(shares, &self.erc20)
+        }
+
+        fn preview_withdraw(&mut self, assets: U256) -> Result<U256, Error> {
+            self.erc4626.preview_withdraw(assets, &self.erc20)
+        }
+
+        fn preview_redeem(&mut self, shares: U256) -> Result<U256, Error> {
+            self.erc4626.preview_redeem(shares, &self.erc20)
+        }
+
+        fn deposit(
+            &mut self,
+            assets: U256,
+            receiver: Address,
+        ) -> Result<U256, Error> {
+            self.erc4626.deposit(assets, receiver, &mut self.erc20)
+        }
+
+        fn redeem(
+            &mut self,
+            shares: U256,
+            receiver: Address,
+            owner: Address,
+        ) -> Result<U256, Error> {
+            self.erc4626.redeem(shares, receiver, owner, &mut self.erc20)
+        }
     }
     unsafe impl TopLevelStorage for Erc4626TestExample {}
 
+    const FEE_BASIS_POINTS: U256 = uint!(100_U256);
+
+    fn init_vault(
+        contract: &Contract<Erc4626TestExample>,
+        asset: &Contract<Erc20>,
+        alice: Address,
+        fee_recipient: Address,
+        entry_fee: U256,
+        exit_fee: U256,
+    ) {
+        let asset_address = asset.address();
+        contract.init(alice, |contract| {
+            contract.erc4626.asset.set(asset_address);
+            contract
+                .erc4626
+                ._set_entry_fee_basis_points(entry_fee)
+                .motsu_unwrap();
+            contract
+                .erc4626
+                ._set_exit_fee_basis_points(exit_fee)
+                .motsu_unwrap();
+            contract.erc4626._set_fee_recipient(fee_recipient);
+        });
+        asset
+            .sender(alice)
+            ._mint(alice, uint!(10_000_U256))
+            .motsu_expect("should mint assets");
+        asset
+            .sender(alice)
+            .approve(contract.address(), U256::MAX)
+            .motsu_expect("should approve the vault");
+    }
+
+    #[motsu::test]
+    fn previews_without_fees(
+        contract: Contract<Erc4626TestExample>,
+        asset: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init_vault(&contract, &asset, alice, bob, U256::ZERO, U256::ZERO);
+        let assets = uint!(1000_U256);
+
+        let shares =
+            contract.sender(alice).convert_to_shares(assets).motsu_unwrap();
+        assert_eq!(
+            shares,
+            contract.sender(alice).preview_deposit(assets).motsu_unwrap()
+        );
+        assert_eq!(
+            assets,
+            contract.sender(alice).preview_mint(shares).motsu_unwrap()
+        );
+        assert_eq!(
+            shares,
+            contract.sender(alice).preview_withdraw(assets).motsu_unwrap()
+        );
+        assert_eq!(
+            assets,
+            contract.sender(alice).preview_redeem(shares).motsu_unwrap()
+        );
+    }
+
+    #[motsu::test]
+    fn preview_deposit_and_mint_include_entry_fee(
+        contract: Contract<Erc4626TestExample>,
+        asset: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init_vault(&contract, &asset, alice, bob, FEE_BASIS_POINTS, U256::ZERO);
+
+        // Fee of 1% on total: ceil(1000 * 100 / 10100) = 10.
+        let shares = contract
+            .sender(alice)
+            .preview_deposit(uint!(1000_U256))
+            .motsu_unwrap();
+        assert_eq!(uint!(990_U256), shares);
+
+        // Fee of 1% on raw, rounded up: ceil(999 * 100 / 10000) = 10.
+        let assets =
+            contract.sender(alice).preview_mint(uint!(999_U256)).motsu_unwrap();
+        assert_eq!(uint!(1009_U256), assets);
+    }
+
+    #[motsu::test]
+    fn preview_withdraw_and_redeem_include_exit_fee(
+        contract: Contract<Erc4626TestExample>,
+        asset: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init_vault(&contract, &asset, alice, bob, U256::ZERO, FEE_BASIS_POINTS);
+
+        // Withdrawing 999 assets costs 999 + ceil(9.99) = 1009 shares.
+        let shares = contract
+            .sender(alice)
+            .preview_withdraw(uint!(999_U256))
+            .motsu_unwrap();
+        assert_eq!(uint!(1009_U256), shares);
+
+        // Redeeming 1000 shares yields 1000 - ceil(1000 * 100 / 10100) = 990.
+        let assets = contract
+            .sender(alice)
+            .preview_redeem(uint!(1000_U256))
+            .motsu_unwrap();
+        assert_eq!(uint!(990_U256), assets);
+    }
+
+    #[motsu::test]
+    fn deposit_transfers_entry_fee_to_recipient(
+        contract: Contract<Erc4626TestExample>,
+        asset: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init_vault(&contract, &asset, alice, bob, FEE_BASIS_POINTS, U256::ZERO);
+        let assets = uint!(1000_U256);
+
+        let preview =
+            contract.sender(alice).preview_deposit(assets).motsu_unwrap();
+        let shares =
+            contract.sender(alice).deposit(assets, alice).motsu_unwrap();
+
+        assert_eq!(preview, shares);
+        assert_eq!(
+            uint!(990_U256),
+            contract.sender(alice).erc20.balance_of(alice)
+        );
+        assert_eq!(uint!(10_U256), asset.sender(alice).balance_of(bob));
+        assert_eq!(
+            uint!(990_U256),
+            asset.sender(alice).balance_of(contract.address())
+        );
+        assert_eq!(uint!(9000_U256), asset.sender(alice).balance_of(alice));
+    }
+
+    #[motsu::test]
+    fn redeem_transfers_exit_fee_to_recipient(
+        contract: Contract<Erc4626TestExample>,
+        asset: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init_vault(&contract, &asset, alice, bob, U256::ZERO, FEE_BASIS_POINTS);
+        let shares = contract
+            .sender(alice)
+            .deposit(uint!(1000_U256), alice)
+            .motsu_unwrap();
+
+        let preview =
+            contract.sender(alice).preview_redeem(shares).motsu_unwrap();
+        let assets =
+            contract.sender(alice).redeem(shares, alice, alice).motsu_unwrap();
+
+        assert_eq!(preview, assets);
+        assert_eq!(uint!(990_U256), assets);
+        assert_eq!(U256::ZERO, contract.sender(alice).erc20.balance_of(alice));
+        assert_eq!(uint!(10_U256), asset.sender(alice).balance_of(bob));
+        assert_eq!(
+            U256::ZERO,
+            asset.sender(alice).balance_of(contract.address())
+        );
+        assert_eq!(uint!(9990_U256), asset.sender(alice).balance_of(alice));
+    }
+
+    #[motsu::test]
+    fn sets_fees_up_to_basis_point_scale(
+        contract: Contract<Erc4626TestExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let max = uint!(10_000_U256);
+        contract
+            .sender(alice)
+            .erc4626
+            ._set_entry_fee_basis_points(max)
+            .motsu_unwrap();
+        contract
+            .sender(alice)
+            .erc4626
+            ._set_exit_fee_basis_points(FEE_BASIS_POINTS)
+            .motsu_unwrap();
+        contract.sender(alice).erc4626._set_fee_recipient(bob);
+
+        assert_eq!(
+            max,
+            contract.sender(alice).erc4626._entry_fee_basis_points()
+        );
+        assert_eq!(
+            FEE_BASIS_POINTS,
+            contract.sender(alice).erc4626._exit_fee_basis_points()
+        );
+        assert_eq!(bob, contract.sender(alice).erc4626._fee_recipient());
+
+        for err in [
+            contract
+                .sender(alice)
+                .erc4626
+                ._set_entry_fee_basis_points(max + uint!(1_U256))
+                .motsu_unwrap_err(),
+            contract
+                .sender(alice)
+                .erc4626
+                ._set_exit_fee_basis_points(max + uint!(1_U256))
+                .motsu_unwrap_err(),
+        ] {
+            assert!(matches!(
+                err,
+                Error::InvalidFee(ERC4626InvalidFee { fee_basis_points, max: scale })
+                    if fee_basis_points == uint!(10_001_U256) && scale == max
+            ));
+        }
+        assert_eq!(
+            max,
+            contract.sender(alice).erc4626._entry_fee_basis_points()
+        );
+        assert_eq!(
+            FEE_BASIS_POINTS,
+            contract.sender(alice).erc4626._exit_fee_basis_points()
+        );
+    }
+
     #[motsu::test]
     fn asset_works(contract: Contract<Erc4626TestExample>, alice: Address) {
         let asset = address!("DeaDbeefdEAdbeefdEadbEEFdeadbeEFdEaDbeeF");
@@ -1303,6 +1957,104 @@ mod tests {
         assert_eq!(decimals_offset, new_decimal_offset);
     }
 
+    /// Returns the assets `bob` can redeem after depositing `1000` assets
+    /// into a vault where `alice` deposited one asset and donated `1000`.
+    fn redeemable_after_inflation_attack(
+        contract: &Contract<Erc4626TestExample>,
+        asset: &Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) -> U256 {
+        contract.sender(alice).deposit(uint!(1_U256), alice).motsu_unwrap();
+        asset
+            .sender(alice)
+            .transfer(contract.address(), uint!(1000_U256))
+            .motsu_unwrap();
+
+        asset.sender(bob)._mint(bob, uint!(1000_U256)).motsu_unwrap();
+        asset.sender(bob).approve(contract.address(), U256::MAX).motsu_unwrap();
+        let shares =
+            contract.sender(bob).deposit(uint!(1000_U256), bob).motsu_unwrap();
+
+        contract.sender(bob).preview_redeem(shares).motsu_unwrap()
+    }
+
+    #[motsu::test]
+    fn inflation_attack_without_decimals_offset(
+        contract: Contract<Erc4626TestExample>,
+        asset: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init_vault(&contract, &asset, alice, bob, U256::ZERO, U256::ZERO);
+
+        // Bob only gets one share, worth a third of the vault counting the
+        // virtual share.
+        let redeemable =
+            redeemable_after_inflation_attack(&contract, &asset, alice, bob);
+        assert_eq!(uint!(667_U256), redeemable);
+    }
+
+    #[motsu::test]
+    fn decimals_offset_neutralizes_inflation_attack(
+        contract: Contract<Erc4626TestExample>,
+        asset: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init_vault(&contract, &asset, alice, bob, U256::ZERO, U256::ZERO);
+        contract.sender(alice).erc4626._set_decimals_offset(U8::from(3));
+
+        // Bob loses at most a rounding unit, and Alice loses half of the
+        // 1001 assets she spent to the virtual shares.
+        let redeemable =
+            redeemable_after_inflation_attack(&contract, &asset, alice, bob);
+        assert_eq!(uint!(999_U256), redeemable);
+
+        let alice_shares = contract.sender(alice).erc20.balance_of(alice);
+        let alice_assets =
+            contract.sender(alice).preview_redeem(alice_shares).motsu_unwrap();
+        assert_eq!(uint!(501_U256), alice_assets);
+    }
+
+    #[motsu::test]
+    fn conversions_with_decimals_offset(
+        contract: Contract<Erc4626TestExample>,
+        asset: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init_vault(&contract, &asset, alice, bob, U256::ZERO, U256::ZERO);
+        contract.sender(alice).erc4626._set_decimals_offset(U8::from(3));
+
+        // An empty vault has `10^3` virtual shares for one virtual asset.
+        let shares = contract
+            .sender(alice)
+            .convert_to_shares(uint!(1000_U256))
+            .motsu_unwrap();
+        assert_eq!(uint!(1_000_000_U256), shares);
+        assert_eq!(
+            uint!(1000_U256),
+            contract.sender(alice).convert_to_assets(shares).motsu_unwrap()
+        );
+
+        let minted = contract
+            .sender(alice)
+            .deposit(uint!(1000_U256), alice)
+            .motsu_unwrap();
+        assert_eq!(shares, minted);
+
+        // Converting back and forth never creates assets.
+        for assets in [uint!(1_U256), uint!(7_U256), uint!(1234_U256)] {
+            let shares =
+                contract.sender(alice).convert_to_shares(assets).motsu_unwrap();
+            let converted =
+                contract.sender(alice).convert_to_assets(shares).motsu_unwrap();
+            assert!(converted <= assets);
+            assert!(assets - converted <= uint!(1_U256));
+        }
+    }
+
     #[motsu::test]
     fn decimals(contract: Contract<Erc4626TestExample>, alice: Address) {
         let underlying_decimals = U8::from(17);
@@ -1319,6 +2071,150 @@ mod tests {
         assert_eq!(decimals, underlying_decimals + new_decimal_offset);
     }
 
+    /// Vault accepting no deposits while paused, and no withdrawals while
+    /// frozen.
+    #[storage]
+    struct PausableVault {
+        erc4626: Erc4626,
+        erc20: Erc20,
+        pausable: Pausable,
+        withdrawals_frozen: StorageBool,
+    }
+
+    #[public]
+    impl PausableVault {
+        fn max_deposit(&self, receiver: Address) -> U256 {
+            if self.pausable.paused.get() {
+                U256::ZERO
+            } else {
+                self.erc4626.max_deposit(receiver)
+            }
+        }
+
+        fn preview_deposit(&mut self, assets: U256) -> Result<U256, Error> {
+            self.erc4626.preview_deposit(assets, &self.erc20)
+        }
+
+        fn deposit(
+            &mut self,
+            assets: U256,
+            receiver: Address,
+        ) -> Result<U256, Error> {
+            let max_assets = self.max_deposit(receiver);
+            self.erc4626._deposit_with_max(
+                self._msg_sender(),
+                assets,
+                receiver,
+                max_assets,
+                &mut self.erc20,
+            )
+        }
+
+        fn max_withdraw(&mut self, owner: Address) -> Result<U256, Error> {
+            if self.withdrawals_frozen.get() {
+                Ok(U256::ZERO)
+            } else {
+                self.erc4626.max_withdraw(owner, &self.erc20)
+            }
+        }
+
+        fn withdraw(
+            &mut self,
+            assets: U256,
+            receiver: Address,
+            owner: Address,
+        ) -> Result<U256, Error> {
+            let max_assets = self.max_withdraw(owner)?;
+            self.erc4626._withdraw_with_max(
+                self._msg_sender(),
+                assets,
+                receiver,
+                owner,
+                max_assets,
+                &mut self.erc20,
+            )
+        }
+    }
+
+    unsafe impl TopLevelStorage for PausableVault {}
+
+    impl Context for PausableVault {}
+
+    #[motsu::test]
+    fn paused_vault_rejects_deposits(
+        contract: Contract<PausableVault>,
+        asset: Contract<Erc20>,
+        alice: Address,
+    ) {
+        let asset_address = asset.address();
+        contract.init(alice, |contract| {
+            contract.erc4626.asset.set(asset_address);
+        });
+        asset
+            .sender(alice)
+            ._mint(alice, uint!(10_000_U256))
+            .motsu_expect("should mint assets");
+        asset
+            .sender(alice)
+            .approve(contract.address(), U256::MAX)
+            .motsu_expect("should approve the vault");
+        let assets = uint!(1000_U256);
+
+        assert_eq!(U256::MAX, contract.sender(alice).max_deposit(alice));
+        contract.sender(alice).deposit(assets, alice).motsu_unwrap();
+
+        contract.sender(alice).pausable.pause().motsu_unwrap();
+
+        // Previews still compute the shares a deposit would mint.
+        assert_eq!(
+            assets,
+            contract.sender(alice).preview_deposit(assets).motsu_unwrap()
+        );
+        assert_eq!(U256::ZERO, contract.sender(alice).max_deposit(alice));
+        let err =
+            contract.sender(alice).deposit(assets, alice).motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            Error::ExceededMaxDeposit(ERC4626ExceededMaxDeposit {
+                receiver,
+                assets: rejected,
+                max,
+            }) if receiver == alice && rejected == assets && max.is_zero()
+        ));
+
+        // Withdrawals are restricted separately from deposits.
+        assert_eq!(
+            assets,
+            contract.sender(alice).max_withdraw(alice).motsu_unwrap()
+        );
+        contract
+            .sender(alice)
+            .withdraw(uint!(100_U256), alice, alice)
+            .motsu_unwrap();
+
+        contract.init(alice, |contract| contract.withdrawals_frozen.set(true));
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).max_withdraw(alice).motsu_unwrap()
+        );
+        let err = contract
+            .sender(alice)
+            .withdraw(uint!(100_U256), alice, alice)
+            .motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            Error::ExceededMaxWithdraw(ERC4626ExceededMaxWithdraw {
+                max,
+                ..
+            }) if max.is_zero()
+        ));
+
+        contract.sender(alice).pausable.unpause().motsu_unwrap();
+        assert_eq!(U256::MAX, contract.sender(alice).max_deposit(alice));
+        contract.sender(alice).deposit(assets, alice).motsu_unwrap();
+        assert_eq!(uint!(8_100_U256), asset.sender(alice).balance_of(alice));
+    }
+
     #[motsu::test]
     fn interface_id() {
         let actual = <Erc4626 as IErc4626>::INTERFACE_ID;
@@ -5,12 +5,12 @@
 //! loan support at the token level. By default there is no fee, but this can be
 //! changed by overriding [`IErc3156FlashLender::flash_loan`].
 //!
-//! NOTE: When this extension is used along with the
-//! [`crate::token::erc20::extensions::Capped`] extension,
+//! NOTE: When this extension is used along with the [`Capped`] extension,
 //! [`IErc3156FlashLender::max_flash_loan`] will not correctly reflect the
-//! maximum that can be flash minted. We recommend overriding
-//! [`IErc3156FlashLender::max_flash_loan`] so that it correctly reflects the
-//! supply cap.
+//! maximum that can be flash minted. Use the
+//! [`Erc20FlashMint::max_flash_loan_capped`] and
+//! [`Erc20FlashMint::flash_loan_capped`] functions instead, so that the supply
+//! cap is respected.
 //!
 //! [ERC-3156]: https://eips.ethereum.org/EIPS/eip-3156
 
@@ -23,14 +23,17 @@ use alloy_primitives::{Address, FixedBytes, U256};
 use stylus_sdk::{
     abi::Bytes,
     call::{Call, MethodError},
-    contract, msg,
+    contract,
     prelude::*,
     storage::{StorageAddress, StorageU256},
 };
 
 use crate::{
-    token::erc20::{self, Erc20, IErc20},
-    utils::introspection::erc165::{Erc165, IErc165},
+    token::erc20::{self, extensions::Capped, Erc20, IErc20},
+    utils::{
+        introspection::erc165::{Erc165, IErc165},
+        Context,
+    },
 };
 
 /// The expected value returned from [`IERC3156FlashBorrower::on_flash_loan`].
@@ -166,9 +169,8 @@ pub trait IErc3156FlashLender {
     /// Returns the maximum amount of tokens available for loan.
     ///
     /// NOTE: This function does not consider any form of supply cap, so in case
-    /// it's used in a token with a cap like
-    /// [`crate::token::erc20::extensions::Capped`], make sure to override this
-    /// function to integrate the cap instead of [`U256::MAX`].
+    /// it's used in a token with a cap like [`Capped`], use
+    /// [`Erc20FlashMint::max_flash_loan_capped`] instead.
     ///
     /// # Arguments
     ///
@@ -216,6 +218,8 @@ pub trait IErc3156FlashLender {
     /// implement the [`IERC3156FlashBorrower`] interface. By the end of the
     /// flash loan, the receiver is expected to own value + fee tokens and have
     /// them approved back to the token contract itself so they can be burned.
+    /// The fee is burned as well, unless a flash fee receiver is set, see
+    /// [`Erc20FlashMint::_flash_fee_receiver`].
     ///
     /// Returns a boolean value indicating whether the operation succeeded.
     ///
@@ -238,6 +242,8 @@ pub trait IErc3156FlashLender {
     /// * [`Error::InvalidReceiver`] - If the `token` address is not a contract
     ///   , the contract fails to execute the call, or the receiver does not
     ///   return [`BORROWER_CALLBACK_VALUE`].
+    /// * [`erc20::Error::TotalSupplyOverflow`] - If the new (temporary) total
+    ///   supply exceeds `U256::MAX`.
     ///
     /// # Events
     ///
@@ -246,7 +252,6 @@ pub trait IErc3156FlashLender {
     ///
     /// # Panics
     ///
-    /// * If the new (temporary) total supply exceeds `U256::MAX`.
     /// * If the sum of the loan value and fee exceeds the maximum value of
     ///   `U256::MAX`.
     ///
@@ -302,9 +307,6 @@ impl IErc3156FlashLender for Erc20FlashMint {
         }
     }
 
-    // This function can reenter, but it doesn't pose a risk because it always
-    // preserves the property that the amount minted at the beginning is always
-    // recovered and burned at the end, or else the entire function will revert.
     fn flash_loan(
         &mut self,
         receiver: Address,
@@ -313,6 +315,65 @@ impl IErc3156FlashLender for Erc20FlashMint {
         data: Bytes,
         erc20: &mut Erc20,
     ) -> Result<bool, Self::Error> {
+        self.flash_loan_with_sender(
+            self._msg_sender(),
+            receiver,
+            token,
+            value,
+            &data,
+            erc20,
+        )
+    }
+}
+
+impl Context for Erc20FlashMint {}
+
+impl Erc20FlashMint {
+    /// Same as [`IErc3156FlashLender::flash_loan`], initiated by `sender`
+    /// rather than [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account initiating the flash loan, passed to the receiver's
+    ///   [`IERC3156FlashBorrower::on_flash_loan`].
+    /// * `receiver` - The receiver of the flash loan. Should implement the
+    ///   [`IERC3156FlashBorrower::on_flash_loan`] interface.
+    /// * `token` - The token to be flash loaned. Only [`contract::address()`]
+    ///   is supported.
+    /// * `value` - The amount of tokens to be loaned.
+    /// * `data` - Arbitrary data that is passed to the receiver.
+    /// * `erc20` - Write access to an [`Erc20`] contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExceededMaxLoan`] - If the `value` is greater than the value
+    ///   returned by [`IErc3156FlashLender::max_flash_loan`].
+    /// * [`Error::UnsupportedToken`] - If `token` is not supported.
+    /// * [`Error::InvalidReceiver`] - If the `token` address is not a contract
+    ///   , the contract fails to execute the call, or the receiver does not
+    ///   return [`BORROWER_CALLBACK_VALUE`].
+    /// * [`erc20::Error::TotalSupplyOverflow`] - If the new (temporary) total
+    ///   supply exceeds `U256::MAX`.
+    ///
+    /// # Events
+    ///
+    /// * [`erc20::Transfer`].
+    /// * [`erc20::Approval`].
+    ///
+    /// # Panics
+    ///
+    /// * If the sum of the loan value and fee exceeds the maximum value of
+    ///   `U256::MAX`.
+    pub fn flash_loan_with_sender(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        token: Address,
+        value: U256,
+        data: &Bytes,
+        erc20: &mut Erc20,
+    ) -> Result<bool, Error> {
         let max_loan = self.max_flash_loan(token, erc20);
         if value > max_loan {
             return Err(Error::ExceededMaxLoan(ERC3156ExceededMaxLoan {
@@ -321,6 +382,189 @@ impl IErc3156FlashLender for Erc20FlashMint {
         }
 
         let fee = self.flash_fee(token, value)?;
+        self.do_flash_loan(sender, receiver, token, value, fee, data, erc20)
+    }
+
+    /// Returns the maximum amount of tokens available for loan, without
+    /// exceeding the supply cap of `capped`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `token` - The address of the token that is requested.
+    /// * `erc20` - Read access to an [`Erc20`] contract.
+    /// * `capped` - Read access to the [`Capped`] contract of the token.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// fn max_flash_loan(&self, token: Address) -> U256 {
+    ///     self.erc20_flash_mint.max_flash_loan_capped(
+    ///         token,
+    ///         &self.erc20,
+    ///         &self.capped,
+    ///     )
+    /// }
+    /// ```
+    pub fn max_flash_loan_capped(
+        &self,
+        token: Address,
+        erc20: &Erc20,
+        capped: &Capped,
+    ) -> U256 {
+        if token == contract::address() {
+            capped.cap().saturating_sub(erc20.total_supply())
+        } else {
+            U256::MIN
+        }
+    }
+
+    /// Performs a flash loan, without exceeding the supply cap of `capped`.
+    ///
+    /// Behaves like [`IErc3156FlashLender::flash_loan`], except that the loan
+    /// value and the fee together should fit within the supply cap.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `receiver` - The receiver of the flash loan. Should implement the
+    ///   [`IERC3156FlashBorrower::on_flash_loan`] interface.
+    /// * `token` - The token to be flash loaned. Only [`contract::address()`]
+    ///   is supported.
+    /// * `value` - The amount of tokens to be loaned.
+    /// * `data` - Arbitrary data that is passed to the receiver.
+    /// * `erc20` - Write access to an [`Erc20`] contract.
+    /// * `capped` - Read access to the [`Capped`] contract of the token.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ExceededMaxLoan`] - If the `value`, or the sum of the `value`
+    ///   and the fee, is greater than the value returned by
+    ///   [`Self::max_flash_loan_capped`]. Checked before calling `receiver`.
+    /// * [`Error::UnsupportedToken`] - If `token` is not supported.
+    /// * [`Error::InvalidReceiver`] - If the `token` address is not a contract
+    ///   , the contract fails to execute the call, or the receiver does not
+    ///   return [`BORROWER_CALLBACK_VALUE`].
+    ///
+    /// # Events
+    ///
+    /// * [`erc20::Transfer`].
+    /// * [`erc20::Approval`].
+    pub fn flash_loan_capped(
+        &mut self,
+        receiver: Address,
+        token: Address,
+        value: U256,
+        data: &Bytes,
+        erc20: &mut Erc20,
+        capped: &Capped,
+    ) -> Result<bool, Error> {
+        self.flash_loan_capped_with_sender(
+            self._msg_sender(),
+            receiver,
+            token,
+            value,
+            data,
+            erc20,
+            capped,
+        )
+    }
+
+    /// Same as [`Self::flash_loan_capped`], initiated by `sender` rather than
+    /// [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account initiating the flash loan, passed to the receiver's
+    ///   [`IERC3156FlashBorrower::on_flash_loan`].
+    /// * `receiver` - The receiver of the flash loan. Should implement the
+    ///   [`IERC3156FlashBorrower::on_flash_loan`] interface.
+    /// * `token` - The token to be flash loaned. Only [`contract::address()`]
+    ///   is supported.
+    /// * `value` - The amount of tokens to be loaned.
+    /// * `data` - Arbitrary data that is passed to the receiver.
+    /// * `erc20` - Write access to an [`Erc20`] contract.
+    /// * `capped` - Read access to the [`Capped`] contract of the token.
+    ///
+    /// # Errors
+    ///
+    /// * Any error of [`Self::flash_loan_capped`].
+    ///
+    /// # Events
+    ///
+    /// * [`erc20::Transfer`].
+    /// * [`erc20::Approval`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn flash_loan_capped_with_sender(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        token: Address,
+        value: U256,
+        data: &Bytes,
+        erc20: &mut Erc20,
+        capped: &Capped,
+    ) -> Result<bool, Error> {
+        let max_loan = self.max_flash_loan_capped(token, erc20, capped);
+        if value > max_loan {
+            return Err(Error::ExceededMaxLoan(ERC3156ExceededMaxLoan {
+                max_loan,
+            }));
+        }
+
+        let fee = self.flash_fee(token, value)?;
+        if value.checked_add(fee).map_or(true, |total| total > max_loan) {
+            return Err(Error::ExceededMaxLoan(ERC3156ExceededMaxLoan {
+                max_loan,
+            }));
+        }
+
+        self.do_flash_loan(sender, receiver, token, value, fee, data, erc20)
+    }
+
+    /// Returns the account receiving the flash fees, or `Address::ZERO` if
+    /// the fees are burned.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn _flash_fee_receiver(&self) -> Address {
+        self.flash_fee_receiver_address.get()
+    }
+
+    /// Sets the account receiving the flash fees, e.g. a treasury.
+    ///
+    /// Setting it to `Address::ZERO` burns the fees instead.
+    ///
+    /// Internal function without access restriction.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `receiver` - Account receiving the flash fees.
+    pub fn _set_flash_fee_receiver(&mut self, receiver: Address) {
+        self.flash_fee_receiver_address.set(receiver);
+    }
+
+    /// Mints `value` tokens to `receiver`, calls it back and collects the
+    /// loan along with `fee`.
+    #[allow(clippy::too_many_arguments)]
+    fn do_flash_loan(
+        &mut self,
+        initiator: Address,
+        receiver: Address,
+        token: Address,
+        value: U256,
+        fee: U256,
+        data: &Bytes,
+        erc20: &mut Erc20,
+    ) -> Result<bool, Error> {
+        // This function can reenter, but it doesn't pose a risk because it
+        // always preserves the property that the amount minted at the
+        // beginning is always recovered and burned at the end, or else the
+        // entire function will revert.
         if !Address::has_code(&receiver) {
             return Err(Error::InvalidReceiver(ERC3156InvalidReceiver {
                 receiver,
@@ -331,7 +575,7 @@ impl IErc3156FlashLender for Erc20FlashMint {
         let loan_return = loan_receiver
             .on_flash_loan(
                 Call::new_in(self),
-                msg::sender(),
+                initiator,
                 token,
                 value,
                 fee,
@@ -351,7 +595,7 @@ impl IErc3156FlashLender for Erc20FlashMint {
             .expect("allowance should not exceed `U256::MAX`");
         erc20._spend_allowance(receiver, contract::address(), allowance)?;
 
-        let flash_fee_receiver = self.flash_fee_receiver_address.get();
+        let flash_fee_receiver = self._flash_fee_receiver();
 
         if fee.is_zero() || flash_fee_receiver.is_zero() {
             erc20._burn(receiver, allowance)?;
@@ -374,25 +618,62 @@ impl IErc165 for Erc20FlashMint {
 
 #[cfg(all(test, feature = "std"))]
 mod tests {
-    use alloy_primitives::{uint, Address, U256};
+    use alloy_primitives::{uint, Address, FixedBytes, U256};
     use motsu::prelude::*;
-    use stylus_sdk::{abi::Bytes, prelude::*};
+    use stylus_sdk::{abi::Bytes, call::Call, prelude::*};
 
     use super::{
-        ERC3156ExceededMaxLoan, ERC3156InvalidReceiver,
+        Capped, ERC3156ExceededMaxLoan, ERC3156InvalidReceiver,
         ERC3156UnsupportedToken, Erc20, Erc20FlashMint, Error,
-        IErc3156FlashLender,
+        IErc3156FlashLender, BORROWER_CALLBACK_VALUE,
+    };
+    use crate::{
+        token::erc20::{IErc20, Transfer},
+        utils::introspection::erc165::IErc165,
     };
-    use crate::utils::introspection::erc165::IErc165;
 
     #[storage]
     struct Erc20FlashMintTestExample {
         erc20_flash_mint: Erc20FlashMint,
         erc20: Erc20,
+        capped: Capped,
     }
 
     #[public]
     impl Erc20FlashMintTestExample {
+        fn approve(
+            &mut self,
+            spender: Address,
+            value: U256,
+        ) -> Result<bool, crate::token::erc20::Error> {
+            self.erc20.approve(spender, value)
+        }
+
+        fn max_flash_loan_capped(&self, token: Address) -> U256 {
+            self.erc20_flash_mint.max_flash_loan_capped(
+                token,
+                &self.erc20,
+                &self.capped,
+            )
+        }
+
+        fn flash_loan_capped(
+            &mut self,
+            receiver: Address,
+            token: Address,
+            value: U256,
+            data: Bytes,
+        ) -> Result<bool, super::Error> {
+            self.erc20_flash_mint.flash_loan_capped(
+                receiver,
+                token,
+                value,
+                &data,
+                &mut self.erc20,
+                &self.capped,
+            )
+        }
+
         fn max_flash_loan(&self, token: Address) -> U256 {
             self.erc20_flash_mint.max_flash_loan(token, &self.erc20)
         }
@@ -424,6 +705,41 @@ mod tests {
 
     unsafe impl TopLevelStorage for Erc20FlashMintTestExample {}
 
+    mod token {
+        #![allow(missing_docs)]
+        #![cfg_attr(coverage_nightly, coverage(off))]
+        use alloc::vec;
+
+        stylus_sdk::prelude::sol_interface! {
+            interface IToken {
+                function approve(address spender, uint256 value) external returns (bool);
+            }
+        }
+    }
+
+    /// Borrower approving the loan and the fee back to the lending token.
+    #[storage]
+    struct FlashBorrowerMock {}
+
+    unsafe impl TopLevelStorage for FlashBorrowerMock {}
+
+    #[public]
+    impl FlashBorrowerMock {
+        fn on_flash_loan(
+            &mut self,
+            _initiator: Address,
+            token: Address,
+            amount: U256,
+            fee: U256,
+            _data: Bytes,
+        ) -> Result<FixedBytes<32>, Vec<u8>> {
+            token::IToken::new(token)
+                .approve(Call::new_in(self), token, amount + fee)
+                .map_err(|_| Vec::new())?;
+            Ok(BORROWER_CALLBACK_VALUE.into())
+        }
+    }
+
     #[motsu::test]
     fn max_flash_loan_token_match(
         contract: Contract<Erc20FlashMintTestExample>,
@@ -572,6 +888,164 @@ mod tests {
         ));
     }
 
+    #[motsu::test]
+    fn flash_loan_routes_fee_to_flash_fee_receiver(
+        contract: Contract<Erc20FlashMintTestExample>,
+        borrower: Contract<FlashBorrowerMock>,
+        alice: Address,
+        treasury: Address,
+    ) {
+        let fee = uint!(10_U256);
+        let value = uint!(1000_U256);
+        contract.init(alice, |contract| {
+            contract.erc20_flash_mint.flash_fee_value.set(fee);
+            contract.erc20_flash_mint._set_flash_fee_receiver(treasury);
+            contract
+                .erc20
+                ._mint(borrower.address(), fee)
+                .motsu_expect("should mint the fee to the borrower");
+        });
+
+        let result = contract
+            .sender(alice)
+            .flash_loan(
+                borrower.address(),
+                contract.address(),
+                value,
+                vec![].into(),
+            )
+            .motsu_expect("should perform the flash loan");
+
+        assert!(result);
+        assert_eq!(
+            treasury,
+            contract.sender(alice).erc20_flash_mint._flash_fee_receiver()
+        );
+        assert_eq!(fee, contract.sender(alice).erc20.balance_of(treasury));
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).erc20.balance_of(borrower.address())
+        );
+        assert_eq!(fee, contract.sender(alice).erc20.total_supply());
+        contract.assert_emitted(&Transfer {
+            from: borrower.address(),
+            to: treasury,
+            value: fee,
+        });
+    }
+
+    #[motsu::test]
+    fn flash_loan_burns_fee_without_flash_fee_receiver(
+        contract: Contract<Erc20FlashMintTestExample>,
+        borrower: Contract<FlashBorrowerMock>,
+        alice: Address,
+    ) {
+        let fee = uint!(10_U256);
+        contract.init(alice, |contract| {
+            contract.erc20_flash_mint.flash_fee_value.set(fee);
+            contract
+                .erc20
+                ._mint(borrower.address(), fee)
+                .motsu_expect("should mint the fee to the borrower");
+        });
+
+        contract
+            .sender(alice)
+            .flash_loan(
+                borrower.address(),
+                contract.address(),
+                uint!(1000_U256),
+                vec![].into(),
+            )
+            .motsu_expect("should perform the flash loan");
+
+        assert_eq!(U256::ZERO, contract.sender(alice).erc20.total_supply());
+    }
+
+    #[motsu::test]
+    fn max_flash_loan_capped(
+        contract: Contract<Erc20FlashMintTestExample>,
+        alice: Address,
+    ) {
+        let cap = uint!(10000_U256);
+        let initial_supply = uint!(4000_U256);
+        contract.init(alice, |contract| {
+            contract.capped.cap.set(cap);
+            contract
+                .erc20
+                ._mint(alice, initial_supply)
+                .motsu_expect("should mint initial supply tokens");
+        });
+
+        assert_eq!(
+            cap - initial_supply,
+            contract.sender(alice).max_flash_loan_capped(contract.address())
+        );
+        assert_eq!(
+            U256::MIN,
+            contract.sender(alice).max_flash_loan_capped(alice)
+        );
+    }
+
+    #[motsu::test]
+    fn flash_loan_capped_reverts_when_exceeded_cap(
+        contract: Contract<Erc20FlashMintTestExample>,
+        borrower: Contract<FlashBorrowerMock>,
+        alice: Address,
+    ) {
+        let cap = uint!(10000_U256);
+        let fee = uint!(10_U256);
+        contract.init(alice, |contract| {
+            contract.capped.cap.set(cap);
+            contract.erc20_flash_mint.flash_fee_value.set(fee);
+            contract
+                .erc20
+                ._mint(borrower.address(), fee)
+                .motsu_expect("should mint the fee to the borrower");
+        });
+        // The fee minted to the borrower is part of the total supply.
+        let max_loan = cap - fee;
+
+        let err = contract
+            .sender(alice)
+            .flash_loan_capped(
+                borrower.address(),
+                contract.address(),
+                max_loan + uint!(1_U256),
+                vec![].into(),
+            )
+            .motsu_expect_err("should return Error::ExceededMaxLoan");
+        assert!(matches!(
+            err,
+            Error::ExceededMaxLoan(ERC3156ExceededMaxLoan { max_loan: max })
+                if max == max_loan
+        ));
+
+        // The loan fits within the cap, but not along with the fee.
+        let err = contract
+            .sender(alice)
+            .flash_loan_capped(
+                borrower.address(),
+                contract.address(),
+                max_loan,
+                vec![].into(),
+            )
+            .motsu_expect_err("should return Error::ExceededMaxLoan");
+        assert!(matches!(err, Error::ExceededMaxLoan(_)));
+        assert_eq!(fee, contract.sender(alice).erc20.total_supply());
+
+        contract
+            .sender(alice)
+            .flash_loan_capped(
+                borrower.address(),
+                contract.address(),
+                max_loan - fee,
+                vec![].into(),
+            )
+            .motsu_expect("should perform the flash loan within the cap");
+        assert_eq!(U256::ZERO, contract.sender(alice).erc20.total_supply());
+    }
+
     #[motsu::test]
     fn interface_id() {
         let actual = <Erc20FlashMint as IErc3156FlashLender>::INTERFACE_ID;
@@ -146,6 +146,89 @@ impl<T: IEip712 + StorageType> Erc20Permit<T> {
         s: B256,
         erc20: &mut Erc20,
         nonces: &mut Nonces,
+    ) -> Result<(), Error> {
+        self._permit(
+            owner,
+            spender,
+            value,
+            deadline,
+            erc20,
+            nonces,
+            |storage, hash| ecdsa::recover(storage, hash, v, r, s),
+        )
+    }
+
+    /// Sets `value` as the allowance of `spender` over `owner`'s tokens,
+    /// given `owner`'s signed approval as a single `signature`.
+    ///
+    /// Same as [`Self::permit`], but `signature` is either a 65 bytes
+    /// `r || s || v` signature, or a 64 bytes [EIP-2098] compact signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account that owns the tokens.
+    /// * `spender` - Account that will spend the tokens.
+    /// * `value` - The number of tokens being permitted to transfer by
+    ///   `spender`.
+    /// * `deadline` - Deadline for the permit action.
+    /// * `signature` - The `owner`'s signature.
+    /// * `erc20` - Write access to an [`Erc20`] contract.
+    /// * `nonces` - Write access to a [`Nonces`] contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`ERC2612ExpiredSignature`] - If the `deadline` param is from the
+    ///   past.
+    /// * [`ERC2612InvalidSigner`] - If signer is not an `owner`.
+    /// * [`ecdsa::Error::InvalidSignatureLength`] - If `signature` is neither
+    ///   64 nor 65 bytes long.
+    /// * [`ecdsa::Error::InvalidSignatureS`] - If the `s` value is greater than
+    ///   [`ecdsa::SIGNATURE_S_UPPER_BOUND`].
+    /// * [`ecdsa::Error::InvalidSignature`] - If the recovered address is
+    ///   `Address::ZERO`.
+    /// * [`erc20::Error::InvalidSpender`] - If the `spender` address is
+    ///   `Address::ZERO`.
+    ///
+    /// # Events
+    ///
+    /// * [`erc20::Approval`]
+    ///
+    /// [EIP-2098]: https://eips.ethereum.org/EIPS/eip-2098
+    #[allow(clippy::too_many_arguments)]
+    pub fn permit_with_signature(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        value: U256,
+        deadline: U256,
+        signature: &[u8],
+        erc20: &mut Erc20,
+        nonces: &mut Nonces,
+    ) -> Result<(), Error> {
+        self._permit(
+            owner,
+            spender,
+            value,
+            deadline,
+            erc20,
+            nonces,
+            |storage, hash| ecdsa::try_recover(storage, hash, signature),
+        )
+    }
+
+    /// Shared workflow of [`Self::permit`] and [`Self::permit_with_signature`],
+    /// recovering the signer of the permit hash with `recover`.
+    #[allow(clippy::too_many_arguments)]
+    fn _permit(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        value: U256,
+        deadline: U256,
+        erc20: &mut Erc20,
+        nonces: &mut Nonces,
+        recover: impl FnOnce(&mut Self, B256) -> Result<Address, ecdsa::Error>,
     ) -> Result<(), Error> {
         if U256::from(block::timestamp()) > deadline {
             return Err(ERC2612ExpiredSignature { deadline }.into());
@@ -162,7 +245,7 @@ impl<T: IEip712 + StorageType> Erc20Permit<T> {
 
         let hash: B256 = self.eip712.hash_typed_data_v4(struct_hash);
 
-        let signer: Address = ecdsa::recover(self, hash, v, r, s)?;
+        let signer: Address = recover(self, hash)?;
 
         if signer != owner {
             return Err(ERC2612InvalidSigner { signer, owner }.into());
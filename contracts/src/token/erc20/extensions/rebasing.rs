@@ -0,0 +1,516 @@
+//! Extension of ERC-20 tokens with an elastic supply, whose balances scale by
+//! a global factor.
+//!
+//! [`Erc20Rebasing`] stores shares rather than balances in its [`Erc20`]
+//! state, and converts them with the [`Rebasing`] conversions of the state it
+//! embeds:
+//!
+//! ```rust,ignore
+//! #[storage]
+//! struct Factor {
+//!     factor: StorageU256,
+//! }
+//!
+//! impl Rebasing for Factor {
+//!     fn _to_balance(&self, shares: U256) -> U256 {
+//!         shares.mul_div(self.factor.get(), WAD, Rounding::Floor)
+//!     }
+//!
+//!     fn _to_shares(&self, value: U256, rounding: Rounding) -> U256 {
+//!         value.mul_div(WAD, self.factor.get(), rounding)
+//!     }
+//! }
+//!
+//! #[entrypoint]
+//! #[storage]
+//! struct MyToken {
+//!     #[borrow]
+//!     erc20_rebasing: Erc20Rebasing<Factor>,
+//! }
+//!
+//! #[public]
+//! #[inherit(Erc20Rebasing<Factor>)]
+//! impl MyToken {}
+//! ```
+//!
+//! Since `total_supply` and every balance are converted from shares with the
+//! same rounding down, the sum of all balances never exceeds `total_supply`.
+//! A rebase, i.e. a change of the factor, changes every balance, and
+//! `total_supply`, in the same proportion.
+//!
+//! Transfers charge the sender the shares of the transferred amount, rounded
+//! up. Allowances, errors and events are expressed in balances, like the
+//! amounts passed to the token.
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{Address, U256};
+use stylus_sdk::{evm, prelude::*};
+
+use crate::{
+    token::erc20::{
+        self, ERC20InsufficientBalance, ERC20InvalidReceiver,
+        ERC20InvalidSender, Erc20, IErc20,
+    },
+    utils::{math::Rounding, Context},
+};
+
+/// Converts between the shares stored by an [`Erc20Rebasing`] contract and
+/// the balances it reports.
+///
+/// Both conversions default to the identity, i.e. no rebasing.
+pub trait Rebasing {
+    /// Returns the balance corresponding to `shares`.
+    ///
+    /// Defaults to `shares`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `shares` - Amount of shares stored by [`Erc20`].
+    fn _to_balance(&self, shares: U256) -> U256 {
+        shares
+    }
+
+    /// Returns the shares corresponding to the balance `value`.
+    ///
+    /// Defaults to `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `value` - Amount of tokens, as reported by balances.
+    /// * `rounding` - Rounding direction of the conversion, e.g.
+    ///   [`Rounding::Ceil`] for the shares a sender gives away.
+    fn _to_shares(&self, value: U256, rounding: Rounding) -> U256 {
+        let _ = rounding;
+        value
+    }
+}
+
+/// State of an [`Erc20Rebasing`] Contract.
+#[storage]
+pub struct Erc20Rebasing<T: Rebasing + StorageType> {
+    /// [`Erc20`] contract, storing shares rather than balances.
+    pub(crate) erc20: Erc20,
+    /// Contract implementing the [`Rebasing`] conversions.
+    pub rebasing: T,
+}
+
+#[public]
+impl<T: Rebasing + StorageType> IErc20 for Erc20Rebasing<T> {
+    type Error = erc20::Error;
+
+    fn total_supply(&self) -> U256 {
+        self.rebasing._to_balance(self.erc20.total_supply())
+    }
+
+    fn balance_of(&self, account: Address) -> U256 {
+        self.rebasing._to_balance(self.erc20.balance_of(account))
+    }
+
+    fn transfer(
+        &mut self,
+        to: Address,
+        value: U256,
+    ) -> Result<bool, Self::Error> {
+        self._transfer(self._msg_sender(), to, value)?;
+        Ok(true)
+    }
+
+    fn allowance(&self, owner: Address, spender: Address) -> U256 {
+        self.erc20.allowance(owner, spender)
+    }
+
+    fn approve(
+        &mut self,
+        spender: Address,
+        value: U256,
+    ) -> Result<bool, Self::Error> {
+        self.erc20.approve_with_sender(self._msg_sender(), spender, value)
+    }
+
+    fn transfer_from(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<bool, Self::Error> {
+        self.erc20._spend_allowance(from, self._msg_sender(), value)?;
+        self._transfer(from, to, value)?;
+        Ok(true)
+    }
+}
+
+impl<T: Rebasing + StorageType> Context for Erc20Rebasing<T> {}
+
+impl<T: Rebasing + StorageType> Erc20Rebasing<T> {
+    /// Creates a `value` amount of tokens and assigns them to `account`.
+    ///
+    /// `account` receives the shares of `value`, rounded down.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account to mint tokens to.
+    /// * `value` - Amount of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc20::Error::InvalidReceiver`] - If the `account` address is
+    ///   `Address::ZERO`.
+    /// * [`erc20::Error::TotalSupplyOverflow`] - If the total shares would
+    ///   exceed `U256::MAX`.
+    ///
+    /// # Events
+    ///
+    /// * [`erc20::Transfer`].
+    pub fn _mint(
+        &mut self,
+        account: Address,
+        value: U256,
+    ) -> Result<(), erc20::Error> {
+        if account.is_zero() {
+            return Err(erc20::Error::InvalidReceiver(ERC20InvalidReceiver {
+                receiver: Address::ZERO,
+            }));
+        }
+        let shares = self.rebasing._to_shares(value, Rounding::Floor);
+        self.update(Address::ZERO, account, shares, value)
+    }
+
+    /// Destroys a `value` amount of tokens from `account`.
+    ///
+    /// `account` gives away the shares of `value`, rounded up.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account to burn tokens from.
+    /// * `value` - Amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc20::Error::InvalidSender`] - If the `account` address is
+    ///   `Address::ZERO`.
+    /// * [`erc20::Error::InsufficientBalance`] - If the balance of `account` is
+    ///   less than `value`.
+    ///
+    /// # Events
+    ///
+    /// * [`erc20::Transfer`].
+    pub fn _burn(
+        &mut self,
+        account: Address,
+        value: U256,
+    ) -> Result<(), erc20::Error> {
+        if account.is_zero() {
+            return Err(erc20::Error::InvalidSender(ERC20InvalidSender {
+                sender: Address::ZERO,
+            }));
+        }
+        let shares = self.rebasing._to_shares(value, Rounding::Ceil);
+        self.update(account, Address::ZERO, shares, value)
+    }
+
+    /// Moves a `value` amount of tokens from `from` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account to transfer tokens to.
+    /// * `value` - Amount of tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc20::Error::InvalidSender`] - If the `from` address is
+    ///   `Address::ZERO`.
+    /// * [`erc20::Error::InvalidReceiver`] - If the `to` address is
+    ///   `Address::ZERO`.
+    /// * [`erc20::Error::InsufficientBalance`] - If the balance of `from` is
+    ///   less than `value`.
+    ///
+    /// # Events
+    ///
+    /// * [`erc20::Transfer`].
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), erc20::Error> {
+        if from.is_zero() {
+            return Err(erc20::Error::InvalidSender(ERC20InvalidSender {
+                sender: Address::ZERO,
+            }));
+        }
+        if to.is_zero() {
+            return Err(erc20::Error::InvalidReceiver(ERC20InvalidReceiver {
+                receiver: Address::ZERO,
+            }));
+        }
+        let shares = self.rebasing._to_shares(value, Rounding::Ceil);
+        self.update(from, to, shares, value)
+    }
+
+    /// Moves `shares` from `from` to `to`, and emits a [`erc20::Transfer`]
+    /// event of `value`, the amount of tokens these shares account for.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account to transfer shares from, or `Address::ZERO` to mint.
+    /// * `to` - Account to transfer shares to, or `Address::ZERO` to burn.
+    /// * `shares` - Amount of shares to move.
+    /// * `value` - Amount of tokens reported in errors and events.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc20::Error::InsufficientBalance`] - If `from` doesn't have enough
+    ///   shares.
+    /// * [`erc20::Error::TotalSupplyOverflow`] - If the total shares would
+    ///   exceed `U256::MAX`.
+    ///
+    /// # Events
+    ///
+    /// * [`erc20::Transfer`].
+    fn update(
+        &mut self,
+        from: Address,
+        to: Address,
+        shares: U256,
+        value: U256,
+    ) -> Result<(), erc20::Error> {
+        self.erc20.update_balances(from, to, shares).map_err(
+            |err| match err {
+                erc20::Error::InsufficientBalance(
+                    ERC20InsufficientBalance { sender, balance, .. },
+                ) => erc20::Error::InsufficientBalance(
+                    ERC20InsufficientBalance {
+                        sender,
+                        balance: self.rebasing._to_balance(balance),
+                        needed: value,
+                    },
+                ),
+                err => err,
+            },
+        )?;
+
+        evm::log(erc20::Transfer { from, to, value });
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{uint, Address, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::{prelude::*, storage::StorageU256};
+
+    use super::{Erc20Rebasing, Rebasing};
+    use crate::{
+        token::erc20::{self, ERC20InsufficientBalance, IErc20},
+        utils::math::{alloy::Math, Rounding},
+    };
+
+    const WAD: U256 = uint!(1_000_000_000_000_000_000_U256);
+
+    /// Scales balances by `factor`, in WAD.
+    #[storage]
+    struct Factor {
+        factor: StorageU256,
+    }
+
+    impl Rebasing for Factor {
+        fn _to_balance(&self, shares: U256) -> U256 {
+            shares.mul_div(self.factor.get(), WAD, Rounding::Floor)
+        }
+
+        fn _to_shares(&self, value: U256, rounding: Rounding) -> U256 {
+            value.mul_div(WAD, self.factor.get(), rounding)
+        }
+    }
+
+    unsafe impl<T: Rebasing + StorageType> TopLevelStorage for Erc20Rebasing<T> {}
+
+    fn init_token(contract: &Contract<Erc20Rebasing<Factor>>, alice: Address) {
+        contract.init(alice, |contract| {
+            contract.rebasing.factor.set(WAD);
+            contract._mint(alice, uint!(1000_U256)).motsu_unwrap();
+        });
+    }
+
+    fn rebase(
+        contract: &Contract<Erc20Rebasing<Factor>>,
+        alice: Address,
+        factor: U256,
+    ) {
+        contract.init(alice, |contract| contract.rebasing.factor.set(factor));
+    }
+
+    #[test]
+    fn default_conversions_are_identity() {
+        struct Identity;
+        impl Rebasing for Identity {}
+
+        let value = uint!(7_U256);
+        assert_eq!(value, Identity._to_balance(value));
+        assert_eq!(value, Identity._to_shares(value, Rounding::Ceil));
+    }
+
+    #[motsu::test]
+    fn rebase_scales_balances(
+        contract: Contract<Erc20Rebasing<Factor>>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init_token(&contract, alice);
+        contract.sender(alice).transfer(bob, uint!(250_U256)).motsu_unwrap();
+
+        // Rebase by 1.5.
+        rebase(&contract, alice, WAD * uint!(3_U256) / uint!(2_U256));
+
+        assert_eq!(uint!(1125_U256), contract.sender(alice).balance_of(alice));
+        assert_eq!(uint!(375_U256), contract.sender(alice).balance_of(bob));
+        assert_eq!(uint!(1500_U256), contract.sender(alice).total_supply());
+    }
+
+    #[motsu::test]
+    fn transfer_moves_scaled_amount(
+        contract: Contract<Erc20Rebasing<Factor>>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init_token(&contract, alice);
+        // Rebase by 2.
+        rebase(&contract, alice, WAD * uint!(2_U256));
+
+        contract.sender(alice).transfer(bob, uint!(600_U256)).motsu_unwrap();
+
+        assert_eq!(uint!(600_U256), contract.sender(alice).balance_of(bob));
+        assert_eq!(uint!(1400_U256), contract.sender(alice).balance_of(alice));
+        assert_eq!(
+            uint!(300_U256),
+            contract.sender(alice).erc20.balance_of(bob)
+        );
+        contract.assert_emitted(&erc20::Transfer {
+            from: alice,
+            to: bob,
+            value: uint!(600_U256),
+        });
+
+        // Odd amounts round in favor of the token, charging the sender the
+        // whole share.
+        contract.sender(alice).transfer(bob, uint!(1_U256)).motsu_unwrap();
+        assert_eq!(uint!(602_U256), contract.sender(alice).balance_of(bob));
+        assert_eq!(uint!(1398_U256), contract.sender(alice).balance_of(alice));
+        assert!(
+            contract.sender(alice).balance_of(alice)
+                + contract.sender(alice).balance_of(bob)
+                <= contract.sender(alice).total_supply()
+        );
+    }
+
+    #[motsu::test]
+    fn transfer_from_spends_allowance_in_balances(
+        contract: Contract<Erc20Rebasing<Factor>>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        init_token(&contract, alice);
+        contract.sender(alice).approve(bob, uint!(600_U256)).motsu_unwrap();
+        contract.assert_emitted(&erc20::Approval {
+            owner: alice,
+            spender: bob,
+            value: uint!(600_U256),
+        });
+
+        // Rebase by 2: the allowance is not scaled.
+        rebase(&contract, alice, WAD * uint!(2_U256));
+        assert_eq!(
+            uint!(600_U256),
+            contract.sender(alice).allowance(alice, bob)
+        );
+
+        contract
+            .sender(bob)
+            .transfer_from(alice, charlie, uint!(500_U256))
+            .motsu_unwrap();
+
+        assert_eq!(
+            uint!(100_U256),
+            contract.sender(alice).allowance(alice, bob)
+        );
+        assert_eq!(uint!(500_U256), contract.sender(alice).balance_of(charlie));
+        assert_eq!(uint!(1500_U256), contract.sender(alice).balance_of(alice));
+        assert_eq!(
+            uint!(250_U256),
+            contract.sender(alice).erc20.balance_of(charlie)
+        );
+        contract.assert_emitted(&erc20::Transfer {
+            from: alice,
+            to: charlie,
+            value: uint!(500_U256),
+        });
+
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, charlie, uint!(101_U256))
+            .motsu_unwrap_err();
+        assert!(matches!(err, erc20::Error::InsufficientAllowance(_)));
+    }
+
+    #[motsu::test]
+    fn insufficient_balance_is_reported_in_balances(
+        contract: Contract<Erc20Rebasing<Factor>>,
+        alice: Address,
+        bob: Address,
+    ) {
+        init_token(&contract, alice);
+        // Rebase by 2.
+        rebase(&contract, alice, WAD * uint!(2_U256));
+
+        let err = contract
+            .sender(alice)
+            .transfer(bob, uint!(2001_U256))
+            .motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            erc20::Error::InsufficientBalance(ERC20InsufficientBalance {
+                sender,
+                balance,
+                needed,
+            }) if sender == alice
+                && balance == uint!(2000_U256)
+                && needed == uint!(2001_U256)
+        ));
+    }
+
+    #[motsu::test]
+    fn mint_and_burn_scaled_amounts(
+        contract: Contract<Erc20Rebasing<Factor>>,
+        alice: Address,
+    ) {
+        init_token(&contract, alice);
+        // Rebase by 2.
+        rebase(&contract, alice, WAD * uint!(2_U256));
+
+        contract.sender(alice)._burn(alice, uint!(600_U256)).motsu_unwrap();
+        assert_eq!(uint!(1400_U256), contract.sender(alice).total_supply());
+        assert_eq!(
+            uint!(700_U256),
+            contract.sender(alice).erc20.total_supply()
+        );
+        contract.assert_emitted(&erc20::Transfer {
+            from: alice,
+            to: Address::ZERO,
+            value: uint!(600_U256),
+        });
+
+        contract.sender(alice)._mint(alice, uint!(200_U256)).motsu_unwrap();
+        assert_eq!(uint!(1600_U256), contract.sender(alice).balance_of(alice));
+        assert_eq!(uint!(1600_U256), contract.sender(alice).total_supply());
+    }
+}
@@ -13,11 +13,11 @@
 
 use alloc::{vec, vec::Vec};
 
-use alloy_primitives::{Address, FixedBytes, U256, U8};
+use alloy_primitives::{uint, Address, FixedBytes, U256, U8};
 use alloy_sol_macro::sol;
 use stylus_sdk::{
     call::{Call, MethodError},
-    contract, msg,
+    contract,
     prelude::*,
     storage::{StorageAddress, StorageU8},
 };
@@ -25,10 +25,16 @@ use stylus_sdk::{
 use crate::{
     token::erc20::{
         self,
-        utils::{safe_erc20, IErc20 as IErc20Solidity, ISafeErc20, SafeErc20},
+        utils::{
+            safe_erc20, IErc20 as IErc20Solidity, IErc20Metadata, ISafeErc20,
+            SafeErc20,
+        },
         Erc20, IErc20,
     },
-    utils::introspection::erc165::{Erc165, IErc165},
+    utils::{
+        introspection::erc165::{Erc165, IErc165},
+        Context,
+    },
 };
 
 sol! {
@@ -53,6 +59,14 @@ sol! {
     #[allow(missing_docs)]
     error ERC20InvalidReceiver(address receiver);
 
+    /// Indicates that the decimals of the wrapper don't match the decimals of
+    /// the underlying token.
+    ///
+    /// * `underlying_decimals` - Decimals of the underlying token.
+    /// * `decimals` - Decimals requested for the wrapper.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    error ERC20WrapperDecimalsMismatch(uint8 underlying_decimals, uint8 decimals);
 }
 
 /// An [`Erc20Wrapper`] error.
@@ -72,6 +86,9 @@ pub enum Error {
 
     /// The underlying token couldn't be wrapped.
     InvalidUnderlying(ERC20InvalidUnderlying),
+
+    /// The decimals of the wrapper don't match the underlying token's.
+    DecimalsMismatch(ERC20WrapperDecimalsMismatch),
 }
 
 impl MethodError for Error {
@@ -116,6 +133,9 @@ pub trait IErc20Wrapper {
 
     /// Returns the number of decimals used to get its user representation.
     ///
+    /// These are the decimals of the underlying token, as set by
+    /// [`Erc20Wrapper::_set_underlying`].
+    ///
     /// # Arguments
     ///
     /// * `&self` - Read access to the contract's state.
@@ -157,8 +177,8 @@ pub trait IErc20Wrapper {
     ///
     /// # Errors
     ///
-    /// * [`Error::InvalidSender`] - If the `msg::sender()`'s address is the
-    ///   `contract:address()`.
+    /// * [`Error::InvalidSender`] - If the [`Context::_msg_sender`]'s address
+    ///   is the `contract:address()`.
     /// * [`Error::InvalidReceiver`] - If the `account` address is a
     ///   `contract:address()`.
     /// * [`Error::SafeErc20`] - If caller lacks sufficient balance or hasn't
@@ -240,8 +260,50 @@ impl IErc20Wrapper for Erc20Wrapper {
         value: U256,
         erc20: &mut Erc20,
     ) -> Result<bool, Self::Error> {
+        self.deposit_for_with_sender(self._msg_sender(), account, value, erc20)
+    }
+
+    fn withdraw_to(
+        &mut self,
+        account: Address,
+        value: U256,
+        erc20: &mut Erc20,
+    ) -> Result<bool, Self::Error> {
+        self.withdraw_to_with_sender(self._msg_sender(), account, value, erc20)
+    }
+}
+
+impl Context for Erc20Wrapper {}
+
+impl Erc20Wrapper {
+    /// Same as [`IErc20Wrapper::deposit_for`], acting for `sender` rather
+    /// than [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account depositing its underlying tokens.
+    /// * `account` - The account to deposit tokens to.
+    /// * `value` - The amount of tokens to deposit.
+    /// * `erc20` - Write access to an [`Erc20`] contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSender`] - If `sender` is the `contract:address()`.
+    /// * [`Error::InvalidReceiver`] - If the `account` address is a
+    ///   `contract:address()`.
+    /// * [`Error::SafeErc20`] - If `sender` lacks sufficient balance or hasn't
+    ///   approved enough tokens to the [`Erc20Wrapper`] contract.
+    /// * [`Error::Erc20`] - If an error occurrs during [`Erc20::_mint`]
+    ///   operation.
+    pub fn deposit_for_with_sender(
+        &mut self,
+        sender: Address,
+        account: Address,
+        value: U256,
+        erc20: &mut Erc20,
+    ) -> Result<bool, Error> {
         let contract_address = contract::address();
-        let sender = msg::sender();
 
         if sender == contract_address {
             return Err(ERC20InvalidSender { sender }.into());
@@ -263,17 +325,37 @@ impl IErc20Wrapper for Erc20Wrapper {
         Ok(true)
     }
 
-    fn withdraw_to(
+    /// Same as [`IErc20Wrapper::withdraw_to`], acting for `sender` rather
+    /// than [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account burning its wrapped tokens.
+    /// * `account` - The account to withdraw tokens to.
+    /// * `value` - The amount of tokens to withdraw.
+    /// * `erc20` - Write access to an [`Erc20`] contract.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If the `account`'s address is a
+    ///   `contract:address()`.
+    /// * [`Error::Erc20`] - If an error occurrs during [`Erc20::_burn`]
+    ///   operation.
+    /// * [`Error::SafeErc20`] - If the [`Erc20Wrapper`] contract lacks
+    ///   sufficient balance.
+    pub fn withdraw_to_with_sender(
         &mut self,
+        sender: Address,
         account: Address,
         value: U256,
         erc20: &mut Erc20,
-    ) -> Result<bool, Self::Error> {
+    ) -> Result<bool, Error> {
         if account == contract::address() {
             return Err(ERC20InvalidReceiver { receiver: account }.into());
         }
 
-        erc20._burn(msg::sender(), value)?;
+        erc20._burn(sender, value)?;
 
         self.safe_erc20.safe_transfer(self.underlying(), account, value)?;
 
@@ -281,7 +363,58 @@ impl IErc20Wrapper for Erc20Wrapper {
     }
 }
 
+/// Decimals of an underlying token not exposing them.
+pub const DEFAULT_DECIMALS: U8 = uint!(18_U8);
+
 impl Erc20Wrapper {
+    /// Sets the `underlying` token, and the decimals of the wrapper to the
+    /// decimals of `underlying`, so that wrapped tokens are exchanged 1:1.
+    ///
+    /// Underlying tokens not exposing their decimals are assumed to use
+    /// [`DEFAULT_DECIMALS`].
+    ///
+    /// Internal function meant to be called once, when initializing the
+    /// contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `underlying` - Address of the underlying token.
+    /// * `decimals` - Decimals required by the caller for the wrapper, if any.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidUnderlying`] - If `underlying` is the wrapper itself.
+    /// * [`Error::DecimalsMismatch`] - If `decimals` is provided and differs
+    ///   from the decimals of `underlying`.
+    pub fn _set_underlying(
+        &mut self,
+        underlying: Address,
+        decimals: Option<U8>,
+    ) -> Result<(), Error> {
+        if underlying == contract::address() {
+            return Err(ERC20InvalidUnderlying { token: underlying }.into());
+        }
+
+        let underlying_decimals = IErc20Metadata::new(underlying)
+            .decimals(Call::new_in(self))
+            .map_or(DEFAULT_DECIMALS, U8::from);
+
+        if let Some(decimals) = decimals {
+            if decimals != underlying_decimals {
+                return Err(ERC20WrapperDecimalsMismatch {
+                    underlying_decimals: underlying_decimals.to(),
+                    decimals: decimals.to(),
+                }
+                .into());
+            }
+        }
+
+        self.underlying.set(underlying);
+        self.underlying_decimals.set(underlying_decimals);
+        Ok(())
+    }
+
     /// Mint wrapped token to cover any underlying tokens that would have been
     /// transferred by mistake or acquired from rebasing mechanisms.
     ///
@@ -383,6 +516,106 @@ mod tests {
 
     unsafe impl TopLevelStorage for Erc20WrapperTestExample {}
 
+    /// Underlying token exposing its decimals.
+    #[storage]
+    struct Erc20WithDecimals {
+        decimals: StorageU8,
+    }
+
+    unsafe impl TopLevelStorage for Erc20WithDecimals {}
+
+    #[public]
+    impl Erc20WithDecimals {
+        fn decimals(&self) -> U8 {
+            self.decimals.get()
+        }
+    }
+
+    #[motsu::test]
+    fn set_underlying_inherits_decimals(
+        contract: Contract<Erc20WrapperTestExample>,
+        token: Contract<Erc20WithDecimals>,
+        alice: Address,
+    ) {
+        token.init(alice, |token| token.decimals.set(uint!(6_U8)));
+        contract
+            .sender(alice)
+            .wrapper
+            ._set_underlying(token.address(), None)
+            .motsu_unwrap();
+
+        assert_eq!(contract.sender(alice).underlying(), token.address());
+        assert_eq!(contract.sender(alice).decimals(), uint!(6_U8));
+
+        // Requiring the underlying token's decimals is allowed.
+        contract
+            .sender(alice)
+            .wrapper
+            ._set_underlying(token.address(), Some(uint!(6_U8)))
+            .motsu_unwrap();
+        assert_eq!(contract.sender(alice).decimals(), uint!(6_U8));
+    }
+
+    // TODO: Calls to missing functions panic with current motsu limitations,
+    // instead of reverting.
+    #[motsu::test]
+    #[ignore]
+    fn set_underlying_defaults_decimals(
+        contract: Contract<Erc20WrapperTestExample>,
+        erc20_contract: Contract<Erc20>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            .wrapper
+            ._set_underlying(erc20_contract.address(), None)
+            .motsu_unwrap();
+
+        assert_eq!(contract.sender(alice).decimals(), DEFAULT_DECIMALS);
+    }
+
+    #[motsu::test]
+    fn set_underlying_reverts_on_decimals_mismatch(
+        contract: Contract<Erc20WrapperTestExample>,
+        token: Contract<Erc20WithDecimals>,
+        alice: Address,
+    ) {
+        token.init(alice, |token| token.decimals.set(uint!(6_U8)));
+        let err = contract
+            .sender(alice)
+            .wrapper
+            ._set_underlying(token.address(), Some(uint!(18_U8)))
+            .motsu_unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::DecimalsMismatch(ERC20WrapperDecimalsMismatch {
+                underlying_decimals: 6,
+                decimals: 18,
+            })
+        ));
+        assert_eq!(contract.sender(alice).underlying(), Address::ZERO);
+        assert_eq!(contract.sender(alice).decimals(), U8::ZERO);
+    }
+
+    #[motsu::test]
+    fn set_underlying_reverts_on_self(
+        contract: Contract<Erc20WrapperTestExample>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .wrapper
+            ._set_underlying(contract.address(), None)
+            .motsu_unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::InvalidUnderlying(ERC20InvalidUnderlying { token })
+                if token == contract.address()
+        ));
+    }
+
     #[motsu::test]
     fn decimals_works(
         contract: Contract<Erc20WrapperTestExample>,
@@ -4,20 +4,26 @@
 //! revert instead of returning `false` on failure. This behavior is
 //! nonetheless conventional and does not conflict with the expectations of
 //! [`Erc20`] applications.
+//!
+//! Integrators that need legacy semantics can embed an
+//! `Erc20<ReturnFalse>`, whose `transfer`, `transfer_from` and `approve`
+//! return `false` on failure. See [`FailureMode`].
 use alloc::{vec, vec::Vec};
 
 use alloy_primitives::{Address, FixedBytes, U256};
 use openzeppelin_stylus_proc::interface_id;
 use stylus_sdk::{
     call::MethodError,
-    evm, msg,
+    evm,
+    host::VM,
     prelude::*,
-    storage::{StorageMap, StorageU256},
+    storage::{StorageMap, StorageType, StorageU256},
 };
 
 use crate::utils::{
     introspection::erc165::{Erc165, IErc165},
-    math::storage::{AddAssignChecked, AddAssignUnchecked, SubAssignUnchecked},
+    math::storage::{AddAssignUnchecked, SubAssignUnchecked},
+    Context,
 };
 
 pub mod extensions;
@@ -93,6 +99,26 @@ mod sol {
         #[allow(missing_docs)]
         error ERC20InvalidApprover(address approver);
 
+        /// Indicates that minting `value` tokens would overflow the
+        /// `total_supply`.
+        ///
+        /// * `total_supply` - Current total supply of the token.
+        /// * `value` - Number of tokens to mint.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC20TotalSupplyOverflow(uint256 total_supply, uint256 value);
+
+        /// Indicates that the current `allowance` of `spender` differs from the
+        /// `expected` one. Used in checked approvals.
+        ///
+        /// * `spender` - Address that may be allowed to operate on tokens without
+        /// being their owner.
+        /// * `allowance` - Current amount of tokens `spender` is allowed to
+        /// operate with.
+        /// * `expected` - Allowance expected by the caller.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC20AllowanceMismatch(address spender, uint256 allowance, uint256 expected);
     }
 }
 
@@ -117,6 +143,11 @@ pub enum Error {
     /// Indicates a failure with the `approver` of a token to be approved. Used
     /// in approvals. approver Address initiating an approval operation.
     InvalidApprover(ERC20InvalidApprover),
+    /// Indicates that minting would overflow the `total_supply`.
+    TotalSupplyOverflow(ERC20TotalSupplyOverflow),
+    /// Indicates that the current allowance of `spender` differs from the
+    /// expected one. Used in checked approvals.
+    AllowanceMismatch(ERC20AllowanceMismatch),
 }
 
 impl MethodError for Error {
@@ -125,9 +156,72 @@ impl MethodError for Error {
     }
 }
 
+/// Behavior of [`Erc20`] `transfer`, `transfer_from` and `approve` on
+/// failure, selected by the type parameter of [`Erc20`].
+///
+/// ```rust,ignore
+/// #[entrypoint]
+/// #[storage]
+/// struct LegacyToken {
+///     #[borrow]
+///     erc20: Erc20<ReturnFalse>,
+/// }
+///
+/// #[public]
+/// #[inherit(Erc20<ReturnFalse>)]
+/// impl LegacyToken {}
+/// ```
+pub trait FailureMode: StorageType {
+    /// Whether failures return `false` and leave the state untouched, rather
+    /// than revert with an [`Error`].
+    const RETURN_FALSE: bool;
+}
+
+/// Implements [`FailureMode`] for a marker type, stored in no slot.
+macro_rules! impl_failure_mode {
+    ($name:ident, $return_false:literal) => {
+        impl FailureMode for $name {
+            const RETURN_FALSE: bool = $return_false;
+        }
+
+        impl StorageType for $name {
+            type Wraps<'a> = Self;
+            type WrapsMut<'a> = Self;
+
+            const REQUIRED_SLOTS: usize = 0;
+            const SLOT_BYTES: usize = 0;
+
+            unsafe fn new(_slot: U256, _offset: u8, _host: VM) -> Self {
+                Self
+            }
+
+            fn load<'s>(self) -> Self::Wraps<'s> {
+                self
+            }
+
+            fn load_mut<'s>(self) -> Self::WrapsMut<'s> {
+                self
+            }
+        }
+    };
+}
+
+/// [`FailureMode`] reverting with an [`Error`], the default of [`Erc20`].
+#[derive(Debug)]
+pub struct Revert;
+
+impl_failure_mode!(Revert, false);
+
+/// [`FailureMode`] returning `false` and leaving the state untouched, as some
+/// legacy ERC-20 tokens do.
+#[derive(Debug)]
+pub struct ReturnFalse;
+
+impl_failure_mode!(ReturnFalse, true);
+
 /// State of an [`Erc20`] token.
 #[storage]
-pub struct Erc20 {
+pub struct Erc20<M: FailureMode = Revert> {
     /// Maps users to balances.
     pub(crate) balances: StorageMap<Address, StorageU256>,
     /// Maps users to a mapping of each spender's allowance.
@@ -135,6 +229,8 @@ pub struct Erc20 {
         StorageMap<Address, StorageMap<Address, StorageU256>>,
     /// The total supply of the token.
     pub(crate) total_supply: StorageU256,
+    /// Marker selecting the [`FailureMode`] of the token.
+    _failure_mode: M,
 }
 
 /// Required interface of an [`Erc20`] compliant contract.
@@ -267,7 +363,7 @@ pub trait IErc20 {
 }
 
 #[public]
-impl IErc20 for Erc20 {
+impl<M: FailureMode> IErc20 for Erc20<M> {
     type Error = Error;
 
     fn total_supply(&self) -> U256 {
@@ -283,9 +379,7 @@ impl IErc20 for Erc20 {
         to: Address,
         value: U256,
     ) -> Result<bool, Self::Error> {
-        let from = msg::sender();
-        self._transfer(from, to, value)?;
-        Ok(true)
+        self.transfer_with_sender(self._msg_sender(), to, value)
     }
 
     fn allowance(&self, owner: Address, spender: Address) -> U256 {
@@ -297,8 +391,7 @@ impl IErc20 for Erc20 {
         spender: Address,
         value: U256,
     ) -> Result<bool, Self::Error> {
-        let owner = msg::sender();
-        self._approve(owner, spender, value, true)
+        self.approve_with_sender(self._msg_sender(), spender, value)
     }
 
     fn transfer_from(
@@ -307,14 +400,211 @@ impl IErc20 for Erc20 {
         to: Address,
         value: U256,
     ) -> Result<bool, Self::Error> {
-        let spender = msg::sender();
-        self._spend_allowance(from, spender, value)?;
-        self._transfer(from, to, value)?;
-        Ok(true)
+        self.transfer_from_with_sender(self._msg_sender(), from, to, value)
     }
 }
 
-impl Erc20 {
+impl<M: FailureMode> Erc20<M> {
+    /// Maps the outcome of an operation to the return value of the
+    /// corresponding [`IErc20`] function, according to the [`FailureMode`].
+    fn handle(result: Result<(), Error>) -> Result<bool, Error> {
+        match result {
+            Ok(()) => Ok(true),
+            Err(_) if M::RETURN_FALSE => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Same as [`IErc20::transfer`], acting for `sender` rather than
+    /// [`Context::_msg_sender`].
+    ///
+    /// A contract overriding [`Context`] forwards its own `transfer` here
+    /// with the resolved sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account transferring its tokens.
+    /// * `to` - Account to transfer tokens to.
+    /// * `value` - Number of tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSender`] - If the `sender` address is `Address::ZERO`.
+    /// * [`Error::InvalidReceiver`] - If the `to` address is `Address::ZERO`.
+    /// * [`Error::InsufficientBalance`] - If `sender` doesn't have a balance of
+    ///   at least `value`.
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`].
+    pub fn transfer_with_sender(
+        &mut self,
+        sender: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<bool, Error> {
+        Self::handle(self._transfer(sender, to, value))
+    }
+
+    /// Same as [`IErc20::approve`], acting for `owner` rather than
+    /// [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account that owns the tokens.
+    /// * `spender` - Account that will spend the tokens.
+    /// * `value` - Number of tokens `spender` is allowed to spend.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidApprover`] - If the `owner` address is
+    ///   `Address::ZERO`.
+    /// * [`Error::InvalidSpender`] - If the `spender` address is
+    ///   `Address::ZERO`.
+    ///
+    /// # Events
+    ///
+    /// * [`Approval`].
+    pub fn approve_with_sender(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        value: U256,
+    ) -> Result<bool, Error> {
+        Self::handle(self._approve(owner, spender, value, true).map(|_| ()))
+    }
+
+    /// Same as [`IErc20::transfer_from`], acting for `sender` rather than
+    /// [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account spending its allowance.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account to transfer tokens to.
+    /// * `value` - Number of tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidSender`] - If the `from` address is `Address::ZERO`.
+    /// * [`Error::InvalidReceiver`] - If the `to` address is `Address::ZERO`.
+    /// * [`Error::InsufficientAllowance`] - If not enough allowance is
+    ///   available.
+    /// * [`Error::InsufficientBalance`] - If the `from` address doesn't have
+    ///   enough tokens.
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`].
+    pub fn transfer_from_with_sender(
+        &mut self,
+        sender: Address,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<bool, Error> {
+        let allowance = self.allowance(from, sender);
+        let result = self
+            ._spend_allowance(from, sender, value)
+            .and_then(|()| self._transfer(from, to, value));
+
+        if result.is_err() && M::RETURN_FALSE {
+            // No revert will roll the spent allowance back, so restore it.
+            self.allowances.setter(from).insert(sender, allowance);
+        }
+
+        Self::handle(result)
+    }
+
+    /// Sets `new_value` as the allowance of `spender` over the caller's
+    /// tokens, only if the current allowance is `current_expected`.
+    ///
+    /// Unlike changing an allowance with [`IErc20::approve`], this cannot be
+    /// front-run by `spender` spending the current allowance before the new
+    /// one is set, which would let it spend both. No need to reset the
+    /// allowance to zero in a separate transaction first.
+    ///
+    /// Returns a boolean value indicating whether the operation succeeded.
+    ///
+    /// NOTE: This function is not part of the [ERC-20] standard, and has to be
+    /// exposed by the contract.
+    ///
+    /// [ERC-20]: https://eips.ethereum.org/EIPS/eip-20
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `spender` - Account that will spend the tokens.
+    /// * `current_expected` - Allowance of `spender` expected by the caller.
+    /// * `new_value` - Number of tokens `spender` is allowed to spend.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::AllowanceMismatch`] - If the current allowance of `spender`
+    ///   is not `current_expected`.
+    /// * [`Error::InvalidSpender`] - If the `spender` address is
+    ///   `Address::ZERO`.
+    ///
+    /// # Events
+    ///
+    /// * [`Approval`].
+    pub fn set_allowance(
+        &mut self,
+        spender: Address,
+        current_expected: U256,
+        new_value: U256,
+    ) -> Result<bool, Error> {
+        self.set_allowance_with_sender(
+            self._msg_sender(),
+            spender,
+            current_expected,
+            new_value,
+        )
+    }
+
+    /// Same as [`Erc20::set_allowance`], acting for `owner` rather than
+    /// [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `owner` - Account that owns the tokens.
+    /// * `spender` - Account that will spend the tokens.
+    /// * `current_expected` - Allowance of `spender` expected by `owner`.
+    /// * `new_value` - Number of tokens `spender` is allowed to spend.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::AllowanceMismatch`] - If the current allowance of `spender`
+    ///   is not `current_expected`.
+    /// * [`Error::InvalidSpender`] - If the `spender` address is
+    ///   `Address::ZERO`.
+    ///
+    /// # Events
+    ///
+    /// * [`Approval`].
+    pub fn set_allowance_with_sender(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        current_expected: U256,
+        new_value: U256,
+    ) -> Result<bool, Error> {
+        let allowance = self.allowance(owner, spender);
+        if allowance != current_expected {
+            return Err(Error::AllowanceMismatch(ERC20AllowanceMismatch {
+                spender,
+                allowance,
+                expected: current_expected,
+            }));
+        }
+
+        self._approve(owner, spender, new_value, true)
+    }
+
     /// Sets a `value` number of tokens as the allowance of `spender` over the
     /// caller's tokens.
     ///
@@ -408,14 +698,12 @@ impl Erc20 {
     ///
     /// * [`Error::InvalidReceiver`] - If the `account` address is
     ///   `Address::ZERO`.
+    /// * [`Error::TotalSupplyOverflow`] - If `total_supply` would exceed
+    ///   `U256::MAX`.
     ///
     /// # Events
     ///
     /// * [`Transfer`].
-    ///
-    /// # Panics
-    ///
-    /// * If `total_supply` exceeds `U256::MAX`.
     pub fn _mint(
         &mut self,
         account: Address,
@@ -445,16 +733,46 @@ impl Erc20 {
     ///
     /// * [`Error::InsufficientBalance`] - If the `from` address doesn't have
     ///   enough tokens.
+    /// * [`Error::TotalSupplyOverflow`] - If `total_supply` would exceed
+    ///   `U256::MAX`. It may happen during `mint` operation.
     ///
     /// # Events
     ///
     /// * [`Transfer`].
+    pub fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), Error> {
+        self.update_balances(from, to, value)?;
+
+        evm::log(Transfer { from, to, value });
+
+        Ok(())
+    }
+
+    /// Moves a `value` amount of tokens from `from` to `to`, as
+    /// [`Self::_update`] does, without emitting [`Transfer`].
     ///
-    /// # Panics
+    /// Lets extensions storing other units than balances, e.g.
+    /// [`extensions::rebasing::Erc20Rebasing`], emit their own [`Transfer`]
+    /// event.
     ///
-    /// * If `total_supply` exceeds `U256::MAX`. It may happen during `mint`
-    ///   operation.
-    pub fn _update(
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Owner's address.
+    /// * `to` - Recipient's address.
+    /// * `value` - Amount to be transferred.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InsufficientBalance`] - If the `from` address doesn't have
+    ///   enough tokens.
+    /// * [`Error::TotalSupplyOverflow`] - If `total_supply` would exceed
+    ///   `U256::MAX`. It may happen during `mint` operation.
+    pub(crate) fn update_balances(
         &mut self,
         from: Address,
         to: Address,
@@ -463,10 +781,13 @@ impl Erc20 {
         if from.is_zero() {
             // Mint operation. Overflow check required: the rest of the code
             // assumes that `total_supply` never overflows.
-            self.total_supply.add_assign_checked(
-                value,
-                "should not exceed `U256::MAX` for `total_supply`",
-            );
+            let total_supply = self.total_supply.get();
+            let Some(new_total_supply) = total_supply.checked_add(value) else {
+                return Err(Error::TotalSupplyOverflow(
+                    ERC20TotalSupplyOverflow { total_supply, value },
+                ));
+            };
+            self.total_supply.set(new_total_supply);
         } else {
             let from_balance = self.balances.get(from);
             if from_balance < value {
@@ -495,8 +816,6 @@ impl Erc20 {
             self.balances.setter(to).add_assign_unchecked(value);
         }
 
-        evm::log(Transfer { from, to, value });
-
         Ok(())
     }
 
@@ -574,6 +893,11 @@ impl Erc20 {
     }
 }
 
+impl<M: FailureMode> Context for Erc20<M> {}
+
+/// Implemented for the default [`FailureMode`] only, so that
+/// `Erc20::supports_interface` needs no type annotation. The interface is the
+/// same for every [`FailureMode`].
 impl IErc165 for Erc20 {
     fn supports_interface(interface_id: FixedBytes<4>) -> bool {
         <Self as IErc20>::INTERFACE_ID == u32::from_be_bytes(*interface_id)
@@ -585,11 +909,14 @@ impl IErc165 for Erc20 {
 mod tests {
     use alloy_primitives::{uint, Address, U256};
     use motsu::prelude::*;
-    use stylus_sdk::prelude::*;
+    use stylus_sdk::{prelude::*, storage::StorageType};
 
-    use super::{Approval, Erc20, Error, IErc165, IErc20, Transfer};
+    use super::{
+        Approval, ERC20AllowanceMismatch, ERC20TotalSupplyOverflow, Erc20,
+        Error, FailureMode, IErc165, IErc20, ReturnFalse, Transfer,
+    };
 
-    unsafe impl TopLevelStorage for Erc20 {}
+    unsafe impl<M: FailureMode> TopLevelStorage for Erc20<M> {}
 
     #[motsu::test]
     fn mint(contract: Contract<Erc20>, alice: Address) {
@@ -618,7 +945,6 @@ mod tests {
     }
 
     #[motsu::test]
-    #[should_panic = "should not exceed `U256::MAX` for `total_supply`"]
     fn update_mint_errors_arithmetic_overflow(
         contract: Contract<Erc20>,
         alice: Address,
@@ -635,7 +961,17 @@ mod tests {
             .motsu_expect("should mint tokens");
         // Mint action should NOT work:
         // overflow on `total_supply`.
-        let _result = contract.sender(alice)._mint(alice, one);
+        let err = contract
+            .sender(alice)
+            ._update(Address::ZERO, alice, one)
+            .motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            Error::TotalSupplyOverflow(ERC20TotalSupplyOverflow {
+                total_supply,
+                value,
+            }) if total_supply == U256::MAX && value == one
+        ));
     }
 
     #[motsu::test]
@@ -661,23 +997,35 @@ mod tests {
     }
 
     #[motsu::test]
-    #[should_panic = "should not exceed `U256::MAX` for `total_supply`"]
     fn mint_errors_arithmetic_overflow(
         contract: Contract<Erc20>,
         alice: Address,
+        bob: Address,
     ) {
         let one = uint!(1_U256);
+        let near_max = U256::MAX - one;
         assert_eq!(U256::ZERO, contract.sender(alice).balance_of(alice));
         assert_eq!(U256::ZERO, contract.sender(alice).total_supply());
 
         // Initialize state for the test case:
-        // Alice's balance as `U256::MAX`.
+        // Alice's balance as `U256::MAX - 1`.
         contract
             .sender(alice)
-            ._mint(alice, U256::MAX)
+            ._mint(alice, near_max)
             .motsu_expect("should mint tokens");
         // Mint action should NOT work -- overflow on `_total_supply`.
-        let _result = contract.sender(alice)._mint(alice, one);
+        let err =
+            contract.sender(alice)._mint(bob, one + one).motsu_unwrap_err();
+        assert!(matches!(err, Error::TotalSupplyOverflow(_)));
+
+        // Check state didn't wrap.
+        assert_eq!(near_max, contract.sender(alice).total_supply());
+        assert_eq!(near_max, contract.sender(alice).balance_of(alice));
+        assert_eq!(U256::ZERO, contract.sender(alice).balance_of(bob));
+
+        // Minting up to `U256::MAX` still works.
+        contract.sender(alice)._mint(bob, one).motsu_unwrap();
+        assert_eq!(U256::MAX, contract.sender(alice).total_supply());
     }
 
     #[motsu::test]
@@ -713,6 +1061,32 @@ mod tests {
         });
     }
 
+    #[motsu::test]
+    fn mint_and_burn_emit_transfer_from_and_to_zero(
+        contract: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let value = uint!(7_U256);
+
+        contract.sender(alice)._mint(bob, value).motsu_unwrap();
+        contract.sender(alice)._burn(bob, value).motsu_unwrap();
+
+        contract.assert_emitted(&Transfer {
+            from: Address::ZERO,
+            to: bob,
+            value,
+        });
+        contract.assert_emitted(&Transfer {
+            from: bob,
+            to: Address::ZERO,
+            value,
+        });
+        // The caller is never reported as an endpoint.
+        assert!(!contract.emitted(&Transfer { from: alice, to: bob, value }));
+        assert!(!contract.emitted(&Transfer { from: bob, to: alice, value }));
+    }
+
     #[motsu::test]
     fn burn_errors_insufficient_balance(
         contract: Contract<Erc20>,
@@ -938,6 +1312,162 @@ mod tests {
         assert!(matches!(err, Error::InvalidApprover(_)));
     }
 
+    #[motsu::test]
+    fn transfer_reverts_by_default(
+        contract: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let one = uint!(1_U256);
+
+        let err = contract.sender(alice).transfer(bob, one).motsu_unwrap_err();
+        assert!(matches!(err, Error::InsufficientBalance(_)));
+    }
+
+    #[motsu::test]
+    fn transfer_returns_false_in_return_false_mode(
+        contract: Contract<Erc20<ReturnFalse>>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let one = uint!(1_U256);
+        contract.sender(alice)._mint(alice, one).motsu_unwrap();
+
+        let result =
+            contract.sender(alice).transfer(bob, one + one).motsu_unwrap();
+        assert!(!result);
+        assert_eq!(one, contract.sender(alice).balance_of(alice));
+        assert_eq!(U256::ZERO, contract.sender(alice).balance_of(bob));
+
+        let result = contract.sender(alice).transfer(bob, one).motsu_unwrap();
+        assert!(result);
+        assert_eq!(one, contract.sender(alice).balance_of(bob));
+    }
+
+    #[test]
+    fn failure_mode_takes_no_storage_slot() {
+        assert_eq!(3, <Erc20 as StorageType>::REQUIRED_SLOTS);
+        assert_eq!(3, <Erc20<ReturnFalse> as StorageType>::REQUIRED_SLOTS);
+    }
+
+    #[motsu::test]
+    fn set_allowance_from_zero(
+        contract: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let one = uint!(1_U256);
+
+        let result = contract
+            .sender(alice)
+            .set_allowance(bob, U256::ZERO, one)
+            .motsu_unwrap();
+
+        assert!(result);
+        assert_eq!(one, contract.sender(alice).allowance(alice, bob));
+        contract.assert_emitted(&Approval {
+            owner: alice,
+            spender: bob,
+            value: one,
+        });
+    }
+
+    #[motsu::test]
+    fn set_allowance_with_matching_expectation(
+        contract: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let one = uint!(1_U256);
+        let two = uint!(2_U256);
+        contract.sender(alice).approve(bob, one).motsu_unwrap();
+
+        contract.sender(alice).set_allowance(bob, one, two).motsu_unwrap();
+        assert_eq!(two, contract.sender(alice).allowance(alice, bob));
+
+        contract
+            .sender(alice)
+            .set_allowance(bob, two, U256::ZERO)
+            .motsu_unwrap();
+        assert_eq!(U256::ZERO, contract.sender(alice).allowance(alice, bob));
+    }
+
+    #[motsu::test]
+    fn error_when_set_allowance_with_mismatched_expectation(
+        contract: Contract<Erc20>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let one = uint!(1_U256);
+        let two = uint!(2_U256);
+        contract.sender(alice)._mint(alice, two).motsu_unwrap();
+        contract.sender(alice).approve(bob, two).motsu_unwrap();
+
+        // Bob spent part of the allowance before the change.
+        contract.sender(bob).transfer_from(alice, bob, one).motsu_unwrap();
+
+        let err = contract
+            .sender(alice)
+            .set_allowance(bob, two, uint!(5_U256))
+            .motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            Error::AllowanceMismatch(ERC20AllowanceMismatch {
+                spender,
+                allowance,
+                expected,
+            }) if spender == bob && allowance == one && expected == two
+        ));
+        assert_eq!(one, contract.sender(alice).allowance(alice, bob));
+    }
+
+    #[motsu::test]
+    fn approve_returns_false_in_return_false_mode(
+        contract: Contract<Erc20>,
+        legacy: Contract<Erc20<ReturnFalse>>,
+        alice: Address,
+    ) {
+        let one = uint!(1_U256);
+
+        let err = contract
+            .sender(alice)
+            .approve(Address::ZERO, one)
+            .motsu_unwrap_err();
+        assert!(matches!(err, Error::InvalidSpender(_)));
+
+        let result =
+            legacy.sender(alice).approve(Address::ZERO, one).motsu_unwrap();
+        assert!(!result);
+    }
+
+    #[motsu::test]
+    fn transfer_from_returns_false_and_keeps_allowance_in_return_false_mode(
+        contract: Contract<Erc20>,
+        legacy: Contract<Erc20<ReturnFalse>>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let one = uint!(1_U256);
+        let two = uint!(2_U256);
+        contract.sender(alice)._mint(alice, one).motsu_unwrap();
+        contract.sender(alice).approve(bob, two).motsu_unwrap();
+        legacy.sender(alice)._mint(alice, one).motsu_unwrap();
+        legacy.sender(alice).approve(bob, two).motsu_unwrap();
+
+        // Allowance is sufficient, but the balance is not.
+        let err = contract
+            .sender(bob)
+            .transfer_from(alice, bob, two)
+            .motsu_unwrap_err();
+        assert!(matches!(err, Error::InsufficientBalance(_)));
+
+        let result =
+            legacy.sender(bob).transfer_from(alice, bob, two).motsu_unwrap();
+        assert!(!result);
+        assert_eq!(two, legacy.sender(alice).allowance(alice, bob));
+        assert_eq!(one, legacy.sender(alice).balance_of(alice));
+    }
+
     #[motsu::test]
     fn interface_id() {
         let actual = <Erc20 as IErc20>::INTERFACE_ID;
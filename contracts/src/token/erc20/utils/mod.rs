@@ -18,5 +18,10 @@ mod token {
             function balanceOf(address account) external view returns (uint256);
             function totalSupply() external view returns (uint256);
         }
+
+        /// Solidity Interface of the optional ERC-20 metadata.
+        interface IErc20Metadata {
+            function decimals() external view returns (uint8);
+        }
     }
 }
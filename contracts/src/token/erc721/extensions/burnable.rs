@@ -1,9 +1,11 @@
 //! Optional Burnable extension of the ERC-721 standard.
 
 use alloy_primitives::{Address, U256};
-use stylus_sdk::msg;
 
-use crate::token::erc721::{self, Erc721};
+use crate::{
+    token::erc721::{self, Erc721},
+    utils::Context,
+};
 
 /// An [`Erc721`] token that can be burned (destroyed).
 pub trait IErc721Burnable {
@@ -33,13 +35,41 @@ impl IErc721Burnable for Erc721 {
     type Error = erc721::Error;
 
     fn burn(&mut self, token_id: U256) -> Result<(), Self::Error> {
+        self.burn_with_sender(self._msg_sender(), token_id)
+    }
+}
+
+impl Erc721 {
+    /// Same as [`IErc721Burnable::burn`], acting for `sender` rather than
+    /// [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account burning the token.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc721::Error::NonexistentToken`] - If token does not exist.
+    /// * [`erc721::Error::InsufficientApproval`] - If `sender` does not have
+    ///   the right to approve.
+    ///
+    /// # Events
+    ///
+    /// * [`erc721::Transfer`].
+    pub fn burn_with_sender(
+        &mut self,
+        sender: Address,
+        token_id: U256,
+    ) -> Result<(), erc721::Error> {
         // Setting an "auth" arguments enables the
         // [`super::super::Erc721::_is_authorized`] check which verifies that
         // the token exists (from != `Address::ZERO`).
         //
         // Therefore, it is not needed to verify that the return value is not 0
         // here.
-        self._update(Address::ZERO, token_id, msg::sender())?;
+        self._update(Address::ZERO, token_id, sender)?;
         Ok(())
     }
 }
@@ -14,7 +14,10 @@
 //! Fields `first_consecutive_id` (used to offset first token id) and
 //! `max_batch_size` (used to restrict maximum batch size) can be assigned
 //! during construction with `koba` (stylus construction tooling) within
-//! solidity constructor file.
+//! solidity constructor file, or with
+//! [`Erc721Consecutive::_set_first_consecutive_id`] and
+//! [`Erc721Consecutive::_set_max_batch_size`]. Unless assigned, the first
+//! token id is `0` and batches are limited to [`DEFAULT_MAX_BATCH_SIZE`].
 //!
 //! IMPORTANT: Consecutive mint of [`Erc721Consecutive`] tokens is only allowed
 //! inside the contract's Solidity constructor.
@@ -31,7 +34,7 @@ use alloy_primitives::{uint, Address, FixedBytes, U256};
 use stylus_sdk::{
     abi::Bytes,
     call::MethodError,
-    evm, msg,
+    evm,
     prelude::*,
     stylus_proc::{public, SolidityError},
 };
@@ -50,12 +53,17 @@ use crate::{
             checkpoints,
             checkpoints::{Size, Trace, S160},
         },
+        Context,
     },
 };
 
 type U96 = <S160 as Size>::Key;
 type StorageU96 = <S160 as Size>::KeyStorage;
 
+/// Maximum size of a batch of consecutive tokens, used when no
+/// `max_batch_size` was assigned to the contract.
+pub const DEFAULT_MAX_BATCH_SIZE: U96 = uint!(5000_U96);
+
 pub use sol::*;
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod sol {
@@ -198,14 +206,13 @@ impl IErc721 for Erc721Consecutive {
         token_id: U256,
         data: Bytes,
     ) -> Result<(), Error> {
-        self.transfer_from(from, to, token_id)?;
-        Ok(self.erc721._check_on_erc721_received(
-            msg::sender(),
+        self.safe_transfer_from_with_sender(
+            self._msg_sender(),
             from,
             to,
             token_id,
             &data,
-        )?)
+        )
     }
 
     fn transfer_from(
@@ -214,30 +221,11 @@ impl IErc721 for Erc721Consecutive {
         to: Address,
         token_id: U256,
     ) -> Result<(), Error> {
-        if to.is_zero() {
-            return Err(erc721::Error::InvalidReceiver(
-                ERC721InvalidReceiver { receiver: Address::ZERO },
-            )
-            .into());
-        }
-
-        // Setting an "auth" argument enables the `_is_authorized` check which
-        // verifies that the token exists (`!from.is_zero()`). Therefore, it is
-        // not needed to verify that the return value is not 0 here.
-        let previous_owner = self._update(to, token_id, msg::sender())?;
-        if previous_owner != from {
-            return Err(erc721::Error::IncorrectOwner(ERC721IncorrectOwner {
-                sender: from,
-                token_id,
-                owner: previous_owner,
-            })
-            .into());
-        }
-        Ok(())
+        self.transfer_from_with_sender(self._msg_sender(), from, to, token_id)
     }
 
     fn approve(&mut self, to: Address, token_id: U256) -> Result<(), Error> {
-        self._approve(to, token_id, msg::sender(), true)
+        self._approve(to, token_id, self._msg_sender(), true)
     }
 
     fn set_approval_for_all(
@@ -245,7 +233,11 @@ impl IErc721 for Erc721Consecutive {
         operator: Address,
         approved: bool,
     ) -> Result<(), Error> {
-        Ok(self.erc721.set_approval_for_all(operator, approved)?)
+        Ok(self.erc721._set_approval_for_all(
+            self._msg_sender(),
+            operator,
+            approved,
+        )?)
     }
 
     fn get_approved(&self, token_id: U256) -> Result<Address, Error> {
@@ -258,6 +250,104 @@ impl IErc721 for Erc721Consecutive {
     }
 }
 
+impl Context for Erc721Consecutive {}
+
+impl Erc721Consecutive {
+    /// Same as [`IErc721::safe_transfer_from_with_data`], acting for `sender`
+    /// rather than [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account operating the transfer.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - Token id as a number.
+    /// * `data` - Additional data with no specified format, sent in the call to
+    ///   [`Erc721::_check_on_erc721_received`].
+    ///
+    /// # Errors
+    ///
+    /// * [`erc721::Error::InvalidReceiver`] - If `to` is `Address::ZERO`.
+    /// * [`erc721::Error::InvalidReceiver`] - If
+    ///   [`erc721::IERC721Receiver::on_erc_721_received`] hasn't returned its
+    ///   interface id or returned with an error.
+    /// * [`erc721::Error::IncorrectOwner`] - If the previous owner is not
+    ///   `from`.
+    /// * [`erc721::Error::InsufficientApproval`] - If `sender` does not have
+    ///   the right to approve.
+    /// * [`erc721::Error::NonexistentToken`] - If the token does not exist.
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`].
+    pub fn safe_transfer_from_with_sender(
+        &mut self,
+        sender: Address,
+        from: Address,
+        to: Address,
+        token_id: U256,
+        data: &Bytes,
+    ) -> Result<(), Error> {
+        self.transfer_from_with_sender(sender, from, to, token_id)?;
+        Ok(self
+            .erc721
+            ._check_on_erc721_received(sender, from, to, token_id, data)?)
+    }
+
+    /// Same as [`IErc721::transfer_from`], acting for `sender` rather than
+    /// [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account operating the transfer.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`erc721::Error::InvalidReceiver`] - If `to` is `Address::ZERO`.
+    /// * [`erc721::Error::IncorrectOwner`] - If the previous owner is not
+    ///   `from`.
+    /// * [`erc721::Error::InsufficientApproval`] - If `sender` does not have
+    ///   the right to approve.
+    /// * [`erc721::Error::NonexistentToken`] - If the token does not exist.
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`].
+    pub fn transfer_from_with_sender(
+        &mut self,
+        sender: Address,
+        from: Address,
+        to: Address,
+        token_id: U256,
+    ) -> Result<(), Error> {
+        if to.is_zero() {
+            return Err(erc721::Error::InvalidReceiver(
+                ERC721InvalidReceiver { receiver: Address::ZERO },
+            )
+            .into());
+        }
+
+        // Setting an "auth" argument enables the `_is_authorized` check which
+        // verifies that the token exists (`!from.is_zero()`). Therefore, it is
+        // not needed to verify that the return value is not 0 here.
+        let previous_owner = self._update(to, token_id, sender)?;
+        if previous_owner != from {
+            return Err(erc721::Error::IncorrectOwner(ERC721IncorrectOwner {
+                sender: from,
+                token_id,
+                owner: previous_owner,
+            })
+            .into());
+        }
+        Ok(())
+    }
+}
+
 // ************** Consecutive **************
 
 impl Erc721Consecutive {
@@ -311,7 +401,7 @@ impl Erc721Consecutive {
     ///
     /// * [`erc721::Error::InvalidReceiver`] - If `to` is `Address::ZERO`.
     /// * [`Error::ExceededMaxBatchMint`] - If `batch_size` exceeds
-    ///   `max_batch_size` of the contract.
+    ///   [`Self::_max_batch_size`].
     ///
     /// # Events
     ///
@@ -422,23 +512,56 @@ impl Erc721Consecutive {
     /// Used to offset the first token id in
     /// [`Erc721Consecutive::_next_consecutive_id`].
     ///
+    /// Defaults to `0`.
+    ///
     /// # Arguments
     ///
     /// * `&self` - Read access to the contract's state.
-    fn _first_consecutive_id(&self) -> U96 {
+    pub fn _first_consecutive_id(&self) -> U96 {
         self.first_consecutive_id.get()
     }
 
+    /// Sets the first token id minted by [`Self::_mint_consecutive`].
+    ///
+    /// CAUTION: Must be called before the first consecutive mint, since
+    /// changing the offset afterwards doesn't move the tokens already minted.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `first_consecutive_id` - Id of the first consecutive token.
+    pub fn _set_first_consecutive_id(&mut self, first_consecutive_id: U96) {
+        self.first_consecutive_id.set(first_consecutive_id);
+    }
+
     /// Maximum size of consecutive token's batch.
     /// This is designed to limit stress on off-chain indexing services that
     /// have to record one entry per token, and have protections against
     /// "unreasonably large" batches of tokens.
     ///
+    /// Defaults to [`DEFAULT_MAX_BATCH_SIZE`] if no maximum size was set.
+    ///
     /// # Arguments
     ///
     /// * `&self` - Read access to the contract's state.
-    fn _max_batch_size(&self) -> U96 {
-        self.max_batch_size.get()
+    pub fn _max_batch_size(&self) -> U96 {
+        match self.max_batch_size.get() {
+            U96::ZERO => DEFAULT_MAX_BATCH_SIZE,
+            max_batch_size => max_batch_size,
+        }
+    }
+
+    /// Sets the maximum size of a batch minted by
+    /// [`Self::_mint_consecutive`].
+    ///
+    /// Setting it to `0` restores [`DEFAULT_MAX_BATCH_SIZE`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `max_batch_size` - Maximum number of tokens minted in a batch.
+    pub fn _set_max_batch_size(&mut self, max_batch_size: U96) {
+        self.max_batch_size.set(max_batch_size);
     }
 }
 
@@ -557,7 +680,7 @@ impl Erc721Consecutive {
     ) -> Result<(), Error> {
         self._mint(to, token_id)?;
         Ok(self.erc721._check_on_erc721_received(
-            msg::sender(),
+            self._msg_sender(),
             Address::ZERO,
             to,
             token_id,
@@ -688,7 +811,7 @@ impl Erc721Consecutive {
     ) -> Result<(), Error> {
         self._transfer(from, to, token_id)?;
         Ok(self.erc721._check_on_erc721_received(
-            msg::sender(),
+            self._msg_sender(),
             from,
             to,
             token_id,
@@ -785,14 +908,15 @@ impl IErc165 for Erc721Consecutive {
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use alloy_primitives::{uint, Address, U256};
-    use motsu::prelude::Contract;
+    use motsu::prelude::*;
 
     use crate::{
         token::{
             erc721,
             erc721::{
                 extensions::consecutive::{
-                    ERC721ExceededMaxBatchMint, Erc721Consecutive, Error, U96,
+                    ERC721ExceededMaxBatchMint, Erc721Consecutive, Error,
+                    DEFAULT_MAX_BATCH_SIZE, U96,
                 },
                 ERC721IncorrectOwner, ERC721InvalidApprover,
                 ERC721InvalidReceiver, ERC721InvalidSender,
@@ -939,6 +1063,92 @@ mod tests {
         ));
     }
 
+    #[motsu::test]
+    fn max_batch_size_defaults(
+        contract: Contract<Erc721Consecutive>,
+        alice: Address,
+    ) {
+        assert_eq!(
+            DEFAULT_MAX_BATCH_SIZE,
+            contract.sender(alice)._max_batch_size()
+        );
+
+        contract.sender(alice)._set_max_batch_size(uint!(10_U96));
+        assert_eq!(uint!(10_U96), contract.sender(alice)._max_batch_size());
+
+        contract.sender(alice)._set_max_batch_size(U96::ZERO);
+        assert_eq!(
+            DEFAULT_MAX_BATCH_SIZE,
+            contract.sender(alice)._max_batch_size()
+        );
+    }
+
+    #[motsu::test]
+    fn mints_batch_at_max_batch_size(
+        contract: Contract<Erc721Consecutive>,
+        alice: Address,
+    ) {
+        let max_batch_size = uint!(3_U96);
+        contract.sender(alice)._set_max_batch_size(max_batch_size);
+
+        contract
+            .sender(alice)
+            ._mint_consecutive(alice, max_batch_size)
+            .expect("should mint a batch at the limit");
+        assert_eq!(
+            U256::from(max_batch_size),
+            contract.sender(alice).balance_of(alice).motsu_unwrap()
+        );
+
+        let err = contract
+            .sender(alice)
+            ._mint_consecutive(alice, max_batch_size + uint!(1_U96))
+            .expect_err("should not mint a batch over the limit");
+        assert!(matches!(
+            err,
+            Error::ExceededMaxBatchMint(ERC721ExceededMaxBatchMint {
+                batch_size,
+                max_batch
+            }) if batch_size == uint!(4_U256) && max_batch == uint!(3_U256)
+        ));
+    }
+
+    #[motsu::test]
+    fn mints_from_first_consecutive_id(
+        contract: Contract<Erc721Consecutive>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let first_consecutive_id = uint!(100_U96);
+        contract.sender(alice)._set_first_consecutive_id(first_consecutive_id);
+        assert_eq!(
+            first_consecutive_id,
+            contract.sender(alice)._first_consecutive_id()
+        );
+
+        let first = contract
+            .sender(alice)
+            ._mint_consecutive(alice, uint!(2_U96))
+            .expect("should mint consecutively");
+        let second = contract
+            .sender(alice)
+            ._mint_consecutive(bob, uint!(1_U96))
+            .expect("should mint consecutively");
+        assert_eq!(first_consecutive_id, first);
+        assert_eq!(uint!(102_U96), second);
+
+        let owner_of = |token_id: u64| {
+            contract.sender(alice).owner_of(U256::from(token_id))
+        };
+        assert_eq!(alice, owner_of(100).motsu_unwrap());
+        assert_eq!(alice, owner_of(101).motsu_unwrap());
+        assert_eq!(bob, owner_of(102).motsu_unwrap());
+        assert!(matches!(
+            owner_of(99).motsu_unwrap_err(),
+            Error::Erc721(erc721::Error::NonexistentToken(_))
+        ));
+    }
+
     #[motsu::test]
     fn transfers_from(
         contract: Contract<Erc721Consecutive>,
@@ -173,6 +173,55 @@ impl IErc165 for Erc721Enumerable {
 }
 
 impl Erc721Enumerable {
+    /// Returns up to `limit` token IDs owned by `owner`, starting at a given
+    /// `start` index of its token list.
+    ///
+    /// Retrieves a page of `owner`'s tokens in a single call, rather than
+    /// calling [`IErc721Enumerable::token_of_owner_by_index`] for each of
+    /// them. The page is shorter than `limit` when it reaches the end of
+    /// `owner`'s token list.
+    ///
+    /// NOTE: This function is not part of the ERC-721 standard. To expose it
+    /// in your contract's ABI, forward the call to your internal storage
+    /// instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `owner` - Address of tokens' owner.
+    /// * `start` - Index of the first token at `owner`'s tokens list.
+    /// * `limit` - Maximum number of tokens to return.
+    /// * `erc721` - Read access to a contract providing [`IErc721`] interface.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::OutOfBoundsIndex`] - If `start` is out of bounds of `owner`'s
+    ///   tokens list.
+    pub fn tokens_of_owner(
+        &self,
+        owner: Address,
+        start: U256,
+        limit: U256,
+        erc721: &impl IErc721<Error = erc721::Error>,
+    ) -> Result<Vec<U256>, Error> {
+        // `Address::ZERO` owns no tokens, so its list is empty.
+        let balance = erc721.balance_of(owner).unwrap_or_default();
+        if start >= balance {
+            return Err(ERC721OutOfBoundsIndex { owner, index: start }.into());
+        }
+
+        let end = start.saturating_add(limit).min(balance);
+        let owned_tokens = self.owned_tokens.getter(owner);
+        let mut tokens = Vec::new();
+        let mut index = start;
+        while index < end {
+            tokens.push(owned_tokens.get(index));
+            index += uint!(1_U256);
+        }
+
+        Ok(tokens)
+    }
+
     /// Function to add a token to this extension's
     /// ownership-tracking data structures.
     ///
@@ -647,6 +696,138 @@ mod tests {
         ));
     }
 
+    fn mint_enumerable(
+        contract: &Contract<Erc721EnumerableTestExample>,
+        to: Address,
+        token_ids: &[U256],
+    ) {
+        contract.init(to, |contract| {
+            for &token_id in token_ids {
+                contract
+                    .erc721
+                    ._mint(to, token_id)
+                    .expect("should mint a token for {{to}}");
+                contract
+                    .enumerable
+                    ._add_token_to_owner_enumeration(
+                        to,
+                        token_id,
+                        &contract.erc721,
+                    )
+                    .expect("should add token to owner enumeration");
+            }
+        });
+    }
+
+    #[motsu::test]
+    fn tokens_of_owner_paginates(
+        contract: Contract<Erc721EnumerableTestExample>,
+        alice: Address,
+    ) {
+        let tokens_ids: Vec<U256> = (1..=10).map(U256::from).collect();
+        mint_enumerable(&contract, alice, &tokens_ids);
+
+        let limit = uint!(3_U256);
+        let mut pages = Vec::new();
+        let mut start = U256::ZERO;
+        while start < U256::from(tokens_ids.len()) {
+            let page = contract
+                .sender(alice)
+                .enumerable
+                .tokens_of_owner(
+                    alice,
+                    start,
+                    limit,
+                    &contract.sender(alice).erc721,
+                )
+                .expect("should return a page of `alice`'s tokens");
+            start += U256::from(page.len());
+            pages.push(page);
+        }
+
+        let pages_lens: Vec<usize> = pages.iter().map(Vec::len).collect();
+        assert_eq!(vec![3, 3, 3, 1], pages_lens);
+        assert_eq!(tokens_ids, pages.concat());
+    }
+
+    #[motsu::test]
+    fn tokens_of_owner_includes_token_zero(
+        contract: Contract<Erc721EnumerableTestExample>,
+        alice: Address,
+    ) {
+        let tokens_ids = vec![uint!(5_U256), U256::ZERO, uint!(7_U256)];
+        mint_enumerable(&contract, alice, &tokens_ids);
+
+        let tokens = contract
+            .sender(alice)
+            .enumerable
+            .tokens_of_owner(
+                alice,
+                U256::ZERO,
+                uint!(10_U256),
+                &contract.sender(alice).erc721,
+            )
+            .expect("should return all of `alice`'s tokens");
+        assert_eq!(tokens_ids, tokens);
+
+        let tokens = contract
+            .sender(alice)
+            .enumerable
+            .tokens_of_owner(
+                alice,
+                uint!(1_U256),
+                uint!(1_U256),
+                &contract.sender(alice).erc721,
+            )
+            .expect("should return a page starting at token zero");
+        assert_eq!(vec![U256::ZERO], tokens);
+    }
+
+    #[motsu::test]
+    fn reverts_when_tokens_of_owner_start_out_of_bound(
+        contract: Contract<Erc721EnumerableTestExample>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let tokens_ids: Vec<U256> = (1..=10).map(U256::from).collect();
+        mint_enumerable(&contract, alice, &tokens_ids);
+
+        let start = uint!(10_U256);
+        let err = contract
+            .sender(alice)
+            .enumerable
+            .tokens_of_owner(
+                alice,
+                start,
+                uint!(3_U256),
+                &contract.sender(alice).erc721,
+            )
+            .expect_err("should return Error::OutOfBoundsIndex");
+
+        assert!(matches!(err, Error::OutOfBoundsIndex(ERC721OutOfBoundsIndex {
+                owner,
+                index
+            }) if owner == alice && index == start
+        ));
+
+        let err = contract
+            .sender(alice)
+            .enumerable
+            .tokens_of_owner(
+                bob,
+                U256::ZERO,
+                uint!(3_U256),
+                &contract.sender(alice).erc721,
+            )
+            .expect_err("should return Error::OutOfBoundsIndex");
+
+        assert!(matches!(err, Error::OutOfBoundsIndex(ERC721OutOfBoundsIndex {
+                owner,
+                index
+            }) if owner == bob && index.is_zero()
+        ));
+    }
+
     #[motsu::test]
     fn token_of_owner_by_index_after_transfer_works(
         contract: Contract<Erc721EnumerableTestExample>,
@@ -87,8 +87,26 @@ impl Erc721Metadata {
         self.base_uri.get_string()
     }
 
+    /// Sets `base_uri` as the base of Uniform Resource Identifier (URI) for
+    /// all tokens.
+    ///
+    /// Internal function without access restriction.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `base_uri` - New base URI.
+    pub fn _set_base_uri(&mut self, base_uri: String) {
+        self.base_uri.set_str(base_uri);
+    }
+
     /// Returns the Uniform Resource Identifier (URI) for `token_id` token.
     ///
+    /// The URI is the base URI followed by the decimal `token_id`, or an
+    /// empty string if no base URI is set. No separator is added or removed,
+    /// so a base URI meant to be joined with a `/` should end with it, e.g.
+    /// `ipfs://<cid>/`.
+    ///
     /// NOTE: To expose this function in your contract's ABI, implement it as
     /// shown in the Examples section below, accepting only the `token_id`
     /// parameter. The `erc721` reference should come from your contract's
@@ -136,7 +154,102 @@ impl Erc721Metadata {
 
 #[cfg(all(test, feature = "std"))]
 mod tests {
+    use alloc::string::String;
+
+    use alloy_primitives::{uint, Address, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::prelude::*;
+
     use super::{Erc721Metadata, IErc165, IErc721Metadata};
+    use crate::token::erc721::{self, ERC721NonexistentToken, Erc721};
+
+    const TOKEN_ID: U256 = uint!(42_U256);
+
+    #[storage]
+    struct Erc721MetadataExample {
+        erc721: Erc721,
+        metadata: Erc721Metadata,
+    }
+
+    #[public]
+    impl Erc721MetadataExample {
+        #[selector(name = "tokenURI")]
+        fn token_uri(&self, token_id: U256) -> Result<String, erc721::Error> {
+            self.metadata.token_uri(token_id, &self.erc721)
+        }
+    }
+
+    unsafe impl TopLevelStorage for Erc721MetadataExample {}
+
+    fn init(contract: &mut Erc721MetadataExample, owner: Address, base: &str) {
+        contract.metadata._set_base_uri(String::from(base));
+        contract.erc721._mint(owner, TOKEN_ID).motsu_unwrap();
+    }
+
+    #[motsu::test]
+    fn token_uri_is_empty_without_base_uri(
+        contract: Contract<Erc721MetadataExample>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| init(contract, alice, ""));
+
+        let token_uri =
+            contract.sender(alice).token_uri(TOKEN_ID).motsu_unwrap();
+        assert_eq!("", token_uri);
+    }
+
+    #[motsu::test]
+    fn token_uri_appends_id_to_base_uri_without_slash(
+        contract: Contract<Erc721MetadataExample>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            init(contract, alice, "https://example.com/token-");
+        });
+
+        let token_uri =
+            contract.sender(alice).token_uri(TOKEN_ID).motsu_unwrap();
+        assert_eq!("https://example.com/token-42", token_uri);
+    }
+
+    #[motsu::test]
+    fn token_uri_appends_id_to_base_uri_with_slash(
+        contract: Contract<Erc721MetadataExample>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            init(contract, alice, "https://example.com/");
+        });
+
+        assert_eq!(
+            "https://example.com/",
+            contract.sender(alice).metadata.base_uri()
+        );
+        let token_uri =
+            contract.sender(alice).token_uri(TOKEN_ID).motsu_unwrap();
+        assert_eq!("https://example.com/42", token_uri);
+    }
+
+    #[motsu::test]
+    fn token_uri_errors_for_nonexistent_token(
+        contract: Contract<Erc721MetadataExample>,
+        alice: Address,
+    ) {
+        contract.init(alice, |contract| {
+            init(contract, alice, "https://example.com/");
+        });
+
+        let err = contract
+            .sender(alice)
+            .token_uri(TOKEN_ID + uint!(1_U256))
+            .motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            erc721::Error::NonexistentToken(ERC721NonexistentToken {
+                token_id
+            }) if token_id == TOKEN_ID + uint!(1_U256)
+        ));
+    }
 
     #[motsu::test]
     fn interface_id() {
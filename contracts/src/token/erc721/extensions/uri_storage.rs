@@ -125,7 +125,9 @@ mod tests {
     use stylus_sdk::prelude::*;
 
     use super::Erc721UriStorage;
-    use crate::token::erc721::{self, extensions::Erc721Metadata, Erc721};
+    use crate::token::erc721::{
+        self, extensions::Erc721Metadata, ERC721NonexistentToken, Erc721,
+    };
 
     const TOKEN_ID: U256 = uint!(1_U256);
 
@@ -173,4 +175,52 @@ mod tests {
                 .expect("should return token URI")
         );
     }
+
+    #[motsu::test]
+    fn token_uri_errors_for_never_minted_token(
+        contract: Contract<Erc721MetadataExample>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .token_uri(TOKEN_ID)
+            .expect_err("should return Error::NonexistentToken");
+
+        assert!(matches!(
+            err,
+            erc721::Error::NonexistentToken(ERC721NonexistentToken {
+                token_id
+            }) if token_id == TOKEN_ID
+        ));
+    }
+
+    #[motsu::test]
+    fn token_uri_errors_for_burned_token(
+        contract: Contract<Erc721MetadataExample>,
+        alice: Address,
+    ) {
+        contract
+            .sender(alice)
+            .erc721
+            ._mint(alice, TOKEN_ID)
+            .expect("should mint a token for Alice");
+        contract.sender(alice).set_token_uri(TOKEN_ID, String::from("uri"));
+        contract
+            .sender(alice)
+            .erc721
+            ._burn(TOKEN_ID)
+            .expect("should burn a token of Alice");
+
+        let err = contract
+            .sender(alice)
+            .token_uri(TOKEN_ID)
+            .expect_err("should return Error::NonexistentToken");
+
+        assert!(matches!(
+            err,
+            erc721::Error::NonexistentToken(ERC721NonexistentToken {
+                token_id
+            }) if token_id == TOKEN_ID
+        ));
+    }
 }
@@ -6,7 +6,7 @@ use openzeppelin_stylus_proc::interface_id;
 use stylus_sdk::{
     abi::Bytes,
     call::{self, Call, MethodError},
-    evm, function_selector, msg,
+    evm, function_selector,
     prelude::*,
     storage::{StorageAddress, StorageBool, StorageMap, StorageU256},
 };
@@ -14,6 +14,7 @@ use stylus_sdk::{
 use crate::utils::{
     introspection::erc165::{Erc165, IErc165},
     math::storage::{AddAssignUnchecked, SubAssignUnchecked},
+    Context,
 };
 
 pub mod extensions;
@@ -235,6 +236,8 @@ pub trait IErc721: IErc165 {
     /// that contract recipients are aware of the [`Erc721`] protocol to
     /// prevent tokens from being forever locked.
     ///
+    /// Same as [`IErc721::safe_transfer_from_with_data`] with empty `data`.
+    ///
     /// # Arguments
     ///
     /// * `&mut self` - Write access to the contract's state.
@@ -264,6 +267,9 @@ pub trait IErc721: IErc165 {
 
     /// Safely transfers `token_id` token from `from` to `to`.
     ///
+    /// `data` is passed as is to [`IERC721Receiver::on_erc_721_received`] of
+    /// contract recipients. Both forms are exposed as `safeTransferFrom`.
+    ///
     /// # Arguments
     ///
     /// * `&mut self` - Write access to the contract's state.
@@ -334,6 +340,10 @@ pub trait IErc721: IErc165 {
     /// Only a single account can be approved at a time,
     /// so approving the `Address::ZERO` clears previous approvals.
     ///
+    /// The caller must own the token or be an approved operator of its owner.
+    /// As in the reference implementation, approving the current owner does
+    /// not revert.
+    ///
     /// # Arguments
     ///
     /// * `&mut self` - Write access to the contract's state.
@@ -343,8 +353,8 @@ pub trait IErc721: IErc165 {
     /// # Errors
     ///
     /// * [`Error::NonexistentToken`] - If the token does not exist.
-    /// * [`Error::InvalidApprover`] - If `auth` (param of [`Erc721::_approve`])
-    ///   does not have a right to approve this token.
+    /// * [`Error::InvalidApprover`] - If [`Context::_msg_sender`] is neither
+    ///   the owner of the token nor an approved operator of the owner.
     ///
     /// # Events
     ///
@@ -366,7 +376,7 @@ pub trait IErc721: IErc165 {
     /// * `operator` - Account to add to the set of authorized operators.
     /// * `approved` - Flag that determines whether or not permission will be
     ///   granted to `operator`. If true, this means `operator` will be allowed
-    ///   to manage `msg::sender`'s assets.
+    ///   to manage the assets of [`Context::_msg_sender`].
     ///
     /// # Errors
     ///
@@ -436,8 +446,13 @@ impl IErc721 for Erc721 {
         token_id: U256,
         data: Bytes,
     ) -> Result<(), Error> {
-        self.transfer_from(from, to, token_id)?;
-        self._check_on_erc721_received(msg::sender(), from, to, token_id, &data)
+        self.safe_transfer_from_with_sender(
+            self._msg_sender(),
+            from,
+            to,
+            token_id,
+            &data,
+        )
     }
 
     fn transfer_from(
@@ -446,29 +461,11 @@ impl IErc721 for Erc721 {
         to: Address,
         token_id: U256,
     ) -> Result<(), Error> {
-        if to.is_zero() {
-            return Err(
-                ERC721InvalidReceiver { receiver: Address::ZERO }.into()
-            );
-        }
-
-        // Setting an "auth" argument enables the `_is_authorized` check which
-        // verifies that the token exists (`from != 0`). Therefore, it is
-        // not needed to verify that the return value is not 0 here.
-        let previous_owner = self._update(to, token_id, msg::sender())?;
-        if previous_owner != from {
-            return Err(ERC721IncorrectOwner {
-                sender: from,
-                token_id,
-                owner: previous_owner,
-            }
-            .into());
-        }
-        Ok(())
+        self.transfer_from_with_sender(self._msg_sender(), from, to, token_id)
     }
 
     fn approve(&mut self, to: Address, token_id: U256) -> Result<(), Error> {
-        self._approve(to, token_id, msg::sender(), true)
+        self._approve(to, token_id, self._msg_sender(), true)
     }
 
     fn set_approval_for_all(
@@ -476,7 +473,7 @@ impl IErc721 for Erc721 {
         operator: Address,
         approved: bool,
     ) -> Result<(), Error> {
-        self._set_approval_for_all(msg::sender(), operator, approved)
+        self._set_approval_for_all(self._msg_sender(), operator, approved)
     }
 
     fn get_approved(&self, token_id: U256) -> Result<Address, Error> {
@@ -489,6 +486,8 @@ impl IErc721 for Erc721 {
     }
 }
 
+impl Context for Erc721 {}
+
 impl IErc165 for Erc721 {
     fn supports_interface(interface_id: FixedBytes<4>) -> bool {
         <Self as IErc721>::INTERFACE_ID == u32::from_be_bytes(*interface_id)
@@ -497,6 +496,98 @@ impl IErc165 for Erc721 {
 }
 
 impl Erc721 {
+    /// Same as [`IErc721::safe_transfer_from_with_data`], acting for `sender`
+    /// rather than [`Context::_msg_sender`].
+    ///
+    /// A contract overriding [`Context`] forwards its own `safeTransferFrom`
+    /// functions here with the resolved sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account operating the transfer.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - Token id as a number.
+    /// * `data` - Additional data with no specified format, sent in the call to
+    ///   [`Erc721::_check_on_erc721_received`].
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If `to` is `Address::ZERO`.
+    /// * [`Error::InvalidReceiver`] - If
+    ///   [`IERC721Receiver::on_erc_721_received`] hasn't returned its interface
+    ///   id or returned with an error.
+    /// * [`Error::IncorrectOwner`] - If the previous owner is not `from`.
+    /// * [`Error::InsufficientApproval`] - If `sender` does not have the right
+    ///   to approve.
+    /// * [`Error::NonexistentToken`] - If the token does not exist.
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`].
+    pub fn safe_transfer_from_with_sender(
+        &mut self,
+        sender: Address,
+        from: Address,
+        to: Address,
+        token_id: U256,
+        data: &Bytes,
+    ) -> Result<(), Error> {
+        self.transfer_from_with_sender(sender, from, to, token_id)?;
+        self._check_on_erc721_received(sender, from, to, token_id, data)
+    }
+
+    /// Same as [`IErc721::transfer_from`], acting for `sender` rather than
+    /// [`Context::_msg_sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `sender` - Account operating the transfer.
+    /// * `from` - Account of the sender.
+    /// * `to` - Account of the recipient.
+    /// * `token_id` - Token id as a number.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidReceiver`] - If `to` is `Address::ZERO`.
+    /// * [`Error::IncorrectOwner`] - If the previous owner is not `from`.
+    /// * [`Error::InsufficientApproval`] - If `sender` does not have the right
+    ///   to approve.
+    /// * [`Error::NonexistentToken`] - If the token does not exist.
+    ///
+    /// # Events
+    ///
+    /// * [`Transfer`].
+    pub fn transfer_from_with_sender(
+        &mut self,
+        sender: Address,
+        from: Address,
+        to: Address,
+        token_id: U256,
+    ) -> Result<(), Error> {
+        if to.is_zero() {
+            return Err(
+                ERC721InvalidReceiver { receiver: Address::ZERO }.into()
+            );
+        }
+
+        // Setting an "auth" argument enables the `_is_authorized` check which
+        // verifies that the token exists (`from != 0`). Therefore, it is
+        // not needed to verify that the return value is not 0 here.
+        let previous_owner = self._update(to, token_id, sender)?;
+        if previous_owner != from {
+            return Err(ERC721IncorrectOwner {
+                sender: from,
+                token_id,
+                owner: previous_owner,
+            }
+            .into());
+        }
+        Ok(())
+    }
+
     /// Returns the owner of the `token_id`. Does NOT revert if the token
     /// doesn't exist.
     ///
@@ -736,7 +827,7 @@ impl Erc721 {
     ) -> Result<(), Error> {
         self._mint(to, token_id)?;
         self._check_on_erc721_received(
-            msg::sender(),
+            self._msg_sender(),
             Address::ZERO,
             to,
             token_id,
@@ -856,7 +947,13 @@ impl Erc721 {
         data: &Bytes,
     ) -> Result<(), Error> {
         self._transfer(from, to, token_id)?;
-        self._check_on_erc721_received(msg::sender(), from, to, token_id, data)
+        self._check_on_erc721_received(
+            self._msg_sender(),
+            from,
+            to,
+            token_id,
+            data,
+        )
     }
 
     /// Approve `to` to operate on `token_id`.
@@ -1041,10 +1138,10 @@ mod tests {
     use stylus_sdk::{abi::Bytes, prelude::*};
 
     use super::{
-        ERC721IncorrectOwner, ERC721InsufficientApproval,
-        ERC721InvalidApprover, ERC721InvalidOperator, ERC721InvalidOwner,
-        ERC721InvalidReceiver, ERC721InvalidSender, ERC721NonexistentToken,
-        Erc721, Error, IErc721,
+        Approval, ApprovalForAll, ERC721IncorrectOwner,
+        ERC721InsufficientApproval, ERC721InvalidApprover,
+        ERC721InvalidOperator, ERC721InvalidOwner, ERC721InvalidReceiver,
+        ERC721InvalidSender, ERC721NonexistentToken, Erc721, Error, IErc721,
     };
     use crate::utils::introspection::erc165::IErc165;
 
@@ -1389,6 +1486,51 @@ mod tests {
         ));
     }
 
+    #[motsu::test]
+    fn error_when_previous_approvee_transfers_after_transfer(
+        contract: Contract<Erc721>,
+        alice: Address,
+        bob: Address,
+        charlie: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(bob, TOKEN_ID)
+            .expect("should mint token to Bob");
+        contract
+            .sender(bob)
+            .approve(alice, TOKEN_ID)
+            .expect("should approve Bob's token for Alice");
+        contract
+            .sender(bob)
+            .transfer_from(bob, charlie, TOKEN_ID)
+            .expect("should transfer Bob's token to Charlie");
+
+        // The approval is cleared without emitting `Approval`.
+        let approved = contract
+            .sender(alice)
+            .get_approved(TOKEN_ID)
+            .expect("should return the approved account");
+        assert_eq!(approved, Address::ZERO);
+        assert!(!contract.emitted(&Approval {
+            owner: bob,
+            approved: Address::ZERO,
+            token_id: TOKEN_ID,
+        }));
+
+        let err = contract
+            .sender(alice)
+            .transfer_from(charlie, alice, TOKEN_ID)
+            .expect_err("should not transfer with a stale approval");
+        assert!(matches!(
+            err,
+            Error::InsufficientApproval(ERC721InsufficientApproval {
+                    operator,
+                    token_id: t_id,
+            }) if operator == alice && t_id == TOKEN_ID
+        ));
+    }
+
     #[motsu::test]
     fn error_when_transfer_from_transfers_nonexistent_token(
         contract: Contract<Erc721>,
@@ -1855,6 +1997,91 @@ mod tests {
         ));
     }
 
+    #[motsu::test]
+    fn error_when_approve_by_previous_owner(
+        contract: Contract<Erc721>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID)
+            .expect("should mint a token");
+        contract
+            .sender(alice)
+            .transfer_from(alice, bob, TOKEN_ID)
+            .expect("should transfer a token from Alice to Bob");
+
+        let err = contract
+            .sender(alice)
+            .approve(alice, TOKEN_ID)
+            .expect_err("should not approve when not the owner anymore");
+
+        assert!(matches!(
+            err,
+            Error::InvalidApprover(ERC721InvalidApprover {
+                approver
+            }) if approver == alice
+        ));
+    }
+
+    #[motsu::test]
+    fn approves_token_by_operator(
+        contract: Contract<Erc721>,
+        alice: Address,
+        bob: Address,
+        dave: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID)
+            .expect("should mint a token");
+        contract
+            .sender(alice)
+            .set_approval_for_all(bob, true)
+            .expect("should approve Bob for operations on all Alice's tokens");
+        contract.assert_emitted(&ApprovalForAll {
+            owner: alice,
+            operator: bob,
+            approved: true,
+        });
+
+        contract
+            .sender(bob)
+            .approve(dave, TOKEN_ID)
+            .expect("should approve Dave as Alice's operator");
+
+        contract.assert_emitted(&Approval {
+            owner: alice,
+            approved: dave,
+            token_id: TOKEN_ID,
+        });
+        let approved = contract
+            .sender(alice)
+            .get_approved(TOKEN_ID)
+            .expect("should return approved account");
+        assert_eq!(dave, approved);
+    }
+
+    #[motsu::test]
+    fn approves_current_owner(contract: Contract<Erc721>, alice: Address) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID)
+            .expect("should mint a token");
+
+        contract
+            .sender(alice)
+            .approve(alice, TOKEN_ID)
+            .expect("should approve the current owner");
+
+        let approved = contract
+            .sender(alice)
+            .get_approved(TOKEN_ID)
+            .expect("should return approved account");
+        assert_eq!(alice, approved);
+    }
+
     #[motsu::test]
     fn error_when_approval_for_all_for_invalid_operator(
         contract: Contract<Erc721>,
@@ -2665,6 +2892,61 @@ mod tests {
         ));
     }
 
+    #[motsu::test]
+    fn token_queries_and_transfers_require_owned_token(
+        contract: Contract<Erc721>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract
+            .sender(alice)
+            ._mint(alice, TOKEN_ID)
+            .expect("should mint a token for Alice");
+
+        assert_eq!(alice, contract.sender(alice).owner_of(TOKEN_ID).unwrap());
+        assert_eq!(
+            Address::ZERO,
+            contract.sender(alice).get_approved(TOKEN_ID).unwrap()
+        );
+
+        contract
+            .sender(alice)
+            ._burn(TOKEN_ID)
+            .expect("should burn Alice's token");
+
+        let is_nonexistent = |err: Error| {
+            matches!(
+                err,
+                Error::NonexistentToken(ERC721NonexistentToken { token_id })
+                    if token_id == TOKEN_ID
+            )
+        };
+        assert!(is_nonexistent(
+            contract.sender(alice).owner_of(TOKEN_ID).unwrap_err()
+        ));
+        assert!(is_nonexistent(
+            contract.sender(alice).get_approved(TOKEN_ID).unwrap_err()
+        ));
+        assert!(is_nonexistent(
+            contract.sender(alice).approve(bob, TOKEN_ID).unwrap_err()
+        ));
+        assert!(is_nonexistent(
+            contract
+                .sender(alice)
+                .transfer_from(alice, bob, TOKEN_ID)
+                .unwrap_err()
+        ));
+        assert!(is_nonexistent(
+            contract
+                .sender(alice)
+                .safe_transfer_from(alice, bob, TOKEN_ID)
+                .unwrap_err()
+        ));
+        assert!(is_nonexistent(
+            contract.sender(alice)._burn(TOKEN_ID).unwrap_err()
+        ));
+    }
+
     #[motsu::test]
     fn interface_id() {
         let actual = <Erc721 as IErc721>::INTERFACE_ID;
@@ -2688,6 +2970,7 @@ mod tests {
     sol_storage! {
         pub struct Erc721ReceiverMock {
             uint256 _received_token_id;
+            bytes _received_data;
         }
     }
 
@@ -2699,15 +2982,20 @@ mod tests {
             _operator: Address,
             _from: Address,
             token_id: U256,
-            _data: Bytes,
+            data: Bytes,
         ) -> FixedBytes<4> {
             self._received_token_id.set(token_id);
+            self._received_data.set_bytes(data);
             fixed_bytes!("150b7a02")
         }
 
         fn received_token_id(&self) -> U256 {
             self._received_token_id.get()
         }
+
+        fn received_data(&self) -> Bytes {
+            self._received_data.get_bytes().into()
+        }
     }
 
     unsafe impl TopLevelStorage for Erc721ReceiverMock {}
@@ -2727,4 +3015,50 @@ mod tests {
 
         assert_eq!(received_token_id, TOKEN_ID);
     }
+
+    #[motsu::test]
+    fn safe_transfer_from_with_data_forwards_data(
+        erc721: Contract<Erc721>,
+        receiver: Contract<Erc721ReceiverMock>,
+        alice: Address,
+    ) {
+        erc721.sender(alice)._mint(alice, TOKEN_ID).motsu_unwrap();
+        let data: Bytes = vec![0xde, 0xad, 0xbe, 0xef].into();
+
+        erc721
+            .sender(alice)
+            .safe_transfer_from_with_data(
+                alice,
+                receiver.address(),
+                TOKEN_ID,
+                data.clone(),
+            )
+            .motsu_unwrap();
+
+        assert_eq!(TOKEN_ID, receiver.sender(alice).received_token_id());
+        assert_eq!(data, receiver.sender(alice).received_data());
+        assert_eq!(
+            receiver.address(),
+            erc721.sender(alice).owner_of(TOKEN_ID).motsu_unwrap()
+        );
+    }
+
+    #[motsu::test]
+    fn safe_transfer_from_forwards_empty_data(
+        erc721: Contract<Erc721>,
+        receiver: Contract<Erc721ReceiverMock>,
+        alice: Address,
+    ) {
+        erc721.sender(alice)._mint(alice, TOKEN_ID).motsu_unwrap();
+        // Leftover data from a previous transfer is overwritten.
+        receiver.sender(alice)._received_data.set_bytes([1, 2, 3]);
+
+        erc721
+            .sender(alice)
+            .safe_transfer_from(alice, receiver.address(), TOKEN_ID)
+            .motsu_unwrap();
+
+        assert_eq!(TOKEN_ID, receiver.sender(alice).received_token_id());
+        assert!(receiver.sender(alice).received_data().is_empty());
+    }
 }
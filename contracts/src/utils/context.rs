@@ -0,0 +1,241 @@
+//! Information about the current execution context.
+//!
+//! Contracts read the caller through [`Context::_msg_sender`] rather than
+//! [`msg::sender`], so that the account a call is attributed to is resolved in
+//! a single place.
+//!
+//! A contract supporting meta-transactions, e.g. through an [ERC-2771]
+//! forwarder, overrides [`Context`] and routes calls through the
+//! `*_with_sender` functions of the contracts it embeds, which take the caller
+//! explicitly:
+//!
+//! ```rust,ignore
+//! impl Context for MyToken {
+//!     fn _msg_sender(&self) -> Address {
+//!         // Resolve the original signer of a forwarded call.
+//!     }
+//! }
+//!
+//! #[public]
+//! impl MyToken {
+//!     fn transfer(&mut self, to: Address, value: U256) -> Result<bool, Error> {
+//!         self.erc20.transfer_with_sender(self._msg_sender(), to, value)
+//!     }
+//! }
+//! ```
+//!
+//! [ERC-2771]: https://eips.ethereum.org/EIPS/eip-2771
+use alloy_primitives::{Address, U256};
+use stylus_sdk::msg;
+
+/// Provides the sender of the current call and the value sent along with it.
+///
+/// NOTE: There is no `_msg_data` counterpart, since the length of the
+/// calldata is only known to the contract's entrypoint.
+pub trait Context {
+    /// Returns the account the current call is attributed to.
+    ///
+    /// Defaults to [`msg::sender`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn _msg_sender(&self) -> Address {
+        msg::sender()
+    }
+
+    /// Returns the amount of ETH sent along with the current call.
+    ///
+    /// Defaults to [`msg::value`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn _msg_value(&self) -> U256 {
+        msg::value()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{uint, Address, B256, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::{msg, prelude::*, storage::StorageAddress};
+
+    use super::Context;
+    use crate::{
+        access::control::{self, AccessControl, IAccessControl},
+        token::erc20::{self, Erc20, IErc20},
+    };
+
+    const MINTER_ROLE: [u8; 32] = AccessControl::role_id("MINTER_ROLE");
+
+    /// Token attributing calls made by a trusted `forwarder` to `signer`.
+    #[storage]
+    struct ForwardedToken {
+        erc20: Erc20,
+        access_control: AccessControl,
+        forwarder: StorageAddress,
+        signer: StorageAddress,
+    }
+
+    unsafe impl TopLevelStorage for ForwardedToken {}
+
+    impl Context for ForwardedToken {
+        fn _msg_sender(&self) -> Address {
+            if msg::sender() == self.forwarder.get() {
+                self.signer.get()
+            } else {
+                msg::sender()
+            }
+        }
+    }
+
+    #[public]
+    impl ForwardedToken {
+        fn transfer(
+            &mut self,
+            to: Address,
+            value: U256,
+        ) -> Result<bool, erc20::Error> {
+            self.erc20.transfer_with_sender(self._msg_sender(), to, value)
+        }
+
+        fn burn_from(
+            &mut self,
+            account: Address,
+            value: U256,
+        ) -> Result<(), erc20::Error> {
+            self.erc20.burn_from_with_sender(self._msg_sender(), account, value)
+        }
+
+        fn grant_role(
+            &mut self,
+            role: B256,
+            account: Address,
+        ) -> Result<(), control::Error> {
+            self.access_control.grant_role_with_sender(
+                self._msg_sender(),
+                role,
+                account,
+            )
+        }
+    }
+
+    #[motsu::test]
+    fn default_context_is_msg_sender(
+        contract: Contract<Erc20>,
+        alice: Address,
+    ) {
+        assert_eq!(alice, contract.sender(alice)._msg_sender());
+        assert_eq!(U256::ZERO, contract.sender(alice)._msg_value());
+    }
+
+    #[motsu::test]
+    fn overridden_context_attributes_transfer(
+        contract: Contract<ForwardedToken>,
+        alice: Address,
+        bob: Address,
+        forwarder: Address,
+    ) {
+        let value = uint!(10_U256);
+        contract.init(alice, |contract| {
+            contract.forwarder.set(forwarder);
+            contract.signer.set(alice);
+            contract.erc20._mint(alice, value).motsu_unwrap();
+        });
+
+        contract.sender(forwarder).transfer(bob, value).motsu_unwrap();
+
+        assert_eq!(U256::ZERO, contract.sender(alice).erc20.balance_of(alice));
+        assert_eq!(value, contract.sender(alice).erc20.balance_of(bob));
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).erc20.balance_of(forwarder)
+        );
+        contract.assert_emitted(&erc20::Transfer {
+            from: alice,
+            to: bob,
+            value,
+        });
+
+        // Calls not made by the forwarder keep their own sender.
+        let err = contract
+            .sender(bob)
+            .transfer(forwarder, uint!(11_U256))
+            .motsu_unwrap_err();
+        assert!(matches!(err, erc20::Error::InsufficientBalance(_)));
+    }
+
+    #[motsu::test]
+    fn overridden_context_attributes_grant_role(
+        contract: Contract<ForwardedToken>,
+        alice: Address,
+        bob: Address,
+        forwarder: Address,
+    ) {
+        contract.init(alice, |contract| {
+            contract.forwarder.set(forwarder);
+            contract.signer.set(alice);
+            contract
+                .access_control
+                ._grant_role(AccessControl::DEFAULT_ADMIN_ROLE.into(), alice);
+        });
+
+        contract
+            .sender(forwarder)
+            .grant_role(MINTER_ROLE.into(), bob)
+            .motsu_unwrap();
+
+        assert!(contract
+            .sender(alice)
+            .access_control
+            .has_role(MINTER_ROLE.into(), bob));
+        contract.assert_emitted(&control::RoleGranted {
+            role: MINTER_ROLE.into(),
+            account: bob,
+            sender: alice,
+        });
+
+        // The forwarder itself has not been granted the admin role.
+        let err = contract
+            .sender(bob)
+            .grant_role(MINTER_ROLE.into(), forwarder)
+            .motsu_unwrap_err();
+        assert!(matches!(err, control::Error::UnauthorizedAccount(_)));
+        assert!(!contract
+            .sender(alice)
+            .access_control
+            .has_role(MINTER_ROLE.into(), forwarder));
+    }
+
+    #[motsu::test]
+    fn overridden_context_attributes_extension_calls(
+        contract: Contract<ForwardedToken>,
+        alice: Address,
+        bob: Address,
+        forwarder: Address,
+    ) {
+        let value = uint!(10_U256);
+        contract.init(alice, |contract| {
+            contract.forwarder.set(forwarder);
+            contract.signer.set(bob);
+            contract.erc20._mint(alice, value).motsu_unwrap();
+        });
+        contract.sender(alice).erc20.approve(bob, value).motsu_unwrap();
+
+        // The allowance spent is the one of the signer, not the forwarder.
+        contract.sender(forwarder).burn_from(alice, value).motsu_unwrap();
+
+        assert_eq!(U256::ZERO, contract.sender(alice).erc20.balance_of(alice));
+        assert_eq!(
+            U256::ZERO,
+            contract.sender(alice).erc20.allowance(alice, bob)
+        );
+        contract.assert_emitted(&erc20::Transfer {
+            from: alice,
+            to: Address::ZERO,
+            value,
+        });
+    }
+}
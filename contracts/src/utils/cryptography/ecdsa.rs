@@ -39,6 +39,13 @@ mod sol {
         #[derive(Debug)]
         #[allow(missing_docs)]
         error ECDSAInvalidSignatureS(bytes32 s);
+
+        /// The signature has an invalid length.
+        ///
+        /// * `length` - Length of the signature, in bytes.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ECDSAInvalidSignatureLength(uint256 length);
     }
 
     sol! {
@@ -64,6 +71,47 @@ pub enum Error {
     InvalidSignature(ECDSAInvalidSignature),
     /// The signature has an `S` value that is in the upper half order.
     InvalidSignatureS(ECDSAInvalidSignatureS),
+    /// The signature has an invalid length.
+    InvalidSignatureLength(ECDSAInvalidSignatureLength),
+}
+
+/// Reason why a signature could not be recovered by [`try_recover_raw`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecoverError {
+    /// The signature was recovered.
+    NoError,
+    /// The signature derives the `Address::ZERO`.
+    InvalidSignature,
+    /// The signature has an invalid length, in bytes.
+    InvalidSignatureLength(usize),
+    /// The signature has an `S` value that is in the upper half order.
+    InvalidSignatureS(B256),
+}
+
+impl TryFrom<RecoverError> for Error {
+    type Error = RecoverError;
+
+    /// Converts a [`RecoverError`] into the reverting [`Error`].
+    ///
+    /// # Errors
+    ///
+    /// * [`RecoverError::NoError`] - If `err` is [`RecoverError::NoError`],
+    ///   which has no reverting counterpart.
+    fn try_from(err: RecoverError) -> Result<Self, Self::Error> {
+        match err {
+            RecoverError::NoError => Err(err),
+            RecoverError::InvalidSignature => {
+                Ok(ECDSAInvalidSignature {}.into())
+            }
+            RecoverError::InvalidSignatureLength(length) => {
+                Ok(ECDSAInvalidSignatureLength { length: U256::from(length) }
+                    .into())
+            }
+            RecoverError::InvalidSignatureS(s) => {
+                Ok(ECDSAInvalidSignatureS { s }.into())
+            }
+        }
+    }
 }
 
 impl MethodError for ecdsa::Error {
@@ -103,6 +151,178 @@ pub fn recover(
     _recover(storage, hash, v, r, s)
 }
 
+/// Returns the address that signed a hashed message (`hash`) with
+/// `signature`.
+///
+/// `signature` is either a 65 bytes `r || s || v` signature, or a 64 bytes
+/// `r || yParityAndS` [EIP-2098] compact signature.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to storage.
+/// * `hash` - Hash of the message.
+/// * `signature` - Signature of `hash`.
+///
+/// # Errors
+///
+/// * [`Error::InvalidSignatureLength`] - If `signature` is neither 64 nor 65
+///   bytes long.
+/// * [`Error::InvalidSignatureS`] - If the `s` value is greater than
+///   [`SIGNATURE_S_UPPER_BOUND`].
+/// * [`Error::InvalidSignature`] - If the recovered address is `Address::ZERO`.
+///
+/// # Panics
+///
+/// * If the `ecrecover` precompile fails to execute.
+///
+/// [EIP-2098]: https://eips.ethereum.org/EIPS/eip-2098
+pub fn try_recover(
+    storage: &mut impl TopLevelStorage,
+    hash: B256,
+    signature: &[u8],
+) -> Result<Address, Error> {
+    let (recovered, err) = try_recover_raw(storage, hash, signature);
+    Error::try_from(err).map_or(Ok(recovered), Err)
+}
+
+/// Returns the address that signed a hashed message (`hash`) with
+/// `signature`, or `Address::ZERO` along with the reason why the signature
+/// could not be recovered.
+///
+/// Unlike [`try_recover`], this function lets callers handle invalid
+/// signatures without reverting.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to storage.
+/// * `hash` - Hash of the message.
+/// * `signature` - Signature of `hash`, see [`try_recover`].
+///
+/// # Panics
+///
+/// * If the `ecrecover` precompile fails to execute.
+pub fn try_recover_raw(
+    storage: &mut impl TopLevelStorage,
+    hash: B256,
+    signature: &[u8],
+) -> (Address, RecoverError) {
+    let (v, r, s) = match parse_signature(signature) {
+        Ok(parsed) => parsed,
+        Err(err) => return (Address::ZERO, err),
+    };
+    if check_if_malleable(&s).is_err() {
+        return (Address::ZERO, RecoverError::InvalidSignatureS(s));
+    }
+    match _recover(storage, hash, v, r, s) {
+        Ok(recovered) => (recovered, RecoverError::NoError),
+        Err(_) => (Address::ZERO, RecoverError::InvalidSignature),
+    }
+}
+
+/// Returns true if every `(hash, signature, signer)` entry of `batch` has
+/// `signature` recovering `signer` from `hash`.
+///
+/// Stops at the first entry that doesn't verify. An empty `batch` verifies.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to storage.
+/// * `batch` - Hashed messages, their signatures (see [`try_recover`]) and
+///   expected signers.
+///
+/// # Panics
+///
+/// * If the `ecrecover` precompile fails to execute.
+pub fn verify_batch(
+    storage: &mut impl TopLevelStorage,
+    batch: &[(B256, &[u8], Address)],
+) -> bool {
+    batch.iter().all(|&(hash, signature, signer)| {
+        verify(storage, hash, signature, signer)
+    })
+}
+
+/// Returns a bitmask with the `i`-th bit set if the `i`-th
+/// `(hash, signature, signer)` entry of `batch` has `signature` recovering
+/// `signer` from `hash`.
+///
+/// Unlike [`verify_batch`], every entry is verified.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to storage.
+/// * `batch` - At most 256 hashed messages, their signatures (see
+///   [`try_recover`]) and expected signers.
+///
+/// # Panics
+///
+/// * If `batch` has more than 256 entries.
+/// * If the `ecrecover` precompile fails to execute.
+pub fn verify_batch_bitmask(
+    storage: &mut impl TopLevelStorage,
+    batch: &[(B256, &[u8], Address)],
+) -> U256 {
+    assert!(
+        batch.len() <= 256,
+        "should verify at most 256 signatures in `ecdsa::verify_batch_bitmask`"
+    );
+    let mut bitmask = U256::ZERO;
+    for (i, &(hash, signature, signer)) in batch.iter().enumerate() {
+        bitmask.set_bit(i, verify(storage, hash, signature, signer));
+    }
+    bitmask
+}
+
+/// Returns true if `signature` recovers `signer` from `hash`.
+///
+/// # Panics
+///
+/// * If the `ecrecover` precompile fails to execute.
+fn verify(
+    storage: &mut impl TopLevelStorage,
+    hash: B256,
+    signature: &[u8],
+    signer: Address,
+) -> bool {
+    match try_recover_raw(storage, hash, signature) {
+        (recovered, RecoverError::NoError) => recovered == signer,
+        _ => false,
+    }
+}
+
+/// Splits `signature` into its `v`, `r` and `s` values.
+///
+/// # Arguments
+///
+/// * `signature` - Either a 65 bytes `r || s || v` signature, or a 64 bytes `r
+///   || yParityAndS` [EIP-2098] compact signature.
+///
+/// # Errors
+///
+/// * [`RecoverError::InvalidSignatureLength`] - If `signature` is neither 64
+///   nor 65 bytes long.
+///
+/// [EIP-2098]: https://eips.ethereum.org/EIPS/eip-2098
+fn parse_signature(signature: &[u8]) -> Result<(u8, B256, B256), RecoverError> {
+    match signature.len() {
+        65 => {
+            let r = B256::from_slice(&signature[..32]);
+            let s = B256::from_slice(&signature[32..64]);
+            Ok((signature[64], r, s))
+        }
+        64 => {
+            let r = B256::from_slice(&signature[..32]);
+            let mut s = B256::from_slice(&signature[32..]);
+            // The highest bit of `yParityAndS` is the y parity, and the rest
+            // is `s`.
+            let y_parity = s[0] >> 7;
+            s[0] &= 0x7f;
+            Ok((y_parity + 27, r, s))
+        }
+        length => Err(RecoverError::InvalidSignatureLength(length)),
+    }
+}
+
 /// Calls `ecrecover` EVM precompile.
 ///
 /// The `ecrecover` EVM precompile allows for malleable (non-unique) signatures:
@@ -207,9 +427,18 @@ fn check_if_malleable(s: &B256) -> Result<(), Error> {
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use alloy_primitives::{b256, B256};
+    use motsu::prelude::*;
 
     use super::*;
 
+    #[storage]
+    struct Verifier;
+
+    unsafe impl TopLevelStorage for Verifier {}
+
+    #[public]
+    impl Verifier {}
+
     const MSG_HASH: B256 = b256!(
         "a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2"
     );
@@ -242,6 +471,112 @@ mod tests {
         ));
     }
 
+    fn signature(r: B256, s: B256, v: u8) -> Vec<u8> {
+        [r.as_slice(), s.as_slice(), &[v]].concat()
+    }
+
+    #[test]
+    fn parses_signature() {
+        let parsed = parse_signature(&signature(R, S, V))
+            .expect("should parse 65 bytes signature");
+        assert_eq!((V, R, S), parsed);
+    }
+
+    #[test]
+    fn parses_compact_signature() {
+        // `V` is 28, so the y parity bit is set.
+        let mut y_parity_and_s = S;
+        y_parity_and_s[0] |= 0x80;
+        let compact = [R.as_slice(), y_parity_and_s.as_slice()].concat();
+        let parsed = parse_signature(&compact)
+            .expect("should parse 64 bytes compact signature");
+        assert_eq!((V, R, S), parsed);
+
+        let compact = [R.as_slice(), S.as_slice()].concat();
+        let parsed = parse_signature(&compact)
+            .expect("should parse 64 bytes compact signature");
+        assert_eq!((27, R, S), parsed);
+    }
+
+    #[test]
+    fn rejects_invalid_signature_length() {
+        for length in [0, 63, 66] {
+            let err = parse_signature(&vec![1; length])
+                .expect_err("should return InvalidSignatureLength");
+            assert_eq!(RecoverError::InvalidSignatureLength(length), err);
+
+            let err = Error::try_from(err)
+                .expect("should convert InvalidSignatureLength");
+            assert!(matches!(err,
+                Error::InvalidSignatureLength(ECDSAInvalidSignatureLength {
+                    length: l
+                }) if l == U256::from(length)
+            ));
+        }
+    }
+
+    #[test]
+    fn no_error_does_not_convert_to_error() {
+        let err = Error::try_from(RecoverError::NoError)
+            .expect_err("should not convert NoError");
+        assert_eq!(RecoverError::NoError, err);
+    }
+
+    #[test]
+    fn rejects_high_s_compact_signature() {
+        // The highest bit of `s` is the y parity, so `s` is always lower than
+        // `2^255`, but can still be in the upper half order.
+        let high_s = SIGNATURE_S_UPPER_BOUND + uint!(1_U256);
+        let high_s = B256::from(high_s);
+        let compact = [R.as_slice(), high_s.as_slice()].concat();
+        let (_, _, s) = parse_signature(&compact)
+            .expect("should parse 64 bytes compact signature");
+        assert_eq!(high_s, s);
+        assert!(check_if_malleable(&s).is_err());
+    }
+
+    #[motsu::test]
+    fn verifies_empty_batch(contract: Contract<Verifier>, alice: Address) {
+        assert!(verify_batch(&mut *contract.sender(alice), &[]));
+        assert_eq!(
+            U256::ZERO,
+            verify_batch_bitmask(&mut *contract.sender(alice), &[])
+        );
+    }
+
+    #[motsu::test]
+    fn rejects_batch_with_invalid_signatures(
+        contract: Contract<Verifier>,
+        alice: Address,
+    ) {
+        let high_s = B256::from(SIGNATURE_S_UPPER_BOUND + uint!(1_U256));
+        let too_long = [signature(R, S, V).as_slice(), &[0]].concat();
+        let high_s = signature(R, high_s, V);
+        let zero_v = signature(R, S, 0);
+        let batch = [
+            (MSG_HASH, too_long.as_slice(), alice),
+            (MSG_HASH, high_s.as_slice(), alice),
+            (MSG_HASH, zero_v.as_slice(), alice),
+        ];
+
+        assert!(!verify_batch(&mut *contract.sender(alice), &batch));
+        assert_eq!(
+            U256::ZERO,
+            verify_batch_bitmask(&mut *contract.sender(alice), &batch)
+        );
+    }
+
+    #[motsu::test]
+    #[should_panic = "should verify at most 256 signatures"]
+    fn bitmask_panics_on_too_large_batch(
+        contract: Contract<Verifier>,
+        alice: Address,
+    ) {
+        let signature = signature(R, S, 0);
+        let batch = vec![(MSG_HASH, signature.as_slice(), alice); 257];
+        verify_batch_bitmask(&mut *contract.sender(alice), &batch);
+    }
+
     #[test]
     fn validates_s() {
         let valid_s = SIGNATURE_S_UPPER_BOUND - uint!(1_U256);
@@ -10,11 +10,15 @@
 //!
 //! [`eth_signTypedDataV4`]: https://docs.metamask.io/guide/signing-data.html
 
-use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
 
 use alloy_primitives::{keccak256, Address, B256, U256};
 use alloy_sol_types::{sol, SolType};
-use stylus_sdk::{block, contract};
+use stylus_sdk::{
+    block, contract,
+    prelude::*,
+    storage::{StorageAddress, StorageB256, StorageU256},
+};
 
 /// Keccak-256 hash of the EIP-712 domain separator type string.
 const TYPE_HASH: [u8; 32] =
@@ -22,10 +26,20 @@ const TYPE_HASH: [u8; 32] =
         .update(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)")
         .finalize();
 
+/// Keccak-256 hash of the EIP-712 domain separator type string, including a
+/// salt.
+const TYPE_HASH_WITH_SALT: [u8; 32] =
+    keccak_const::Keccak256::new()
+        .update(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract,bytes32 salt)")
+        .finalize();
+
 /// Field for the domain separator.
 const FIELDS: [u8; 1] = [0x0f];
 
-/// Salt for the domain separator.
+/// Field for the domain separator, including a salt.
+const FIELDS_WITH_SALT: [u8; 1] = [0x1f];
+
+/// Salt for the domain separator, when [`IEip712::salt`] is `None`.
 const SALT: [u8; 32] = [0u8; 32];
 
 /// Prefix for ERC-191 version with `0x01`.
@@ -36,6 +50,11 @@ type DomainSeparatorTuple = sol! {
     tuple(bytes32, bytes32, bytes32, uint256, address)
 };
 
+/// Tuple for the domain separator, including a salt.
+type SaltedDomainSeparatorTuple = sol! {
+    tuple(bytes32, bytes32, bytes32, uint256, address, bytes32)
+};
+
 /// Returns the keccak256 digest of an EIP-712 typed data (ERC-191 version
 /// `0x01`).
 ///
@@ -57,6 +76,37 @@ pub fn to_typed_data_hash(
     keccak256(preimage)
 }
 
+/// Returns the domain separator of `T` for the current chain id and
+/// contract address.
+///
+/// The salt of the domain is only encoded if [`IEip712::salt`] returns one.
+///
+/// # Arguments
+///
+/// * `eip712` - Read access to the [`IEip712`] instance.
+#[must_use]
+pub fn build_domain_separator<T: IEip712 + ?Sized>(eip712: &T) -> B256 {
+    let encoded = match eip712.salt() {
+        None => DomainSeparatorTuple::abi_encode(&(
+            TYPE_HASH,
+            T::HASHED_NAME,
+            T::HASHED_VERSION,
+            T::chain_id(),
+            T::contract_address(),
+        )),
+        Some(salt) => SaltedDomainSeparatorTuple::abi_encode(&(
+            TYPE_HASH_WITH_SALT,
+            T::HASHED_NAME,
+            T::HASHED_VERSION,
+            T::chain_id(),
+            T::contract_address(),
+            salt,
+        )),
+    };
+
+    keccak256(encoded)
+}
+
 /// EIP-712 Contract interface.
 pub trait IEip712 {
     /// Immutable name of EIP-712 instance.
@@ -84,6 +134,36 @@ pub trait IEip712 {
         contract::address()
     }
 
+    /// Returns the salt of EIP-712 instance, if any.
+    ///
+    /// Defaults to `None`, leaving the salt out of the domain separator as
+    /// Solidity contracts do. Tokens deployed at the same address on several
+    /// chains may return a distinct salt per deployment, set at construction,
+    /// so that their domains never collide:
+    ///
+    /// ```rust,ignore
+    /// #[storage]
+    /// struct Eip712 {
+    ///     salt: StorageB256,
+    /// }
+    ///
+    /// impl IEip712 for Eip712 {
+    ///     const NAME: &'static str = "ERC-20 Permit Example";
+    ///     const VERSION: &'static str = "1";
+    ///
+    ///     fn salt(&self) -> Option<B256> {
+    ///         Some(self.salt.get())
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn salt(&self) -> Option<B256> {
+        None
+    }
+
     /// Returns the fields and values that describe the domain separator used by
     /// this contract for EIP-712 signature.
     ///
@@ -93,13 +173,17 @@ pub trait IEip712 {
     fn eip712_domain(
         &self,
     ) -> ([u8; 1], String, String, U256, Address, [u8; 32], Vec<U256>) {
+        let (fields, salt) = match self.salt() {
+            None => (FIELDS, SALT),
+            Some(salt) => (FIELDS_WITH_SALT, salt.0),
+        };
         (
-            FIELDS,
+            fields,
             Self::NAME.to_owned(),
             Self::VERSION.to_owned(),
             Self::chain_id(),
             Self::contract_address(),
-            SALT,
+            salt,
             Vec::new(),
         )
     }
@@ -110,15 +194,7 @@ pub trait IEip712 {
     ///
     /// * `&self` - Read access to the contract's state.
     fn domain_separator_v4(&self) -> B256 {
-        let encoded = DomainSeparatorTuple::abi_encode(&(
-            TYPE_HASH,
-            Self::HASHED_NAME,
-            Self::HASHED_VERSION,
-            Self::chain_id(),
-            Self::contract_address(),
-        ));
-
-        keccak256(encoded)
+        build_domain_separator(self)
     }
 
     /// Given an already [hashed struct], this function returns the hash of the
@@ -135,11 +211,98 @@ pub trait IEip712 {
     }
 }
 
+/// Domain separator stored along with the chain id and contract address it
+/// was computed for.
+///
+/// The domain separator is computed on each call by default, which is
+/// fork-safe. Contracts preferring a storage read can embed this cache in
+/// their [`IEip712`] implementation, and return
+/// [`DomainSeparatorCache::domain_separator`] from
+/// [`IEip712::domain_separator_v4`]:
+///
+/// ```rust,ignore
+/// #[storage]
+/// struct Eip712 {
+///     cache: DomainSeparatorCache,
+/// }
+///
+/// impl IEip712 for Eip712 {
+///     const NAME: &'static str = "ERC-20 Permit Example";
+///     const VERSION: &'static str = "1";
+///
+///     fn domain_separator_v4(&self) -> B256 {
+///         self.cache.domain_separator(self)
+///     }
+/// }
+/// ```
+#[storage]
+pub struct DomainSeparatorCache {
+    /// Chain id the domain separator was cached for.
+    pub(crate) cached_chain_id: StorageU256,
+    /// Contract address the domain separator was cached for.
+    pub(crate) cached_address: StorageAddress,
+    /// Cached domain separator.
+    pub(crate) cached_domain_separator: StorageB256,
+}
+
+impl DomainSeparatorCache {
+    /// Returns the cached domain separator of `eip712`, or recomputes it if
+    /// the chain id or the contract address changed since it was cached, e.g.
+    /// after a fork of the chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `eip712` - Read access to the [`IEip712`] instance.
+    #[must_use]
+    pub fn domain_separator<T: IEip712 + ?Sized>(&self, eip712: &T) -> B256 {
+        let cached = self.cached_domain_separator.get();
+        if !cached.is_zero()
+            && self.cached_chain_id.get() == T::chain_id()
+            && self.cached_address.get() == T::contract_address()
+        {
+            cached
+        } else {
+            build_domain_separator(eip712)
+        }
+    }
+
+    /// Caches the domain separator of `eip712`, as computed by
+    /// [`build_domain_separator`], for its current chain id and contract
+    /// address.
+    ///
+    /// Meant to be called at initialization, and again after a fork to avoid
+    /// recomputing the domain separator on each call.
+    ///
+    /// # Arguments
+    ///
+    /// * `eip712` - Write access to the [`IEip712`] instance.
+    /// * `cache` - Accessor of the cache embedded in `eip712`.
+    pub fn cache<T: IEip712 + ?Sized>(
+        eip712: &mut T,
+        cache: impl FnOnce(&mut T) -> &mut Self,
+    ) {
+        let domain_separator = build_domain_separator(eip712);
+        let cache = cache(eip712);
+        cache.cached_chain_id.set(T::chain_id());
+        cache.cached_address.set(T::contract_address());
+        cache.cached_domain_separator.set(domain_separator);
+    }
+}
+
 #[cfg(all(test, feature = "std"))]
 mod tests {
-    use alloy_primitives::{address, b256, uint, Address, U256};
+    use std::cell::Cell;
 
-    use super::{to_typed_data_hash, IEip712, FIELDS, SALT};
+    use alloy_primitives::{address, b256, uint, Address, B256, U256};
+    use alloy_sol_types::eip712_domain;
+    use motsu::prelude::*;
+    use stylus_sdk::{prelude::*, storage::StorageB256};
+
+    use super::{
+        build_domain_separator, to_typed_data_hash, DomainSeparatorCache,
+        IEip712, FIELDS, FIELDS_WITH_SALT, SALT,
+    };
 
     const CHAIN_ID: U256 = uint!(42161_U256);
 
@@ -175,6 +338,107 @@ mod tests {
         assert_eq!(Vec::<U256>::new(), domain.6);
     }
 
+    #[storage]
+    struct SaltedEIP712 {
+        salt: StorageB256,
+    }
+
+    unsafe impl TopLevelStorage for SaltedEIP712 {}
+
+    #[public]
+    impl SaltedEIP712 {}
+
+    impl IEip712 for SaltedEIP712 {
+        const NAME: &'static str = "A Name";
+        const VERSION: &'static str = "1";
+
+        fn chain_id() -> U256 {
+            CHAIN_ID
+        }
+
+        fn contract_address() -> Address {
+            CONTRACT_ADDRESS
+        }
+
+        fn salt(&self) -> Option<B256> {
+            Some(self.salt.get())
+        }
+    }
+
+    fn init_salted(contract: &Contract<SaltedEIP712>, salt: u8) {
+        contract.init(Address::ZERO, |contract| {
+            contract.salt.set(B256::repeat_byte(salt));
+        });
+    }
+
+    #[test]
+    fn domain_separator_matches_eip712() {
+        let expected = eip712_domain! {
+            name: "A Name",
+            version: "1",
+            chain_id: 42161,
+            verifying_contract: CONTRACT_ADDRESS,
+        };
+        assert_eq!(expected.separator(), build_domain_separator(&TestEIP712));
+    }
+
+    #[motsu::test]
+    fn salted_domain_separator_matches_eip712(
+        contract: Contract<SaltedEIP712>,
+    ) {
+        init_salted(&contract, 1);
+
+        let expected = eip712_domain! {
+            name: "A Name",
+            version: "1",
+            chain_id: 42161,
+            verifying_contract: CONTRACT_ADDRESS,
+            salt: B256::repeat_byte(1),
+        };
+        assert_eq!(
+            expected.separator(),
+            contract.sender(Address::ZERO).domain_separator_v4()
+        );
+    }
+
+    #[motsu::test]
+    fn salted_domain(contract: Contract<SaltedEIP712>) {
+        init_salted(&contract, 1);
+
+        let domain = contract.sender(Address::ZERO).eip712_domain();
+        assert_eq!(FIELDS_WITH_SALT, domain.0);
+        assert_eq!([1u8; 32], domain.5);
+    }
+
+    #[motsu::test]
+    fn salt_separates_domains(
+        salted: Contract<SaltedEIP712>,
+        other_salted: Contract<SaltedEIP712>,
+        alice: Address,
+    ) {
+        init_salted(&salted, 1);
+        init_salted(&other_salted, 2);
+
+        let unsalted = TestEIP712.domain_separator_v4();
+        let salted_separator = salted.sender(alice).domain_separator_v4();
+        assert_ne!(unsalted, salted_separator);
+        assert_ne!(
+            salted_separator,
+            other_salted.sender(alice).domain_separator_v4()
+        );
+
+        // The same permit signed for one domain does not verify in the other.
+        let struct_hash = B256::repeat_byte(1);
+        assert_ne!(
+            salted.sender(alice).hash_typed_data_v4(struct_hash),
+            other_salted.sender(alice).hash_typed_data_v4(struct_hash)
+        );
+        assert_ne!(
+            TestEIP712.hash_typed_data_v4(struct_hash),
+            salted.sender(alice).hash_typed_data_v4(struct_hash)
+        );
+    }
+
     #[test]
     fn test_to_typed_data_hash() {
         // TYPE_HASH
@@ -194,4 +458,105 @@ mod tests {
             to_typed_data_hash(&domain_separator, &struct_hash),
         );
     }
+
+    thread_local! {
+        static FORKED_CHAIN_ID: Cell<U256> = const { Cell::new(CHAIN_ID) };
+    }
+
+    #[storage]
+    struct CachedEip712 {
+        cache: DomainSeparatorCache,
+    }
+
+    unsafe impl TopLevelStorage for CachedEip712 {}
+
+    #[public]
+    impl CachedEip712 {}
+
+    impl IEip712 for CachedEip712 {
+        const NAME: &'static str = "A Name";
+        const VERSION: &'static str = "1";
+
+        fn chain_id() -> U256 {
+            FORKED_CHAIN_ID.get()
+        }
+
+        fn contract_address() -> Address {
+            CONTRACT_ADDRESS
+        }
+
+        fn domain_separator_v4(&self) -> B256 {
+            self.cache.domain_separator(self)
+        }
+    }
+
+    #[motsu::test]
+    fn cached_domain_separator_follows_chain_id(
+        contract: Contract<CachedEip712>,
+        alice: Address,
+    ) {
+        let struct_hash = B256::repeat_byte(1);
+        let uncached = build_domain_separator(&TestEIP712);
+        assert_eq!(uncached, contract.sender(alice).domain_separator_v4());
+
+        DomainSeparatorCache::cache(&mut *contract.sender(alice), |c| {
+            &mut c.cache
+        });
+        let cached = contract.sender(alice).domain_separator_v4();
+        assert_eq!(uncached, cached);
+        assert_eq!(cached, contract.sender(alice).domain_separator_v4());
+        let digest = contract.sender(alice).hash_typed_data_v4(struct_hash);
+
+        // Simulate a fork of the chain.
+        FORKED_CHAIN_ID.set(uint!(1_U256));
+
+        let forked = contract.sender(alice).domain_separator_v4();
+        assert_ne!(cached, forked);
+        assert_eq!(build_domain_separator(&*contract.sender(alice)), forked);
+        assert_ne!(
+            digest,
+            contract.sender(alice).hash_typed_data_v4(struct_hash)
+        );
+        assert_eq!(
+            cached,
+            contract.sender(alice).cache.cached_domain_separator.get()
+        );
+
+        // Caching again stores the separator of the forked chain.
+        DomainSeparatorCache::cache(&mut *contract.sender(alice), |c| {
+            &mut c.cache
+        });
+        assert_eq!(
+            forked,
+            contract.sender(alice).cache.cached_domain_separator.get()
+        );
+        assert_eq!(forked, contract.sender(alice).domain_separator_v4());
+
+        FORKED_CHAIN_ID.set(CHAIN_ID);
+        assert_eq!(cached, contract.sender(alice).domain_separator_v4());
+    }
+
+    #[motsu::test]
+    fn cached_digests_match_fresh_computation(
+        contract: Contract<CachedEip712>,
+        alice: Address,
+    ) {
+        let fresh = build_domain_separator(&*contract.sender(alice));
+        DomainSeparatorCache::cache(&mut *contract.sender(alice), |c| {
+            &mut c.cache
+        });
+
+        // E.g. the digests of ten permits within a transaction.
+        for i in 0..10 {
+            let struct_hash = B256::repeat_byte(i);
+            assert_eq!(
+                to_typed_data_hash(&fresh, &struct_hash),
+                contract.sender(alice).hash_typed_data_v4(struct_hash)
+            );
+        }
+        assert_eq!(
+            fresh,
+            contract.sender(alice).cache.cached_domain_separator.get()
+        );
+    }
 }
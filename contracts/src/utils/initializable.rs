@@ -0,0 +1,354 @@
+//! Initializable Contract.
+//!
+//! Contract module which helps initializing contracts deployed behind a
+//! proxy, where the state set up at construction would otherwise live in the
+//! proxy's implementation contract.
+//!
+//! The initialization function of such a contract runs its setup within
+//! [`Initializable::initializer`], which can only succeed once. Internal
+//! initialization functions of the contracts it embeds, e.g.
+//! [`crate::access::ownable::Ownable::_init`], check with
+//! [`Initializable::only_initializing`] that they are not called outside of
+//! it:
+//!
+//! ```rust,ignore
+//! fn initialize(&mut self, owner: Address) -> Result<(), Error> {
+//!     self.initializable.initializer(|initializable| {
+//!         self.ownable._init(initializable, owner)?;
+//!         Ok(())
+//!     })
+//! }
+//! ```
+//!
+//! Upgrades of the implementation needing a migration of the state run it
+//! within [`Initializable::reinitializer`], at a version greater than any
+//! previous initialization.
+//!
+//! CAUTION: The initialization function should be called in the same
+//! transaction as the deployment of the proxy, since anyone can call it
+//! first otherwise.
+
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::aliases::U64;
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    evm,
+    prelude::*,
+    storage::{StorageBool, StorageU64},
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// Emitted when the contract has been initialized.
+        ///
+        /// * `version` - Version the contract was initialized to.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        event Initialized(uint64 version);
+    }
+
+    sol! {
+        /// The contract is already initialized.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error InvalidInitialization();
+
+        /// The contract is not initializing.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error NotInitializing();
+    }
+}
+
+/// An [`Initializable`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The contract is already initialized.
+    InvalidInitialization(InvalidInitialization),
+    /// The contract is not initializing.
+    NotInitializing(NotInitializing),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of an [`Initializable`] contract.
+#[storage]
+pub struct Initializable {
+    /// Version the contract was last initialized to, zero if it has not
+    /// been initialized.
+    pub(crate) initialized: StorageU64,
+    /// Indicates whether the contract is being initialized.
+    pub(crate) initializing: StorageBool,
+}
+
+impl Initializable {
+    /// Returns true if the contract has been initialized, and false
+    /// otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn _initialized(&self) -> bool {
+        !self._get_initialized_version().is_zero()
+    }
+
+    /// Returns the version the contract was last initialized to, or zero if
+    /// it has not been initialized.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn _get_initialized_version(&self) -> U64 {
+        self.initialized.get()
+    }
+
+    /// Returns true if the contract is being initialized, and false
+    /// otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn _initializing(&self) -> bool {
+        self.initializing.get()
+    }
+
+    /// Runs `init` to initialize the contract, and marks it as initialized
+    /// to version `1`.
+    ///
+    /// [`Self::only_initializing`] succeeds while `init` runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `init` - Initialization of the contract, given read access to the
+    ///   initializing contract's state.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidInitialization`] - If the contract is already
+    ///   initialized, or being initialized.
+    /// * Any error returned by `init`.
+    ///
+    /// # Events
+    ///
+    /// * [`Initialized`].
+    pub fn initializer<E: From<Error>>(
+        &mut self,
+        init: impl FnOnce(&Self) -> Result<(), E>,
+    ) -> Result<(), E> {
+        self.reinitializer(U64::from(1), init)
+    }
+
+    /// Runs `init` to initialize the contract, and marks it as initialized
+    /// to `version`.
+    ///
+    /// Meant to migrate the state of the contract after an upgrade. Each
+    /// `version` can only run once, and after any lower version. Versions
+    /// may be skipped, e.g. when upgrading a contract never initialized with
+    /// the intermediate versions.
+    ///
+    /// [`Self::only_initializing`] succeeds while `init` runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `version` - Version to initialize the contract to.
+    /// * `init` - Initialization of the contract, given read access to the
+    ///   initializing contract's state.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidInitialization`] - If the contract is already
+    ///   initialized to `version` or a greater version, or being initialized.
+    /// * Any error returned by `init`.
+    ///
+    /// # Events
+    ///
+    /// * [`Initialized`].
+    pub fn reinitializer<E: From<Error>>(
+        &mut self,
+        version: U64,
+        init: impl FnOnce(&Self) -> Result<(), E>,
+    ) -> Result<(), E> {
+        if self._initializing() || self._get_initialized_version() >= version {
+            return Err(
+                Error::InvalidInitialization(InvalidInitialization {}).into()
+            );
+        }
+
+        self.initialized.set(version);
+        self.initializing.set(true);
+        init(self)?;
+        self.initializing.set(false);
+
+        evm::log(Initialized { version: version.to() });
+        Ok(())
+    }
+
+    /// Checks that the contract is being initialized, i.e. that the caller
+    /// runs within [`Self::initializer`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NotInitializing`] - If the contract is not being initialized.
+    pub fn only_initializing(&self) -> Result<(), Error> {
+        if !self._initializing() {
+            return Err(Error::NotInitializing(NotInitializing {}));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{aliases::U64, uint, Address, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::{prelude::*, storage::StorageU256};
+
+    use super::{Error, Initializable, Initialized};
+
+    /// Contract storing a value set once at initialization.
+    #[storage]
+    struct InitializableValue {
+        initializable: Initializable,
+        value: StorageU256,
+    }
+
+    unsafe impl TopLevelStorage for InitializableValue {}
+
+    #[public]
+    impl InitializableValue {
+        fn initialize(&mut self, value: U256) -> Result<(), Error> {
+            self.initializable.initializer(|initializable| {
+                initializable.only_initializing()?;
+                self.value.set(value);
+                Ok(())
+            })
+        }
+
+        fn migrate(&mut self, version: U64) -> Result<(), Error> {
+            self.initializable.reinitializer(version, |initializable| {
+                initializable.only_initializing()?;
+                // Scale the value by ten at each migration.
+                let value = self.value.get();
+                self.value.set(value * U256::from(10));
+                Ok(())
+            })
+        }
+    }
+
+    #[motsu::test]
+    fn initializes_once(
+        contract: Contract<InitializableValue>,
+        alice: Address,
+    ) {
+        assert!(!contract.sender(alice).initializable._initialized());
+
+        contract.sender(alice).initialize(U256::from(1)).motsu_unwrap();
+
+        assert_eq!(U256::from(1), contract.sender(alice).value.get());
+        assert!(contract.sender(alice).initializable._initialized());
+        assert!(!contract.sender(alice).initializable._initializing());
+        assert_eq!(
+            uint!(1_U64),
+            contract.sender(alice).initializable._get_initialized_version()
+        );
+        contract.assert_emitted(&Initialized { version: 1 });
+    }
+
+    #[motsu::test]
+    fn reinitializes_at_increasing_versions(
+        contract: Contract<InitializableValue>,
+        alice: Address,
+    ) {
+        contract.sender(alice).initialize(U256::from(1)).motsu_unwrap();
+
+        contract.sender(alice).migrate(uint!(2_U64)).motsu_unwrap();
+        assert_eq!(U256::from(10), contract.sender(alice).value.get());
+        contract.assert_emitted(&Initialized { version: 2 });
+
+        contract.sender(alice).migrate(uint!(3_U64)).motsu_unwrap();
+        assert_eq!(U256::from(100), contract.sender(alice).value.get());
+        contract.assert_emitted(&Initialized { version: 3 });
+        assert_eq!(
+            uint!(3_U64),
+            contract.sender(alice).initializable._get_initialized_version()
+        );
+        assert!(!contract.sender(alice).initializable._initializing());
+    }
+
+    #[motsu::test]
+    fn error_when_reinitializing_at_same_or_lower_version(
+        contract: Contract<InitializableValue>,
+        alice: Address,
+    ) {
+        contract.sender(alice).migrate(uint!(2_U64)).motsu_unwrap();
+
+        for version in [uint!(2_U64), uint!(1_U64)] {
+            let err =
+                contract.sender(alice).migrate(version).motsu_unwrap_err();
+            assert!(matches!(err, Error::InvalidInitialization(_)));
+        }
+
+        // Initializing to version `1` after a later version is rejected too.
+        let err =
+            contract.sender(alice).initialize(U256::from(1)).motsu_unwrap_err();
+        assert!(matches!(err, Error::InvalidInitialization(_)));
+        assert_eq!(
+            uint!(2_U64),
+            contract.sender(alice).initializable._get_initialized_version()
+        );
+    }
+
+    #[motsu::test]
+    fn error_when_initializing_twice(
+        contract: Contract<InitializableValue>,
+        alice: Address,
+        bob: Address,
+    ) {
+        contract.sender(alice).initialize(U256::from(1)).motsu_unwrap();
+
+        let err =
+            contract.sender(bob).initialize(U256::from(2)).motsu_unwrap_err();
+        assert!(matches!(err, Error::InvalidInitialization(_)));
+        assert_eq!(U256::from(1), contract.sender(alice).value.get());
+    }
+
+    #[motsu::test]
+    fn error_when_not_initializing(
+        contract: Contract<InitializableValue>,
+        alice: Address,
+    ) {
+        let err = contract
+            .sender(alice)
+            .initializable
+            .only_initializing()
+            .motsu_unwrap_err();
+        assert!(matches!(err, Error::NotInitializing(_)));
+
+        contract.sender(alice).initialize(U256::from(1)).motsu_unwrap();
+
+        let err = contract
+            .sender(alice)
+            .initializable
+            .only_initializing()
+            .motsu_unwrap_err();
+        assert!(matches!(err, Error::NotInitializing(_)));
+    }
+}
@@ -38,6 +38,8 @@ pub trait IErc165 {
     /// }
     /// ```
     ///
+    /// The same can be written with [`crate::supports_interface!`].
+    ///
     /// [ERC]: https://eips.ethereum.org/EIPS/eip-165#how-interfaces-are-identified
     fn supports_interface(interface_id: FixedBytes<4>) -> bool;
 }
@@ -63,3 +65,73 @@ impl IErc165 for Erc165 {
         Self::INTERFACE_ID == u32::from_be_bytes(*interface_id)
     }
 }
+
+/// Returns true if any of the listed [`IErc165`] implementers supports
+/// `interface_id`.
+///
+/// Saves spelling out the interface check of every component embedded in a
+/// contract.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[public]
+/// impl Erc721Example {
+///     fn supports_interface(interface_id: FixedBytes<4>) -> bool {
+///         supports_interface!(
+///             interface_id;
+///             Erc721,
+///             Erc721Enumerable,
+///             Erc721Metadata,
+///         )
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! supports_interface {
+    ($interface_id:expr; $($component:ty),+ $(,)?) => {{
+        let interface_id = $interface_id;
+        false $(|| <$component as
+            $crate::utils::introspection::erc165::IErc165>::supports_interface(
+                interface_id,
+            ))+
+    }};
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::FixedBytes;
+
+    use super::{Erc165, IErc165};
+    use crate::token::erc721::{
+        extensions::{
+            Erc721Enumerable, Erc721Metadata, IErc721Enumerable,
+            IErc721Metadata,
+        },
+        Erc721, IErc721,
+    };
+
+    fn supports_interface(interface_id: FixedBytes<4>) -> bool {
+        crate::supports_interface!(
+            interface_id;
+            Erc721,
+            Erc721Enumerable,
+            Erc721Metadata,
+        )
+    }
+
+    #[test]
+    fn supports_interface_of_every_component() {
+        assert!(supports_interface(<Erc721 as IErc721>::INTERFACE_ID.into()));
+        assert!(supports_interface(
+            <Erc721Enumerable as IErc721Enumerable>::INTERFACE_ID.into()
+        ));
+        assert!(supports_interface(
+            <Erc721Metadata as IErc721Metadata>::INTERFACE_ID.into()
+        ));
+        assert!(supports_interface(<Erc165 as IErc165>::INTERFACE_ID.into()));
+
+        let fake_interface_id = 0x12345678u32;
+        assert!(!supports_interface(fake_interface_id.into()));
+    }
+}
@@ -1,8 +1,10 @@
 //! Standard math utilities missing in `alloy_primitives`.
 use alloy_primitives::{uint, U256, U512};
 
+pub use super::Rounding;
+
 /// Trait for standard math utilities missing in `alloy_primitives`.
-pub trait Math {
+pub trait Math: Sized {
     /// Returns the square root of a number. If the number is not a perfect
     /// square, the value is rounded towards zero.
     /// This method is based on Newton's method for computing square roots; the
@@ -23,8 +25,23 @@ pub trait Math {
     #[must_use]
     fn average(self, rhs: Self) -> Self;
 
-    /// Calculates floor(`self` * `y` / `denominator`) with full precision,
-    /// following the selected `rounding` direction.
+    /// Returns the ceiling of the division of two numbers.
+    ///
+    /// Unlike `(self + rhs - 1) / rhs`, this doesn't overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - numerator of the division.
+    /// * `rhs` - denominator of the division.
+    ///
+    /// # Panics
+    ///
+    /// * If `rhs` is zero.
+    #[must_use]
+    fn ceil_div(self, rhs: Self) -> Self;
+
+    /// Calculates `self` * `y` / `denominator` with full precision, following
+    /// the selected `rounding` direction.
     ///
     /// # Arguments
     ///
@@ -32,16 +49,32 @@ pub trait Math {
     /// * `y` - second value to compute the result.
     /// * `denominator` - denominator of the division.
     /// * `rounding` - rounding technique to use in calculation.
+    ///
+    /// # Panics
+    ///
+    /// * If `denominator` is zero.
+    /// * If the result does not fit into `Self`.
     #[must_use]
     fn mul_div(self, y: Self, denominator: Self, rounding: Rounding) -> Self;
-}
 
-/// Enum representing many rounding techniques.
-pub enum Rounding {
-    /// Rounding toward negative infinity.
-    Floor,
-    /// Rounding toward positive infinity.
-    Ceil,
+    /// Non-panicking version of [`Math::mul_div`].
+    ///
+    /// Returns `None` if `denominator` is zero or if the result does not fit
+    /// into `Self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - first value to compute the result.
+    /// * `y` - second value to compute the result.
+    /// * `denominator` - denominator of the division.
+    /// * `rounding` - rounding technique to use in calculation.
+    #[must_use]
+    fn try_mul_div(
+        self,
+        y: Self,
+        denominator: Self,
+        rounding: Rounding,
+    ) -> Option<Self>;
 }
 
 impl Math for U256 {
@@ -166,22 +199,47 @@ impl Math for U256 {
         (self & rhs) + ((self ^ rhs) >> 1)
     }
 
+    fn ceil_div(self, rhs: Self) -> Self {
+        assert!(!rhs.is_zero(), "division by U256::ZERO in `Math::ceil_div`");
+
+        if self.is_zero() {
+            // Guarantees that `self - 1` below doesn't underflow.
+            U256::ZERO
+        } else {
+            (self - uint!(1_U256)) / rhs + uint!(1_U256)
+        }
+    }
+
     fn mul_div(self, y: Self, denominator: Self, rounding: Rounding) -> Self {
         assert!(
             !denominator.is_zero(),
             "division by U256::ZERO in `Math::mul_div`"
         );
 
+        self.try_mul_div(y, denominator, rounding)
+            .expect("should fit into `U256` in `Math::mul_div`")
+    }
+
+    fn try_mul_div(
+        self,
+        y: Self,
+        denominator: Self,
+        rounding: Rounding,
+    ) -> Option<Self> {
+        if denominator.is_zero() {
+            return None;
+        }
+
         let prod = U512::from(self)
             .checked_mul(U512::from(y))
             .expect("should not panic with `U256` * `U256`");
 
         // Adjust for rounding if needed.
-        let adjusted = match rounding {
-            Rounding::Floor => prod, // No adjustment for Rounding::Floor
-            Rounding::Ceil => prod
-                .checked_add(U512::from(denominator) - U512::from(1))
-                .expect("should not exceed `U512`"),
+        let adjusted = if rounding.rounds_up() {
+            prod.checked_add(U512::from(denominator) - U512::from(1))
+                .expect("should not exceed `U512`")
+        } else {
+            prod
         };
 
         let result = adjusted
@@ -189,9 +247,9 @@ impl Math for U256 {
             .expect("should not panic with `U512` / `U512`");
 
         if result > U512::from(U256::MAX) {
-            panic!("should fit into `U256` in `Math::mul_div`");
+            None
         } else {
-            U256::from(result)
+            Some(U256::from(result))
         }
     }
 }
@@ -203,7 +261,7 @@ mod tests {
         uint, U256, U512,
     };
 
-    use crate::utils::math::alloy::{Math, Rounding};
+    use crate::utils::math::{alloy::Math, Rounding};
 
     #[test]
     fn check_sqrt() {
@@ -222,6 +280,39 @@ mod tests {
         });
     }
 
+    #[test]
+    fn check_average_does_not_overflow() {
+        assert_eq!(U256::MAX, U256::MAX.average(U256::MAX));
+        assert_eq!(
+            U256::MAX - uint!(1_U256),
+            U256::MAX.average(U256::MAX - uint!(2_U256))
+        );
+    }
+
+    #[test]
+    fn check_ceil_div() {
+        assert_eq!(uint!(4_U256), uint!(7_U256).ceil_div(uint!(2_U256)));
+        assert_eq!(uint!(4_U256), uint!(8_U256).ceil_div(uint!(2_U256)));
+        assert_eq!(U256::ZERO, U256::ZERO.ceil_div(uint!(2_U256)));
+        assert_eq!(uint!(1_U256), U256::MAX.ceil_div(U256::MAX));
+        assert_eq!(U256::MAX, U256::MAX.ceil_div(uint!(1_U256)));
+
+        proptest!(|(x: U256, y: U256)| {
+            prop_assume!(y != U256::ZERO, "division by U256::ZERO in `Math::ceil_div`.");
+            let expected = (U512::from(x) + U512::from(y) - uint!(1_U512)) / U512::from(y);
+            assert_eq!(U512::from(x.ceil_div(y)), expected);
+        });
+    }
+
+    #[test]
+    #[should_panic = "division by U256::ZERO in `Math::ceil_div`"]
+    fn check_ceil_div_panics_when_denominator_is_zero() {
+        proptest!(|(x: U256)| {
+            // This should panic.
+            _ = x.ceil_div(U256::ZERO);
+        });
+    }
+
     #[test]
     fn check_mul_div_rounding_floor() {
         proptest!(|(x: U256, y: U256, denominator: U256)| {
@@ -266,4 +357,74 @@ mod tests {
             _ = x.mul_div(y, U256::from(1), Rounding::Floor);
         })
     }
+
+    #[test]
+    fn check_try_mul_div_matches_mul_div() {
+        proptest!(|(x: U256, y: U256, denominator: U256)| {
+            prop_assume!(denominator != U256::ZERO, "division by U256::ZERO in `Math::mul_div`.");
+            prop_assume!(denominator > y, "result should fit into `U256` in `Math::mul_div`.");
+            assert_eq!(
+                x.try_mul_div(y, denominator, Rounding::Floor),
+                Some(x.mul_div(y, denominator, Rounding::Floor))
+            );
+            assert_eq!(
+                x.try_mul_div(y, denominator, Rounding::Ceil),
+                Some(x.mul_div(y, denominator, Rounding::Ceil))
+            );
+        })
+    }
+
+    #[test]
+    fn check_mul_div_rounding_modes() {
+        let mul_div = |x: u64, y: u64, denominator: u64, rounding| {
+            U256::from(x).mul_div(
+                U256::from(y),
+                U256::from(denominator),
+                rounding,
+            )
+        };
+
+        // 7 * 3 / 2 = 10.5
+        assert_eq!(mul_div(7, 3, 2, Rounding::Floor), uint!(10_U256));
+        assert_eq!(mul_div(7, 3, 2, Rounding::Trunc), uint!(10_U256));
+        assert_eq!(mul_div(7, 3, 2, Rounding::Ceil), uint!(11_U256));
+        assert_eq!(mul_div(7, 3, 2, Rounding::Expand), uint!(11_U256));
+
+        // 2 * 1 / 3 = 0.66...
+        assert_eq!(mul_div(2, 1, 3, Rounding::Floor), U256::ZERO);
+        assert_eq!(mul_div(2, 1, 3, Rounding::Trunc), U256::ZERO);
+        assert_eq!(mul_div(2, 1, 3, Rounding::Ceil), uint!(1_U256));
+        assert_eq!(mul_div(2, 1, 3, Rounding::Expand), uint!(1_U256));
+
+        // Exact results are not rounded.
+        for rounding in
+            [Rounding::Floor, Rounding::Ceil, Rounding::Trunc, Rounding::Expand]
+        {
+            assert_eq!(mul_div(6, 4, 3, rounding), uint!(8_U256));
+            assert_eq!(mul_div(0, 4, 3, rounding), U256::ZERO);
+        }
+    }
+
+    #[test]
+    fn check_try_mul_div_returns_none_when_denominator_is_zero() {
+        proptest!(|(x: U256, y: U256)| {
+            assert_eq!(x.try_mul_div(y, U256::ZERO, Rounding::Floor), None);
+            assert_eq!(x.try_mul_div(y, U256::ZERO, Rounding::Ceil), None);
+        })
+    }
+
+    #[test]
+    fn check_try_mul_div_returns_none_when_result_overflows() {
+        proptest!(|(x: U256, y: U256)| {
+            prop_assume!(x != U256::ZERO, "Guaranteed `x` for overflow.");
+            prop_assume!(y > U256::MAX / x, "Guaranteed `y` for overflow.");
+            assert_eq!(x.try_mul_div(y, U256::from(1), Rounding::Floor), None);
+        });
+
+        // The largest result still fits.
+        assert_eq!(
+            U256::MAX.try_mul_div(U256::MAX, U256::MAX, Rounding::Ceil),
+            Some(U256::MAX)
+        );
+    }
 }
@@ -1,3 +1,42 @@
 //! Math helpers for `alloy` and Solidity storage types.
 pub mod alloy;
 pub mod storage;
+
+/// Rounding direction of an operation, shared by math helpers and the
+/// contracts using them.
+///
+/// [`Rounding::Floor`] and [`Rounding::Trunc`], as well as
+/// [`Rounding::Ceil`] and [`Rounding::Expand`], only differ for negative
+/// values, so they round unsigned values the same way.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Rounding toward negative infinity.
+    Floor,
+    /// Rounding toward positive infinity.
+    Ceil,
+    /// Rounding toward zero.
+    Trunc,
+    /// Rounding away from zero.
+    Expand,
+}
+
+impl Rounding {
+    /// Returns true if the rounding direction rounds unsigned values up.
+    #[must_use]
+    pub const fn rounds_up(self) -> bool {
+        matches!(self, Rounding::Ceil | Rounding::Expand)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::Rounding;
+
+    #[test]
+    fn rounds_up() {
+        assert!(!Rounding::Floor.rounds_up());
+        assert!(Rounding::Ceil.rounds_up());
+        assert!(!Rounding::Trunc.rounds_up());
+        assert!(Rounding::Expand.rounds_up());
+    }
+}
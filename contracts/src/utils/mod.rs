@@ -1,5 +1,7 @@
 //! Common Smart Contracts utilities.
+pub mod context;
 pub mod cryptography;
+pub mod initializable;
 pub mod introspection;
 pub mod math;
 pub mod metadata;
@@ -8,6 +10,8 @@ pub mod pausable;
 pub mod reentrant_call_handler;
 pub mod structs;
 
+pub use context::Context;
+pub use initializable::Initializable;
 pub use metadata::Metadata;
 pub use pausable::Pausable;
 pub use reentrant_call_handler::ReentrantCallHandler;
@@ -13,6 +13,21 @@
 //! Note that [`Pausable::pause`] and [`Pausable::unpause`] methods are not
 //! exposed by default.
 //! You should expose them manually in your contract's abi.
+//!
+//! Functions meant as a way out during an emergency, e.g. letting holders
+//! withdraw while regular transfers are stopped, should only run while the
+//! contract is paused, with [`Pausable::when_paused`]. The caller is resolved
+//! through the [`Context`](crate::utils::Context) of the contract:
+//!
+//! ```rust,ignore
+//! fn emergency_withdraw(&mut self, to: Address) -> Result<U256, Error> {
+//!     self.pausable.when_paused()?;
+//!     let account = self._msg_sender();
+//!     let balance = self.erc20.balance_of(account);
+//!     self.erc20.transfer_with_sender(account, to, balance)?;
+//!     Ok(balance)
+//! }
+//! ```
 
 use alloc::{vec, vec::Vec};
 
@@ -158,14 +173,70 @@ impl Pausable {
 
 #[cfg(all(test, feature = "std"))]
 mod tests {
-    use alloy_primitives::Address;
-    use motsu::prelude::Contract;
-    use stylus_sdk::prelude::TopLevelStorage;
-
-    use crate::utils::pausable::{Error, Pausable};
+    use alloy_primitives::{uint, Address, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::prelude::*;
+
+    use crate::{
+        token::erc20::{self, Erc20, IErc20},
+        utils::{
+            pausable::{Error, Pausable},
+            Context,
+        },
+    };
 
     unsafe impl TopLevelStorage for Pausable {}
 
+    #[derive(SolidityError, Debug)]
+    enum EmergencyError {
+        Pausable(Error),
+        Erc20(erc20::Error),
+    }
+
+    /// Token letting holders move their whole balance out while paused.
+    #[storage]
+    struct EmergencyToken {
+        pausable: Pausable,
+        erc20: Erc20,
+    }
+
+    unsafe impl TopLevelStorage for EmergencyToken {}
+
+    impl Context for EmergencyToken {}
+
+    #[public]
+    impl EmergencyToken {
+        fn transfer(
+            &mut self,
+            to: Address,
+            value: U256,
+        ) -> Result<bool, EmergencyError> {
+            self.pausable.when_not_paused()?;
+            Ok(self.erc20.transfer(to, value)?)
+        }
+
+        fn emergency_withdraw(
+            &mut self,
+            to: Address,
+        ) -> Result<U256, EmergencyError> {
+            self.pausable.when_paused()?;
+            let account = self._msg_sender();
+            let balance = self.erc20.balance_of(account);
+            self.erc20.transfer_with_sender(account, to, balance)?;
+            Ok(balance)
+        }
+    }
+
+    /// Pauses `contract` if it is unpaused, and unpauses it otherwise.
+    fn toggle_pause(contract: &Contract<EmergencyToken>, account: Address) {
+        let mut contract = contract.sender(account);
+        if contract.pausable.paused() {
+            contract.pausable.unpause().motsu_unwrap();
+        } else {
+            contract.pausable.pause().motsu_unwrap();
+        }
+    }
+
     fn construct_paused(contract: &mut Pausable) {
         contract.paused.set(true);
     }
@@ -271,4 +342,54 @@ mod tests {
         assert!(matches!(result, Err(Error::ExpectedPause(_))));
         assert!(!contract.sender(alice).paused());
     }
+
+    #[motsu::test]
+    fn emergency_withdraw_only_while_paused(
+        contract: Contract<EmergencyToken>,
+        alice: Address,
+        bob: Address,
+    ) {
+        let value = uint!(10_U256);
+        contract.init(alice, |contract| {
+            contract.erc20._mint(alice, value).motsu_unwrap();
+        });
+
+        let err =
+            contract.sender(alice).emergency_withdraw(bob).motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            EmergencyError::Pausable(Error::ExpectedPause(_))
+        ));
+
+        toggle_pause(&contract, alice);
+        let err = contract
+            .sender(alice)
+            .transfer(bob, uint!(1_U256))
+            .motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            EmergencyError::Pausable(Error::EnforcedPause(_))
+        ));
+
+        let withdrawn =
+            contract.sender(alice).emergency_withdraw(bob).motsu_unwrap();
+        assert_eq!(value, withdrawn);
+        assert_eq!(U256::ZERO, contract.sender(alice).erc20.balance_of(alice));
+        assert_eq!(value, contract.sender(alice).erc20.balance_of(bob));
+        contract.assert_emitted(&erc20::Transfer {
+            from: alice,
+            to: bob,
+            value,
+        });
+
+        // Regular transfers resume once unpaused, and emergency ones stop.
+        toggle_pause(&contract, alice);
+        contract.sender(bob).transfer(alice, uint!(1_U256)).motsu_unwrap();
+        let err =
+            contract.sender(bob).emergency_withdraw(alice).motsu_unwrap_err();
+        assert!(matches!(
+            err,
+            EmergencyError::Pausable(Error::ExpectedPause(_))
+        ));
+    }
 }
@@ -0,0 +1,232 @@
+//! Clock used to key checkpoints, as specified in [EIP-6372].
+//!
+//! A [`Clock`] either follows `block.number` or `block.timestamp`. Contracts
+//! keeping historical values should key their [`Trace`] with a single clock,
+//! and expose it through [`IErc6372`].
+//!
+//! [EIP-6372]: https://eips.ethereum.org/EIPS/eip-6372
+use alloc::string::String;
+
+use alloy_primitives::{aliases::U48, U256};
+use stylus_sdk::block;
+
+use super::{ERC5805FutureLookup, Error, Size, Trace};
+
+/// Interface of a contract exposing its clock, as specified in [EIP-6372].
+///
+/// [EIP-6372]: https://eips.ethereum.org/EIPS/eip-6372
+pub trait IErc6372 {
+    /// Returns the current timepoint of the contract's clock.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    fn clock(&self) -> U48;
+
+    /// Returns a machine-readable description of the contract's clock,
+    /// e.g. `mode=blocknumber&from=default`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[allow(non_snake_case)]
+    fn CLOCK_MODE(&self) -> String;
+}
+
+/// Source of the timepoints used as checkpoint keys.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Clock {
+    /// Timepoints are block numbers.
+    #[default]
+    BlockNumber,
+    /// Timepoints are block timestamps, in seconds.
+    Timestamp,
+}
+
+impl Clock {
+    /// Returns the current timepoint of the clock.
+    ///
+    /// Timepoints exceeding 48 bits saturate to [`U48::MAX`].
+    #[must_use]
+    pub fn now(self) -> U48 {
+        let timepoint = match self {
+            Clock::BlockNumber => block::number(),
+            Clock::Timestamp => block::timestamp(),
+        };
+        U48::saturating_from(timepoint)
+    }
+
+    /// Returns the [EIP-6372] description of the clock.
+    ///
+    /// [EIP-6372]: https://eips.ethereum.org/EIPS/eip-6372
+    #[must_use]
+    pub const fn mode(self) -> &'static str {
+        match self {
+            Clock::BlockNumber => "mode=blocknumber&from=default",
+            Clock::Timestamp => "mode=timestamp",
+        }
+    }
+}
+
+impl<S: Size<Key = U48>> Trace<S> {
+    /// Pushes `value` as the checkpoint of the current timepoint of `clock`.
+    ///
+    /// Returns the previous value and the new value as an ordered pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the checkpoint's state.
+    /// * `clock` - Clock used to key the checkpoints.
+    /// * `value` - Checkpoint value for the current timepoint.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::CheckpointUnorderedInsertion`] - If checkpoints were
+    ///   previously pushed for later timepoints, e.g. with another clock.
+    pub fn push_now(
+        &mut self,
+        clock: Clock,
+        value: S::Value,
+    ) -> Result<(S::Value, S::Value), Error> {
+        self.push(clock.now(), value)
+    }
+
+    /// Returns the value at the end of the past `timepoint` of `clock`, or
+    /// `S::Value::ZERO` if there is none.
+    ///
+    /// The current timepoint is rejected, since its value can still change
+    /// within the current block.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the checkpoint's state.
+    /// * `clock` - Clock used to key the checkpoints.
+    /// * `timepoint` - Past timepoint to lookup.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::FutureLookup`] - If `timepoint` is not lower than the current
+    ///   timepoint of `clock`.
+    pub fn past_lookup(
+        &self,
+        clock: Clock,
+        timepoint: U256,
+    ) -> Result<S::Value, Error> {
+        let now = clock.now();
+        if timepoint >= U256::from(now) {
+            return Err(ERC5805FutureLookup { timepoint, clock: now }.into());
+        }
+        // Lower than `now`, so fits into 48 bits.
+        Ok(self.upper_lookup_recent(U48::from(timepoint)))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{aliases::U48, uint, Address, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::{block, prelude::*};
+
+    use super::Clock;
+    use crate::utils::structs::checkpoints::{
+        ERC5805FutureLookup, Error, Trace, S208,
+    };
+
+    unsafe impl TopLevelStorage for Trace<S208> {}
+
+    #[public]
+    impl Trace<S208> {}
+
+    #[test]
+    fn clock_modes() {
+        assert_eq!(Clock::default(), Clock::BlockNumber);
+        assert_eq!(Clock::BlockNumber.mode(), "mode=blocknumber&from=default");
+        assert_eq!(Clock::Timestamp.mode(), "mode=timestamp");
+    }
+
+    #[motsu::test]
+    fn clock_now() {
+        assert_eq!(Clock::BlockNumber.now(), U48::from(block::number()));
+        assert_eq!(Clock::Timestamp.now(), U48::from(block::timestamp()));
+    }
+
+    #[motsu::test]
+    fn past_lookup_with_timestamp_clock(
+        checkpoint: Contract<Trace<S208>>,
+        alice: Address,
+    ) {
+        let now = Clock::Timestamp.now();
+        let first = now - uint!(100_U48);
+        let second = now - uint!(50_U48);
+
+        checkpoint
+            .sender(alice)
+            .push(first, uint!(11_U208))
+            .expect("push first");
+        checkpoint
+            .sender(alice)
+            .push(second, uint!(22_U208))
+            .expect("push second");
+
+        let lookup = |timepoint: U48| {
+            checkpoint
+                .sender(alice)
+                .past_lookup(Clock::Timestamp, U256::from(timepoint))
+                .expect("should lookup past timepoint")
+        };
+        assert_eq!(lookup(first - uint!(1_U48)), uint!(0_U208));
+        assert_eq!(lookup(first), uint!(11_U208));
+        assert_eq!(lookup(second - uint!(1_U48)), uint!(11_U208));
+        assert_eq!(lookup(now - uint!(1_U48)), uint!(22_U208));
+        assert_eq!(
+            lookup(now - uint!(1_U48)),
+            checkpoint.sender(alice).upper_lookup(now - uint!(1_U48))
+        );
+
+        let err = checkpoint
+            .sender(alice)
+            .past_lookup(Clock::Timestamp, U256::from(now))
+            .expect_err("should reject current timepoint");
+        assert!(matches!(
+            err,
+            Error::FutureLookup(ERC5805FutureLookup { timepoint, clock })
+                if timepoint == U256::from(now) && clock == now
+        ));
+    }
+
+    #[motsu::test]
+    fn past_lookup_with_block_number_clock(
+        checkpoint: Contract<Trace<S208>>,
+        alice: Address,
+    ) {
+        let now = Clock::BlockNumber.now();
+
+        checkpoint
+            .sender(alice)
+            .push_now(Clock::BlockNumber, uint!(11_U208))
+            .expect("push at current block");
+
+        assert_eq!(
+            checkpoint.sender(alice).latest_checkpoint(),
+            Some((now, uint!(11_U208)))
+        );
+        assert_eq!(checkpoint.sender(alice).upper_lookup(now), uint!(11_U208));
+
+        // The value of the current block is not final yet.
+        let err = checkpoint
+            .sender(alice)
+            .past_lookup(Clock::BlockNumber, U256::from(now))
+            .expect_err("should reject current timepoint");
+        assert!(matches!(
+            err,
+            Error::FutureLookup(ERC5805FutureLookup { timepoint, clock })
+                if timepoint == U256::from(now) && clock == now
+        ));
+
+        let err = checkpoint
+            .sender(alice)
+            .past_lookup(Clock::BlockNumber, U256::MAX)
+            .expect_err("should reject future timepoint");
+        assert!(matches!(err, Error::FutureLookup(_)));
+    }
+}
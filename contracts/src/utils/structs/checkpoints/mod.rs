@@ -7,11 +7,16 @@
 //! define sizes for key and value.
 //! Then store a new checkpoint for the current
 //! transaction block using the [`Trace::push`] function.
+//!
+//! Traces keyed by 48-bit timepoints ([`S208`]) can use a [`Clock`] to key
+//! checkpoints by either block number or timestamp.
+pub mod clock;
 pub mod generic_size;
 
 use alloc::{vec, vec::Vec};
 
 use alloy_primitives::{uint, U256, U32};
+pub use clock::{Clock, IErc6372};
 pub use generic_size::{Size, S160, S208, S224};
 pub use sol::*;
 use stylus_sdk::{
@@ -33,6 +38,15 @@ mod sol {
         /// A value was attempted to be inserted into a past checkpoint.
         #[derive(Debug)]
         error CheckpointUnorderedInsertion();
+
+        /// Lookup of a `timepoint` that is not yet in the past, relative to
+        /// the current `clock`.
+        ///
+        /// * `timepoint` - Requested timepoint.
+        /// * `clock` - Current timepoint of the clock.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error ERC5805FutureLookup(uint256 timepoint, uint48 clock);
     }
 }
 
@@ -41,6 +55,9 @@ mod sol {
 pub enum Error {
     /// A value was attempted to be inserted into a past checkpoint.
     CheckpointUnorderedInsertion(CheckpointUnorderedInsertion),
+    /// Lookup of a timepoint that is not yet in the past, relative to the
+    /// current clock.
+    FutureLookup(ERC5805FutureLookup),
 }
 
 impl MethodError for Error {
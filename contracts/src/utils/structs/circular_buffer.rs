@@ -0,0 +1,296 @@
+//! Contract module for a fixed-size buffer of `B256` values, overwriting the
+//! oldest value once full, e.g. to keep a bounded history of oracle
+//! observations or rate limiter timestamps.
+//!
+//! The [`CircularBuffer`] must be set up with [`CircularBuffer::setup`]
+//! before values are pushed. Only the last [`CircularBuffer::length`] values
+//! pushed are kept, and can be looked up most recent first with
+//! [`CircularBuffer::last`].
+use alloc::{vec, vec::Vec};
+
+use alloy_primitives::{uint, B256, U256};
+pub use sol::*;
+use stylus_sdk::{
+    call::MethodError,
+    prelude::*,
+    storage::{StorageB256, StorageU256, StorageVec},
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod sol {
+    use alloy_sol_macro::sol;
+
+    sol! {
+        /// The buffer holds no value at the requested position.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error EmptyBuffer();
+
+        /// The size of the buffer is invalid, e.g. zero.
+        #[derive(Debug)]
+        #[allow(missing_docs)]
+        error InvalidBufferSize();
+    }
+}
+
+/// An error that occurred while calling the [`CircularBuffer`] contract.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The buffer holds no value at the requested position.
+    EmptyBuffer(EmptyBuffer),
+    /// The size of the buffer is invalid, e.g. zero.
+    InvalidBufferSize(InvalidBufferSize),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// State of a [`CircularBuffer`] contract.
+#[storage]
+pub struct CircularBuffer {
+    /// Number of values pushed since the last [`CircularBuffer::clear`].
+    pub(crate) count: StorageU256,
+    /// Values of the buffer, the value pushed in position `count` being
+    /// stored at index `count % data.len()`.
+    pub(crate) data: StorageVec<StorageB256>,
+}
+
+impl CircularBuffer {
+    /// Sets the buffer up to hold `size` values, and clears it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the buffer's state.
+    /// * `size` - Maximum number of values held by the buffer.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidBufferSize`] - If `size` is zero, or exceeds the
+    ///   addressable size of the buffer.
+    pub fn setup(&mut self, size: U256) -> Result<(), Error> {
+        let size = usize::try_from(size)
+            .ok()
+            .filter(|size| *size > 0)
+            .ok_or(InvalidBufferSize {})?;
+
+        self.clear();
+        // SAFETY: Values left over from a previous setup are never read,
+        // since only the values pushed after `clear` are counted.
+        unsafe {
+            self.data.set_len(size);
+        }
+        Ok(())
+    }
+
+    /// Clears the buffer, keeping its size.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the buffer's state.
+    pub fn clear(&mut self) {
+        self.count.set(U256::ZERO);
+    }
+
+    /// Pushes `value` into the buffer, overwriting the oldest value if the
+    /// buffer is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the buffer's state.
+    /// * `value` - Value to push.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::InvalidBufferSize`] - If the buffer has not been set up.
+    ///
+    /// # Panics
+    ///
+    /// * The function should not panic in a regular way.
+    pub fn push(&mut self, value: B256) -> Result<(), Error> {
+        let length = self.length();
+        if length.is_zero() {
+            return Err(InvalidBufferSize {}.into());
+        }
+
+        let count = self.count.get();
+        self.data
+            .setter(count % length)
+            .expect("index should be lower than the buffer length")
+            .set(value);
+        // Overflow not possible: fewer than `U256::MAX` values can be pushed.
+        self.count.set(count + uint!(1_U256));
+        Ok(())
+    }
+
+    /// Returns the number of values held by the buffer, at most
+    /// [`Self::length`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the buffer's state.
+    #[must_use]
+    pub fn count(&self) -> U256 {
+        self.count.get().min(self.length())
+    }
+
+    /// Returns the maximum number of values held by the buffer, zero if it
+    /// has not been set up.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the buffer's state.
+    #[must_use]
+    pub fn length(&self) -> U256 {
+        U256::from(self.data.len())
+    }
+
+    /// Returns the `i`-th most recent value held by the buffer, the last
+    /// value pushed being at `i = 0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the buffer's state.
+    /// * `i` - Position of the value, counting from the most recent one.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::EmptyBuffer`] - If the buffer holds no more than `i` values.
+    ///
+    /// # Panics
+    ///
+    /// * The function should not panic in a regular way.
+    pub fn last(&self, i: U256) -> Result<B256, Error> {
+        if i >= self.count() {
+            return Err(EmptyBuffer {}.into());
+        }
+
+        // Underflow not possible: `i` is lower than `count`.
+        let position = self.count.get() - i - uint!(1_U256);
+        Ok(self
+            .data
+            .get(position % self.length())
+            .expect("index should be lower than the buffer length"))
+    }
+
+    /// Returns true if `value` is held by the buffer, and false otherwise.
+    ///
+    /// This has O(n) time complexity, n being [`Self::count`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the buffer's state.
+    /// * `value` - Value to look up.
+    #[must_use]
+    pub fn includes(&self, value: B256) -> bool {
+        let count: usize = self.count().to();
+        (0..count).any(|index| self.data.get(index) == Some(value))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{uint, Address, B256, U256};
+    use motsu::prelude::*;
+    use stylus_sdk::prelude::{public, TopLevelStorage};
+
+    use super::{CircularBuffer, Error};
+
+    unsafe impl TopLevelStorage for CircularBuffer {}
+
+    #[public]
+    impl CircularBuffer {}
+
+    fn value(n: u8) -> B256 {
+        B256::with_last_byte(n)
+    }
+
+    #[motsu::test]
+    fn push_wraps_around(contract: Contract<CircularBuffer>, alice: Address) {
+        let mut buffer = contract.sender(alice);
+        buffer.setup(uint!(3_U256)).motsu_unwrap();
+        assert_eq!(uint!(3_U256), buffer.length());
+        assert_eq!(U256::ZERO, buffer.count());
+
+        for n in 1..=5 {
+            buffer.push(value(n)).motsu_unwrap();
+        }
+
+        assert_eq!(uint!(3_U256), buffer.count());
+        assert_eq!(uint!(5_U256), buffer.count.get());
+        // Values `4` and `5` overwrote the oldest values `1` and `2`.
+        assert_eq!(Some(value(4)), buffer.data.get(0));
+        assert_eq!(Some(value(5)), buffer.data.get(1));
+        assert_eq!(Some(value(3)), buffer.data.get(2));
+    }
+
+    #[motsu::test]
+    fn last_returns_most_recent_first(
+        contract: Contract<CircularBuffer>,
+        alice: Address,
+    ) {
+        let mut buffer = contract.sender(alice);
+        buffer.setup(uint!(3_U256)).motsu_unwrap();
+
+        buffer.push(value(1)).motsu_unwrap();
+        buffer.push(value(2)).motsu_unwrap();
+        assert_eq!(value(2), buffer.last(U256::ZERO).motsu_unwrap());
+        assert_eq!(value(1), buffer.last(uint!(1_U256)).motsu_unwrap());
+        let err = buffer.last(uint!(2_U256)).motsu_unwrap_err();
+        assert!(matches!(err, Error::EmptyBuffer(_)));
+
+        for n in 3..=7 {
+            buffer.push(value(n)).motsu_unwrap();
+        }
+        assert_eq!(value(7), buffer.last(U256::ZERO).motsu_unwrap());
+        assert_eq!(value(6), buffer.last(uint!(1_U256)).motsu_unwrap());
+        assert_eq!(value(5), buffer.last(uint!(2_U256)).motsu_unwrap());
+        let err = buffer.last(uint!(3_U256)).motsu_unwrap_err();
+        assert!(matches!(err, Error::EmptyBuffer(_)));
+    }
+
+    #[motsu::test]
+    fn includes_forgets_overwritten_values(
+        contract: Contract<CircularBuffer>,
+        alice: Address,
+    ) {
+        let mut buffer = contract.sender(alice);
+        buffer.setup(uint!(2_U256)).motsu_unwrap();
+        assert!(!buffer.includes(B256::ZERO));
+
+        buffer.push(value(1)).motsu_unwrap();
+        buffer.push(value(2)).motsu_unwrap();
+        assert!(buffer.includes(value(1)));
+        assert!(buffer.includes(value(2)));
+
+        buffer.push(value(3)).motsu_unwrap();
+        assert!(!buffer.includes(value(1)));
+        assert!(buffer.includes(value(2)));
+        assert!(buffer.includes(value(3)));
+
+        // Values held before a new setup are no longer included.
+        buffer.setup(uint!(4_U256)).motsu_unwrap();
+        assert_eq!(U256::ZERO, buffer.count());
+        assert!(!buffer.includes(value(3)));
+        let err = buffer.last(U256::ZERO).motsu_unwrap_err();
+        assert!(matches!(err, Error::EmptyBuffer(_)));
+    }
+
+    #[motsu::test]
+    fn error_when_buffer_size_is_invalid(
+        contract: Contract<CircularBuffer>,
+        alice: Address,
+    ) {
+        let mut buffer = contract.sender(alice);
+
+        let err = buffer.push(value(1)).motsu_unwrap_err();
+        assert!(matches!(err, Error::InvalidBufferSize(_)));
+
+        let err = buffer.setup(U256::ZERO).motsu_unwrap_err();
+        assert!(matches!(err, Error::InvalidBufferSize(_)));
+        let err = buffer.setup(U256::MAX).motsu_unwrap_err();
+        assert!(matches!(err, Error::InvalidBufferSize(_)));
+    }
+}
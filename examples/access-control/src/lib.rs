@@ -25,8 +25,7 @@ struct AccessControlExample {
     access: AccessControl,
 }
 
-pub const TRANSFER_ROLE: [u8; 32] =
-    keccak_const::Keccak256::new().update(b"TRANSFER_ROLE").finalize();
+pub const TRANSFER_ROLE: [u8; 32] = AccessControl::role_id("TRANSFER_ROLE");
 
 #[public]
 #[inherit(Erc20, AccessControl)]
@@ -3,9 +3,9 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 
-use alloy_primitives::{Address, B256};
+use alloy_primitives::{Address, B256, U256};
 use openzeppelin_stylus::utils::cryptography::ecdsa;
-use stylus_sdk::prelude::*;
+use stylus_sdk::{abi::Bytes, prelude::*};
 
 #[entrypoint]
 #[storage]
@@ -22,4 +22,51 @@ impl ECDSAExample {
     ) -> Result<Address, ecdsa::Error> {
         ecdsa::recover(self, hash, v, r, s)
     }
+
+    fn try_recover(
+        &mut self,
+        hash: B256,
+        signature: Bytes,
+    ) -> Result<Address, ecdsa::Error> {
+        ecdsa::try_recover(self, hash, &signature)
+    }
+
+    fn verify_batch(
+        &mut self,
+        hashes: Vec<B256>,
+        signatures: Vec<Bytes>,
+        signers: Vec<Address>,
+    ) -> bool {
+        let batch = batch(&hashes, &signatures, &signers);
+        ecdsa::verify_batch(self, &batch)
+    }
+
+    fn verify_batch_bitmask(
+        &mut self,
+        hashes: Vec<B256>,
+        signatures: Vec<Bytes>,
+        signers: Vec<Address>,
+    ) -> U256 {
+        let batch = batch(&hashes, &signatures, &signers);
+        ecdsa::verify_batch_bitmask(self, &batch)
+    }
+}
+
+fn batch<'a>(
+    hashes: &[B256],
+    signatures: &'a [Bytes],
+    signers: &[Address],
+) -> Vec<(B256, &'a [u8], Address)> {
+    assert!(
+        hashes.len() == signatures.len() && hashes.len() == signers.len(),
+        "should pass as many hashes, signatures and signers"
+    );
+    hashes
+        .iter()
+        .zip(signatures)
+        .zip(signers)
+        .map(|((&hash, signature), &signer)| {
+            (hash, signature.as_slice(), signer)
+        })
+        .collect()
 }
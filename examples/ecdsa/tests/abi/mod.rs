@@ -6,8 +6,15 @@ sol!(
    contract ECDSA {
         error ECDSAInvalidSignature();
         error ECDSAInvalidSignatureS(bytes32 s);
+        error ECDSAInvalidSignatureLength(uint256 length);
 
         #[derive(Debug)]
         function recover(bytes32 hash, uint8 v, bytes32 r, bytes32 s) internal pure returns (address recovered);
+        #[derive(Debug)]
+        function tryRecover(bytes32 hash, bytes signature) internal pure returns (address recovered);
+        #[derive(Debug)]
+        function verifyBatch(bytes32[] hashes, bytes[] signatures, address[] signers) external returns (bool verified);
+        #[derive(Debug)]
+        function verifyBatchBitmask(bytes32[] hashes, bytes[] signatures, address[] signers) external returns (uint256 bitmask);
     }
 );
@@ -176,3 +176,186 @@ async fn error_when_higher_s(alice: Account) -> Result<()> {
 
     Ok(())
 }
+
+fn signature(r: B256, s: B256, v: u8) -> Vec<u8> {
+    [r.as_slice(), s.as_slice(), &[v]].concat()
+}
+
+#[e2e::test]
+async fn try_recover_works(alice: Account) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = ECDSA::new(contract_addr, &alice.wallet);
+
+    let ECDSA::tryRecoverReturn { recovered } =
+        contract.tryRecover(HASH, signature(R, S, V).into()).call().await?;
+
+    assert_eq!(ADDRESS, recovered);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn try_recover_works_with_compact_signature(
+    alice: Account,
+) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = ECDSA::new(contract_addr, &alice.wallet);
+
+    // `V` is 28, so the y parity bit is set.
+    let mut y_parity_and_s = S;
+    y_parity_and_s[0] |= 0x80;
+    let compact = [R.as_slice(), y_parity_and_s.as_slice()].concat();
+
+    let ECDSA::tryRecoverReturn { recovered } =
+        contract.tryRecover(HASH, compact.into()).call().await?;
+
+    assert_eq!(ADDRESS, recovered);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn try_recover_rejects_invalid_length(alice: Account) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = ECDSA::new(contract_addr, &alice.wallet);
+
+    let mut invalid = signature(R, S, V);
+    invalid.push(0);
+
+    let err = contract
+        .tryRecover(HASH, invalid.into())
+        .call()
+        .await
+        .expect_err("should return `ECDSAInvalidSignatureLength`");
+
+    assert!(err.reverted_with(ECDSA::ECDSAInvalidSignatureLength {
+        length: uint!(66_U256)
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn try_recover_rejects_higher_s(alice: Account) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = ECDSA::new(contract_addr, &alice.wallet);
+
+    let higher_s = SIGNATURE_S_UPPER_BOUND + uint!(1_U256);
+    let higher_s = B256::from_slice(&higher_s.to_be_bytes_vec());
+
+    let err = contract
+        .tryRecover(HASH, signature(R, higher_s, V).into())
+        .call()
+        .await
+        .expect_err("should return `ECDSAInvalidSignatureS`");
+
+    assert!(err.reverted_with(ECDSA::ECDSAInvalidSignatureS { s: higher_s }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn compact_and_full_signatures_recover_same_signer(
+    alice: Account,
+) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = ECDSA::new(contract_addr, &alice.wallet);
+
+    let signed = alice.sign_hash(&HASH).await;
+    let r: B256 = signed.r().into();
+    let s: B256 = signed.s().into();
+    let v = signed.v() as u8;
+
+    let full = signature(r, s, v + 27);
+    let mut y_parity_and_s = s;
+    y_parity_and_s[0] |= v << 7;
+    let compact = [r.as_slice(), y_parity_and_s.as_slice()].concat();
+
+    let ECDSA::tryRecoverReturn { recovered: from_full } =
+        contract.tryRecover(HASH, full.into()).call().await?;
+    let ECDSA::tryRecoverReturn { recovered: from_compact } =
+        contract.tryRecover(HASH, compact.into()).call().await?;
+
+    assert_eq!(alice.address(), from_full);
+    assert_eq!(from_full, from_compact);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn verify_batch_works(alice: Account, bob: Account) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = ECDSA::new(contract_addr, &alice.wallet);
+
+    let other_hash = b256!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+    );
+    let signed = bob.sign_hash(&other_hash).await;
+    let bob_signature =
+        signature(signed.r().into(), signed.s().into(), signed.v() as u8 + 27);
+
+    let ECDSA::verifyBatchReturn { verified } = contract
+        .verifyBatch(
+            vec![HASH, other_hash],
+            vec![signature(R, S, V).into(), bob_signature.into()],
+            vec![ADDRESS, bob.address()],
+        )
+        .call()
+        .await?;
+
+    assert!(verified);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn verify_batch_rejects_bad_signature(alice: Account) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = ECDSA::new(contract_addr, &alice.wallet);
+
+    let ECDSA::verifyBatchReturn { verified } = contract
+        .verifyBatch(
+            vec![HASH, HASH, HASH],
+            vec![
+                signature(R, S, V).into(),
+                signature(R, S, V).into(),
+                signature(R, S, V).into(),
+            ],
+            vec![ADDRESS, alice.address(), ADDRESS],
+        )
+        .call()
+        .await?;
+
+    assert!(!verified);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn verify_batch_bitmask_reports_each_signature(
+    alice: Account,
+) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = ECDSA::new(contract_addr, &alice.wallet);
+
+    let mut invalid_length = signature(R, S, V);
+    invalid_length.push(0);
+
+    let ECDSA::verifyBatchBitmaskReturn { bitmask } = contract
+        .verifyBatchBitmask(
+            vec![HASH, HASH, HASH, HASH],
+            vec![
+                signature(R, S, V).into(),
+                signature(R, S, V).into(),
+                invalid_length.into(),
+                signature(R, S, V).into(),
+            ],
+            vec![ADDRESS, alice.address(), ADDRESS, ADDRESS],
+        )
+        .call()
+        .await?;
+
+    assert_eq!(uint!(0b1001_U256), bitmask);
+
+    Ok(())
+}
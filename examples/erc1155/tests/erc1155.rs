@@ -594,6 +594,23 @@ async fn error_when_invalid_operator_approval_for_all(
     Ok(())
 }
 
+#[e2e::test]
+async fn error_when_self_approval_for_all(alice: Account) -> eyre::Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = Erc1155::new(contract_addr, &alice.wallet);
+
+    let alice_addr = alice.address();
+
+    let err = send!(contract.setApprovalForAll(alice_addr, true))
+        .expect_err("should return `ERC1155InvalidOperator`");
+
+    assert!(err.reverted_with(Erc1155::ERC1155InvalidOperator {
+        operator: alice_addr
+    }));
+
+    Ok(())
+}
+
 #[e2e::test]
 async fn is_approved_for_all_zero_address(alice: Account) -> eyre::Result<()> {
     let contract_addr = alice.as_deployer().deploy().await?.address()?;
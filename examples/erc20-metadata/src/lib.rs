@@ -0,0 +1,37 @@
+#![cfg_attr(not(test), no_main)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use alloy_primitives::FixedBytes;
+use openzeppelin_stylus::{
+    token::erc20::{extensions::Erc20Metadata, Erc20},
+    utils::introspection::erc165::IErc165,
+};
+use stylus_sdk::{prelude::*, storage::StorageU8};
+
+#[entrypoint]
+#[storage]
+struct Erc20MetadataExample {
+    #[borrow]
+    erc20: Erc20,
+    #[borrow]
+    metadata: Erc20Metadata,
+    decimals: StorageU8,
+}
+
+#[public]
+#[inherit(Erc20, Erc20Metadata)]
+impl Erc20MetadataExample {
+    // Overrides the default [`Metadata::decimals`] with the value set at
+    // construction, so that the same wasm can deploy tokens with different
+    // decimals.
+    fn decimals(&self) -> u8 {
+        self.decimals.get().to::<u8>()
+    }
+
+    fn supports_interface(interface_id: FixedBytes<4>) -> bool {
+        Erc20::supports_interface(interface_id)
+            || Erc20Metadata::supports_interface(interface_id)
+    }
+}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract Erc20 {
+        function name() external view returns (string name);
+        function symbol() external view returns (string symbol);
+        function decimals() external view returns (uint8 decimals);
+        function totalSupply() external view returns (uint256 totalSupply);
+        function supportsInterface(bytes4 interface_id) external view returns (bool supportsInterface);
+    }
+);
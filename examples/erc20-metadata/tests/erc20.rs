@@ -0,0 +1,99 @@
+#![cfg(feature = "e2e")]
+
+use abi::Erc20;
+use alloy::{primitives::U256, sol};
+use e2e::{Account, ReceiptExt};
+use eyre::Result;
+
+use crate::Erc20MetadataExample::constructorCall;
+
+mod abi;
+
+sol!("src/constructor.sol");
+
+fn ctr(name: &str, symbol: &str, decimals: u8) -> constructorCall {
+    constructorCall {
+        name_: name.to_owned(),
+        symbol_: symbol.to_owned(),
+        decimals_: decimals,
+    }
+}
+
+// ============================================================================
+// Integration Tests: ERC-20 Metadata Extension
+// ============================================================================
+
+#[e2e::test]
+async fn constructs(alice: Account) -> Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr("Test Token", "TTK", 6))
+        .deploy()
+        .await?
+        .address()?;
+    let contract = Erc20::new(contract_addr, &alice.wallet);
+
+    let Erc20::nameReturn { name } = contract.name().call().await?;
+    let Erc20::symbolReturn { symbol } = contract.symbol().call().await?;
+    let Erc20::decimalsReturn { decimals } = contract.decimals().call().await?;
+    let Erc20::totalSupplyReturn { totalSupply: total_supply } =
+        contract.totalSupply().call().await?;
+
+    assert_eq!("Test Token", name);
+    assert_eq!("TTK", symbol);
+    assert_eq!(6, decimals);
+    assert_eq!(U256::ZERO, total_supply);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn constructs_distinct_tokens_from_same_wasm(
+    alice: Account,
+) -> Result<()> {
+    let first_addr = alice
+        .as_deployer()
+        .with_constructor(ctr("First Token", "FST", 18))
+        .deploy()
+        .await?
+        .address()?;
+    let second_addr = alice
+        .as_deployer()
+        .with_constructor(ctr("Second Token", "SND", 6))
+        .deploy()
+        .await?
+        .address()?;
+    let first = Erc20::new(first_addr, &alice.wallet);
+    let second = Erc20::new(second_addr, &alice.wallet);
+
+    assert_eq!("First Token", first.name().call().await?.name);
+    assert_eq!("FST", first.symbol().call().await?.symbol);
+    assert_eq!(18, first.decimals().call().await?.decimals);
+    assert_eq!("Second Token", second.name().call().await?.name);
+    assert_eq!("SND", second.symbol().call().await?.symbol);
+    assert_eq!(6, second.decimals().call().await?.decimals);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn supports_interface(alice: Account) -> Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr("Test Token", "TTK", 18))
+        .deploy()
+        .await?
+        .address()?;
+    let contract = Erc20::new(contract_addr, &alice.wallet);
+
+    let erc20_metadata_interface_id: u32 = 0xa219a025;
+    let supports_interface = contract
+        .supportsInterface(erc20_metadata_interface_id.into())
+        .call()
+        .await?
+        .supportsInterface;
+
+    assert!(supports_interface);
+
+    Ok(())
+}
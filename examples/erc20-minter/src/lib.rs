@@ -0,0 +1,39 @@
+#![cfg_attr(not(test), no_main)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use alloy_primitives::{Address, U256};
+use openzeppelin_stylus::{
+    access::{
+        control,
+        roles::{IMinterRole, MinterRole},
+    },
+    token::erc20::{self, Erc20},
+};
+use stylus_sdk::prelude::*;
+
+#[derive(SolidityError, Debug)]
+enum Error {
+    AccessControl(control::Error),
+    Erc20(erc20::Error),
+}
+
+#[entrypoint]
+#[storage]
+struct Erc20MinterExample {
+    #[borrow]
+    erc20: Erc20,
+    #[borrow]
+    minter: MinterRole,
+}
+
+#[public]
+#[inherit(Erc20, MinterRole)]
+impl Erc20MinterExample {
+    fn mint(&mut self, account: Address, value: U256) -> Result<(), Error> {
+        self.minter.only_minter()?;
+        self.erc20._mint(account, value)?;
+        Ok(())
+    }
+}
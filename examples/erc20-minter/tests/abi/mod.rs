@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract Erc20Minter {
+        function balanceOf(address account) external view returns (uint256 balance);
+        function totalSupply() external view returns (uint256 totalSupply);
+        function mint(address account, uint256 value) external;
+
+        function isMinter(address account) external view returns (bool isMinter);
+        function onlyMinter() external view;
+        function grantMinter(address account) external;
+        function revokeMinter(address account) external;
+        function renounceMinter() external;
+
+        error AccessControlUnauthorizedAccount(address account, bytes32 neededRole);
+
+        #[derive(Debug, PartialEq)]
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        #[derive(Debug, PartialEq)]
+        event RoleGranted(bytes32 indexed role, address indexed account, address indexed sender);
+        #[derive(Debug, PartialEq)]
+        event RoleRevoked(bytes32 indexed role, address indexed account, address indexed sender);
+        #[derive(Debug, PartialEq)]
+        event LastMinterRemoved(address indexed account);
+    }
+);
@@ -0,0 +1,132 @@
+#![cfg(feature = "e2e")]
+
+use abi::Erc20Minter::{
+    self, AccessControlUnauthorizedAccount, LastMinterRemoved, RoleGranted,
+    RoleRevoked, Transfer,
+};
+use alloy::primitives::{uint, Address, U256};
+use e2e::{receipt, send, Account, EventExt, ReceiptExt, Revert};
+use eyre::Result;
+use openzeppelin_stylus::access::roles::MINTER_ROLE;
+
+mod abi;
+
+// ============================================================================
+// Integration Tests: Erc20 with MinterRole
+// ============================================================================
+
+#[e2e::test]
+async fn constructs(alice: Account) -> Result<()> {
+    let alice_addr = alice.address();
+    let receipt = alice.as_deployer().deploy().await?;
+    let contract = Erc20Minter::new(receipt.address()?, &alice.wallet);
+
+    assert!(receipt.emits(RoleGranted {
+        role: MINTER_ROLE.into(),
+        account: alice_addr,
+        sender: alice_addr
+    }));
+
+    let Erc20Minter::isMinterReturn { isMinter } =
+        contract.isMinter(alice_addr).call().await?;
+    assert!(isMinter);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn minter_mints(alice: Account, bob: Account) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = Erc20Minter::new(contract_addr, &alice.wallet);
+    let bob_addr = bob.address();
+    let value = uint!(10_U256);
+
+    let receipt = receipt!(contract.mint(bob_addr, value))?;
+    assert!(receipt.emits(Transfer {
+        from: Address::ZERO,
+        to: bob_addr,
+        value
+    }));
+
+    let Erc20Minter::balanceOfReturn { balance } =
+        contract.balanceOf(bob_addr).call().await?;
+    assert_eq!(value, balance);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_non_minter_mints(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = Erc20Minter::new(contract_addr, &bob.wallet);
+    let bob_addr = bob.address();
+
+    let err = send!(contract.mint(bob_addr, uint!(10_U256)))
+        .expect_err("should not mint when not a minter");
+    assert!(err.reverted_with(AccessControlUnauthorizedAccount {
+        account: bob_addr,
+        neededRole: MINTER_ROLE.into()
+    }));
+
+    let Erc20Minter::totalSupplyReturn { totalSupply } =
+        contract.totalSupply().call().await?;
+    assert_eq!(U256::ZERO, totalSupply);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn admin_grants_and_revokes_minter(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract_alice = Erc20Minter::new(contract_addr, &alice.wallet);
+    let contract_bob = Erc20Minter::new(contract_addr, &bob.wallet);
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+    let value = uint!(1_U256);
+
+    let receipt = receipt!(contract_alice.grantMinter(bob_addr))?;
+    assert!(receipt.emits(RoleGranted {
+        role: MINTER_ROLE.into(),
+        account: bob_addr,
+        sender: alice_addr
+    }));
+    let _ = receipt!(contract_bob.mint(bob_addr, value))?;
+
+    let receipt = receipt!(contract_alice.revokeMinter(bob_addr))?;
+    assert!(receipt.emits(RoleRevoked {
+        role: MINTER_ROLE.into(),
+        account: bob_addr,
+        sender: alice_addr
+    }));
+
+    let err = send!(contract_bob.mint(bob_addr, value))
+        .expect_err("should not mint after the minter role is revoked");
+    assert!(err.reverted_with(AccessControlUnauthorizedAccount {
+        account: bob_addr,
+        neededRole: MINTER_ROLE.into()
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn last_minter_renounces(alice: Account) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = Erc20Minter::new(contract_addr, &alice.wallet);
+    let alice_addr = alice.address();
+
+    let receipt = receipt!(contract.renounceMinter())?;
+    assert!(receipt.emits(LastMinterRemoved { account: alice_addr }));
+
+    let Erc20Minter::isMinterReturn { isMinter } =
+        contract.isMinter(alice_addr).call().await?;
+    assert!(!isMinter);
+
+    Ok(())
+}
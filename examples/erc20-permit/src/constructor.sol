@@ -2,11 +2,32 @@
 pragma solidity ^0.8.21;
 
 contract Erc20PermitExample {
+    bytes32 private constant TYPE_HASH =
+        keccak256(
+            "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+        );
+
     mapping(address account => uint256) private _balances;
     mapping(address account => mapping(address spender => uint256))
         private _allowances;
     uint256 private _totalSupply;
     mapping(address account => uint256) _nonces;
 
-    constructor() {}
+    uint256 private _cachedChainId;
+    address private _cachedThis;
+    bytes32 private _cachedDomainSeparator;
+
+    constructor() {
+        _cachedChainId = block.chainid;
+        _cachedThis = address(this);
+        _cachedDomainSeparator = keccak256(
+            abi.encode(
+                TYPE_HASH,
+                keccak256("ERC-20 Permit Example"),
+                keccak256("1"),
+                block.chainid,
+                address(this)
+            )
+        );
+    }
 }
@@ -9,9 +9,12 @@ use openzeppelin_stylus::{
         extensions::{permit, Erc20Permit},
         Erc20,
     },
-    utils::{cryptography::eip712::IEip712, nonces::Nonces},
+    utils::{
+        cryptography::eip712::{DomainSeparatorCache, IEip712},
+        nonces::Nonces,
+    },
 };
-use stylus_sdk::prelude::*;
+use stylus_sdk::{abi::Bytes, prelude::*};
 
 #[entrypoint]
 #[storage]
@@ -24,12 +27,19 @@ struct Erc20PermitExample {
     erc20_permit: Erc20Permit<Eip712>,
 }
 
+/// Domain separator cached in `constructor.sol`.
 #[storage]
-struct Eip712;
+struct Eip712 {
+    cache: DomainSeparatorCache,
+}
 
 impl IEip712 for Eip712 {
     const NAME: &'static str = "ERC-20 Permit Example";
     const VERSION: &'static str = "1";
+
+    fn domain_separator_v4(&self) -> B256 {
+        self.cache.domain_separator(self)
+    }
 }
 
 #[public]
@@ -67,4 +77,23 @@ impl Erc20PermitExample {
             &mut self.nonces,
         )
     }
+
+    fn permit_with_signature(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        value: U256,
+        deadline: U256,
+        signature: Bytes,
+    ) -> Result<(), permit::Error> {
+        self.erc20_permit.permit_with_signature(
+            owner,
+            spender,
+            value,
+            deadline,
+            &signature,
+            &mut self.erc20,
+            &mut self.nonces,
+        )
+    }
 }
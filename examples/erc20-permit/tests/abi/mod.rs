@@ -15,6 +15,7 @@ sol!(
         function mint(address account, uint256 amount) external;
 
         function permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s) external;
+        function permitWithSignature(address owner, address spender, uint256 value, uint256 deadline, bytes signature) external;
         function nonces(address owner) external view returns (uint256 nonce);
         function DOMAIN_SEPARATOR() external view returns (bytes32 domainSeparator);
 
@@ -26,6 +27,7 @@ sol!(
 
         error ERC2612ExpiredSignature(uint256 deadline);
         error ERC2612InvalidSigner(address signer, address owner);
+        error ECDSAInvalidSignatureS(bytes32 s);
 
         #[derive(Debug, PartialEq)]
         event Transfer(address indexed from, address indexed to, uint256 value);
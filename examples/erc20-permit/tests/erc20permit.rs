@@ -3,6 +3,7 @@
 use abi::Erc20Permit;
 use alloy::{
     primitives::{keccak256, Address, B256, U256},
+    providers::Provider,
     sol,
     sol_types::SolType,
 };
@@ -22,6 +23,15 @@ const PERMIT_TYPEHASH: [u8; 32] =
         .update(b"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)")
         .finalize();
 
+const TYPE_HASH: [u8; 32] =
+    keccak_const::Keccak256::new()
+        .update(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)")
+        .finalize();
+
+type DomainSeparatorTuple = sol! {
+    tuple(bytes32, bytes32, bytes32, uint256, address)
+};
+
 type PermitStructHashTuple = sol! {
     tuple(bytes32, address, address, uint256, uint256, uint256)
 };
@@ -72,10 +82,46 @@ fn to_non_eip155_v(v: bool) -> u8 {
     v as u8 + 27
 }
 
+/// Returns the 65 bytes `r || s || v` form of `signature`.
+fn to_bytes(signature: &alloy::signers::Signature) -> Vec<u8> {
+    let r: B256 = signature.r().into();
+    let s: B256 = signature.s().into();
+    [r.as_slice(), s.as_slice(), &[to_non_eip155_v(signature.v())]].concat()
+}
+
+/// Returns the 64 bytes [EIP-2098] compact form of `signature`.
+///
+/// [EIP-2098]: https://eips.ethereum.org/EIPS/eip-2098
+fn to_compact_bytes(signature: &alloy::signers::Signature) -> Vec<u8> {
+    let r: B256 = signature.r().into();
+    let mut y_parity_and_s: B256 = signature.s().into();
+    y_parity_and_s[0] |= (signature.v() as u8) << 7;
+    [r.as_slice(), y_parity_and_s.as_slice()].concat()
+}
+
 // ============================================================================
 // Integration Tests: ERC-20 Permit Extension
 // ============================================================================
 
+#[e2e::test]
+async fn caches_domain_separator_at_construction(alice: Account) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = Erc20Permit::new(contract_addr, &alice.wallet);
+
+    let chain_id = alice.wallet.get_chain_id().await?;
+    let expected = keccak256(DomainSeparatorTuple::abi_encode(&(
+        TYPE_HASH,
+        keccak256("ERC-20 Permit Example"),
+        keccak256("1"),
+        U256::from(chain_id),
+        contract_addr,
+    )));
+
+    assert_eq!(expected, domain_separator!(contract));
+
+    Ok(())
+}
+
 #[e2e::test]
 async fn error_when_expired_deadline_for_permit(
     alice: Account,
@@ -332,3 +378,109 @@ async fn permit_rejects_invalid_signature(
 
     Ok(())
 }
+
+#[e2e::test]
+async fn permit_with_signature_works_with_both_forms(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract_alice = Erc20Permit::new(contract_addr, &alice.wallet);
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+
+    let balance = uint!(10_U256);
+    watch!(contract_alice.mint(alice_addr, balance))?;
+
+    for (nonce, value) in
+        [(U256::ZERO, uint!(3_U256)), (uint!(1_U256), balance)]
+    {
+        let struct_hash = permit_struct_hash(
+            alice_addr,
+            bob_addr,
+            value,
+            nonce,
+            FAIR_DEADLINE,
+        );
+        let typed_data_hash =
+            to_typed_data_hash(domain_separator!(contract_alice), struct_hash);
+        let signature = alice.sign_hash(&typed_data_hash).await;
+
+        let signature = if nonce.is_zero() {
+            to_bytes(&signature)
+        } else {
+            to_compact_bytes(&signature)
+        };
+
+        let receipt = receipt!(contract_alice.permitWithSignature(
+            alice_addr,
+            bob_addr,
+            value,
+            FAIR_DEADLINE,
+            signature.into()
+        ))?;
+
+        assert!(receipt.emits(Erc20Permit::Approval {
+            owner: alice_addr,
+            spender: bob_addr,
+            value,
+        }));
+
+        let Erc20Permit::allowanceReturn { allowance } =
+            contract_alice.allowance(alice_addr, bob_addr).call().await?;
+        assert_eq!(value, allowance);
+    }
+
+    let Erc20Permit::noncesReturn { nonce } =
+        contract_alice.nonces(alice_addr).call().await?;
+    assert_eq!(uint!(2_U256), nonce);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn permit_with_signature_rejects_malleable_signature(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract_alice = Erc20Permit::new(contract_addr, &alice.wallet);
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+
+    let struct_hash = permit_struct_hash(
+        alice_addr,
+        bob_addr,
+        uint!(1_U256),
+        U256::ZERO,
+        FAIR_DEADLINE,
+    );
+    let typed_data_hash =
+        to_typed_data_hash(domain_separator!(contract_alice), struct_hash);
+    let signature = alice.sign_hash(&typed_data_hash).await;
+
+    // Flip `s` to the upper half order and `v` to the other parity.
+    let secp256k1n = uint!(
+        0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141_U256
+    );
+    let high_s = B256::from(secp256k1n - signature.s());
+    let r: B256 = signature.r().into();
+    let malleable =
+        [r.as_slice(), high_s.as_slice(), &[to_non_eip155_v(!signature.v())]]
+            .concat();
+
+    let err = send!(contract_alice.permitWithSignature(
+        alice_addr,
+        bob_addr,
+        uint!(1_U256),
+        FAIR_DEADLINE,
+        malleable.into()
+    ))
+    .expect_err("should return `ECDSAInvalidSignatureS`");
+
+    assert!(
+        err.reverted_with(Erc20Permit::ECDSAInvalidSignatureS { s: high_s })
+    );
+
+    Ok(())
+}
@@ -13,12 +13,26 @@ contract Erc20WrapperExample {
     uint8 private _decimals;
 
     error ERC20InvalidUnderlying(address token);
+    error ERC20WrapperDecimalsMismatch(uint8 underlying_decimals, uint8 decimals);
 
     constructor(address underlyingToken_, uint8 decimals_) {
         if (underlyingToken_ == address(this)) {
             revert ERC20InvalidUnderlying(address(this));
         }
+        uint8 underlyingDecimals = 18;
+        (bool success, bytes memory data) = underlyingToken_.staticcall(
+            abi.encodeWithSignature("decimals()")
+        );
+        if (success && data.length >= 32) {
+            uint256 returned = abi.decode(data, (uint256));
+            if (returned <= type(uint8).max) {
+                underlyingDecimals = uint8(returned);
+            }
+        }
+        if (decimals_ != underlyingDecimals) {
+            revert ERC20WrapperDecimalsMismatch(underlyingDecimals, decimals_);
+        }
         _underlying = underlyingToken_;
-        _decimals = decimals_;
+        _decimals = underlyingDecimals;
     }
 }
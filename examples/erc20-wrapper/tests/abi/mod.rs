@@ -24,6 +24,7 @@ sol!(
         error ERC20InvalidUnderlying(address token);
         error ERC20InvalidSender(address sender);
         error ERC20InvalidReceiver(address receiver);
+        error ERC20WrapperDecimalsMismatch(uint8 underlying_decimals, uint8 decimals);
 
     }
 
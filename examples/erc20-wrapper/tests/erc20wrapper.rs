@@ -5,7 +5,7 @@ use alloy::{
     primitives::{uint, Address, U256},
     sol,
 };
-use e2e::{receipt, watch, Account, EventExt, ReceiptExt};
+use e2e::{receipt, watch, Account, EventExt, ReceiptExt, Revert};
 use eyre::Result;
 
 use crate::Erc20WrapperExample::constructorCall;
@@ -69,6 +69,30 @@ async fn constructs(alice: Account) -> Result<()> {
     Ok(())
 }
 
+#[e2e::test]
+async fn constructor_reverts_on_decimals_mismatch(
+    alice: Account,
+) -> Result<()> {
+    let asset_address = erc20::deploy(&alice.wallet).await?;
+    let invalid_decimals = 6;
+    let err = alice
+        .as_deployer()
+        .with_constructor(Erc20WrapperExample::constructorCall {
+            underlyingToken_: asset_address,
+            decimals_: invalid_decimals,
+        })
+        .deploy()
+        .await
+        .expect_err("should not deploy due to `ERC20WrapperDecimalsMismatch`");
+
+    assert!(err.reverted_with(Erc20Wrapper::ERC20WrapperDecimalsMismatch {
+        underlying_decimals: DECIMALS,
+        decimals: invalid_decimals,
+    }));
+
+    Ok(())
+}
+
 #[e2e::test]
 async fn deposit_for_success(alice: Account) -> Result<()> {
     let initial_supply = uint!(1000_U256);
@@ -71,11 +71,12 @@ impl Erc20Example {
         let max_supply = self.capped.cap();
 
         // Overflow check required.
-        let supply = self
-            .erc20
-            .total_supply()
-            .checked_add(value)
-            .expect("new supply should not exceed `U256::MAX`");
+        let total_supply = self.erc20.total_supply();
+        let Some(supply) = total_supply.checked_add(value) else {
+            return Err(erc20::Error::TotalSupplyOverflow(
+                erc20::ERC20TotalSupplyOverflow { total_supply, value },
+            ))?;
+        };
 
         if supply > max_supply {
             return Err(capped::Error::ExceededCap(
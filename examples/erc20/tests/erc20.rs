@@ -4,10 +4,10 @@ use abi::Erc20;
 use alloy::{
     primitives::{uint, Address, U256},
     sol,
+    sol_types::SolEvent,
 };
 use e2e::{
-    receipt, send, watch, Account, EventExt, Panic, PanicCode, ReceiptExt,
-    Revert,
+    receipt, send, watch, Account, EventExt, LogsExt, ReceiptExt, Revert,
 };
 use eyre::Result;
 
@@ -66,6 +66,37 @@ async fn constructs(alice: Account) -> Result<()> {
     Ok(())
 }
 
+#[e2e::test]
+async fn constructs_distinct_tokens_from_same_wasm(
+    alice: Account,
+) -> Result<()> {
+    let first_addr = alice
+        .as_deployer()
+        .with_default_constructor::<constructorCall>()
+        .deploy()
+        .await?
+        .address()?;
+    let second_addr = alice
+        .as_deployer()
+        .with_constructor(Erc20Example::constructorCall {
+            name_: "Other Token".to_owned(),
+            symbol_: "OTK".to_owned(),
+            cap_: CAP,
+        })
+        .deploy()
+        .await?
+        .address()?;
+    let first = Erc20::new(first_addr, &alice.wallet);
+    let second = Erc20::new(second_addr, &alice.wallet);
+
+    assert_eq!(TOKEN_NAME, first.name().call().await?.name);
+    assert_eq!(TOKEN_SYMBOL, first.symbol().call().await?.symbol);
+    assert_eq!("Other Token", second.name().call().await?.name);
+    assert_eq!("OTK", second.symbol().call().await?.symbol);
+
+    Ok(())
+}
+
 #[e2e::test]
 async fn mints(alice: Account) -> Result<()> {
     let contract_addr = alice
@@ -164,7 +195,10 @@ async fn mints_rejects_overflow(alice: Account) -> Result<()> {
     let err = send!(contract.mint(alice_addr, one))
         .expect_err("should not exceed U256::MAX");
 
-    assert!(err.panicked_with(PanicCode::ArithmeticOverflow));
+    assert!(err.reverted_with(Erc20::ERC20TotalSupplyOverflow {
+        totalSupply: max_cap,
+        value: one
+    }));
 
     let Erc20::balanceOfReturn { balance } =
         contract.balanceOf(alice_addr).call().await?;
@@ -222,6 +256,50 @@ async fn transfers(alice: Account, bob: Account) -> Result<()> {
     Ok(())
 }
 
+#[e2e::test]
+async fn transfer_emits_one_decoded_transfer(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_default_constructor::<constructorCall>()
+        .deploy()
+        .await?
+        .address()?;
+    let contract_alice = Erc20::new(contract_addr, &alice.wallet);
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+
+    let balance = uint!(10_U256);
+    let value = uint!(1_U256);
+
+    watch!(contract_alice.mint(alice_addr, balance))?;
+
+    let receipt = receipt!(contract_alice.transfer(bob_addr, value))?;
+
+    let transfers = receipt.decode_logs::<Erc20::Transfer>();
+    assert_eq!(1, transfers.len());
+    assert_eq!(contract_addr, transfers[0].address);
+    assert_eq!(
+        Erc20::Transfer { from: alice_addr, to: bob_addr, value },
+        transfers[0].data
+    );
+    assert!(receipt.decode_logs::<Erc20::Approval>().is_empty());
+
+    let topics = receipt.inner.logs()[0].topics();
+    assert_eq!(
+        [
+            Erc20::Transfer::SIGNATURE_HASH,
+            alice_addr.into_word(),
+            bob_addr.into_word()
+        ],
+        topics
+    );
+
+    Ok(())
+}
+
 #[e2e::test]
 async fn transfer_rejects_insufficient_balance(
     alice: Account,
@@ -16,6 +16,9 @@ contract Erc4626Example {
     address private _asset;
     uint8 private _underlyingDecimals;
     uint8 private _decimalsOffset;
+    uint256 private _entryFeeBasisPoints;
+    uint256 private _exitFeeBasisPoints;
+    address private _feeRecipient;
 
     constructor(string memory name_, string memory symbol_, address asset_, uint8 decimalsOffset_) {
         _name = name_;
@@ -96,7 +96,7 @@ contract Erc721ConsecutiveExample {
                 revert ERC721InvalidReceiver(address(0));
             }
 
-            uint256 maxBatchSize = _maxBatchSize;
+            uint256 maxBatchSize = _maxBatchSize == 0 ? 5000 : _maxBatchSize;
             if (batchSize > maxBatchSize) {
                 revert ERC721ExceededMaxBatchMint(batchSize, maxBatchSize);
             }
@@ -5,6 +5,7 @@ use alloc::vec::Vec;
 
 use alloy_primitives::{Address, FixedBytes, U256};
 use openzeppelin_stylus::{
+    supports_interface,
     token::erc721::{
         self,
         extensions::{
@@ -12,7 +13,7 @@ use openzeppelin_stylus::{
         },
         Erc721, IErc721,
     },
-    utils::{introspection::erc165::IErc165, pausable, Pausable},
+    utils::{pausable, Pausable},
 };
 use stylus_sdk::{abi::Bytes, prelude::*};
 
@@ -180,8 +181,7 @@ impl Erc721Example {
     }
 
     fn supports_interface(interface_id: FixedBytes<4>) -> bool {
-        Erc721::supports_interface(interface_id)
-            || Enumerable::supports_interface(interface_id)
+        supports_interface!(interface_id; Erc721, Enumerable)
     }
 
     /// WARNING: These functions are intended for **testing purposes** only. In
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.21;
+
+contract VestingWalletSharesExample {
+    uint64 private _start;
+    uint64 private _duration;
+    uint256 private _totalShares;
+    mapping(address => uint256) private _shares;
+    mapping(address => uint256) private _erc20TotalReleased;
+    mapping(address => mapping(address => uint256)) private _erc20Released;
+
+    event PayeeAdded(address indexed account, uint256 shares);
+
+    error VestingWalletInvalidPayee(address account);
+    error VestingWalletInvalidShares(address account);
+
+    constructor(
+        uint64 startTimestamp,
+        uint64 durationSeconds,
+        address[] memory payees,
+        uint256[] memory shares
+    ) {
+        _start = startTimestamp;
+        _duration = durationSeconds;
+
+        for (uint256 i = 0; i < payees.length; i++) {
+            _addPayee(payees[i], shares[i]);
+        }
+    }
+
+    function _addPayee(address account, uint256 shares) private {
+        if (account == address(0) || _shares[account] != 0) {
+            revert VestingWalletInvalidPayee(account);
+        }
+        if (shares == 0) {
+            revert VestingWalletInvalidShares(account);
+        }
+
+        _shares[account] = shares;
+        _totalShares += shares;
+
+        emit PayeeAdded(account, shares);
+    }
+}
@@ -0,0 +1,16 @@
+#![cfg_attr(not(test), no_main)]
+extern crate alloc;
+
+use openzeppelin_stylus::finance::vesting_wallet_shares::VestingWalletShares;
+use stylus_sdk::prelude::*;
+
+#[entrypoint]
+#[storage]
+struct VestingWalletSharesExample {
+    #[borrow]
+    vesting_wallet_shares: VestingWalletShares,
+}
+
+#[public]
+#[inherit(VestingWalletShares)]
+impl VestingWalletSharesExample {}
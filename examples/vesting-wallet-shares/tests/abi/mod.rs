@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+     #[sol(rpc)]
+     contract VestingWalletShares {
+          function start() external view returns (uint256 start);
+          function duration() external view returns (uint256 duration);
+          function end() external view returns (uint256 end);
+          function totalShares() external view returns (uint256 totalShares);
+          function shares(address account) external view returns (uint256 shares);
+          function totalReleased(address token) external view returns (uint256 totalReleased);
+          function released(address token, address account) external view returns (uint256 released);
+          function releasable(address token, address account) external view returns (uint256 releasable);
+          function release(address token, address account) external;
+          function vestedAmount(address token, uint64 timestamp) external view returns (uint256 vestedAmount);
+
+          error SafeErc20FailedOperation(address token);
+          error InvalidToken(address token);
+          error VestingWalletInvalidPayee(address account);
+          error VestingWalletInvalidShares(address account);
+          error VestingWalletNoPaymentDue(address token, address account);
+
+          #[derive(Debug, PartialEq)]
+          event PayeeAdded(address indexed account, uint256 shares);
+          #[derive(Debug, PartialEq)]
+          event ERC20PaymentReleased(address indexed token, address indexed to, uint256 amount);
+     }
+);
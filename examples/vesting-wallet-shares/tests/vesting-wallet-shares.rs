@@ -0,0 +1,191 @@
+#![cfg(feature = "e2e")]
+
+use abi::VestingWalletShares;
+use alloy::{
+    eips::BlockId,
+    primitives::{Address, U256},
+    providers::Provider,
+    rpc::types::BlockTransactionsKind,
+    sol,
+};
+use e2e::{receipt, send, watch, Account, EventExt, ReceiptExt, Revert};
+use mock::{erc20, erc20::ERC20Mock};
+
+use crate::VestingWalletSharesExample::constructorCall;
+
+mod abi;
+mod mock;
+
+sol!("src/constructor.sol");
+
+const BALANCE: u64 = 1000;
+const DURATION: u64 = 365 * 86400; // 1 year
+
+fn ctr(
+    start_timestamp: u64,
+    duration_seconds: u64,
+    payees: &[(Address, u64)],
+) -> constructorCall {
+    constructorCall {
+        startTimestamp: start_timestamp,
+        durationSeconds: duration_seconds,
+        payees: payees.iter().map(|&(account, _)| account).collect(),
+        shares: payees.iter().map(|&(_, shares)| U256::from(shares)).collect(),
+    }
+}
+
+async fn block_timestamp(account: &Account) -> eyre::Result<u64> {
+    let timestamp = account
+        .wallet
+        .get_block(BlockId::latest(), BlockTransactionsKind::Hashes)
+        .await?
+        .expect("latest block should exist")
+        .header
+        .timestamp;
+
+    Ok(timestamp)
+}
+
+async fn deploy_erc20(
+    account: &Account,
+    mint_to: Address,
+    allocation: U256,
+) -> eyre::Result<Address> {
+    let erc20_address = erc20::deploy(&account.wallet).await?;
+    let erc20 = ERC20Mock::new(erc20_address, &account.wallet);
+    watch!(erc20.mint(mint_to, allocation))?;
+    Ok(erc20_address)
+}
+
+#[e2e::test]
+async fn constructs(alice: Account, bob: Account) -> eyre::Result<()> {
+    let start_timestamp = block_timestamp(&alice).await?;
+    let receipt = alice
+        .as_deployer()
+        .with_constructor(ctr(
+            start_timestamp,
+            DURATION,
+            &[(alice.address(), 1), (bob.address(), 3)],
+        ))
+        .deploy()
+        .await?;
+    let contract = VestingWalletShares::new(receipt.address()?, &alice.wallet);
+
+    let start = contract.start().call().await?.start;
+    let duration = contract.duration().call().await?.duration;
+    let end = contract.end().call().await?.end;
+    let total_shares = contract.totalShares().call().await?.totalShares;
+    let alice_shares = contract.shares(alice.address()).call().await?.shares;
+    let bob_shares = contract.shares(bob.address()).call().await?.shares;
+
+    assert_eq!(U256::from(start_timestamp), start);
+    assert_eq!(U256::from(DURATION), duration);
+    assert_eq!(U256::from(start_timestamp + DURATION), end);
+    assert_eq!(U256::from(4), total_shares);
+    assert_eq!(U256::from(1), alice_shares);
+    assert_eq!(U256::from(3), bob_shares);
+
+    assert!(receipt.emits(VestingWalletShares::PayeeAdded {
+        account: bob.address(),
+        shares: U256::from(3),
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn rejects_zero_address_for_payee(alice: Account) -> eyre::Result<()> {
+    let start = block_timestamp(&alice).await?;
+    let err = alice
+        .as_deployer()
+        .with_constructor(ctr(start, DURATION, &[(Address::ZERO, 1)]))
+        .deploy()
+        .await
+        .expect_err("should not deploy due to `VestingWalletInvalidPayee`");
+
+    assert!(err.reverted_with(
+        VestingWalletShares::VestingWalletInvalidPayee {
+            account: Address::ZERO
+        }
+    ));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn rejects_zero_shares(alice: Account) -> eyre::Result<()> {
+    let start = block_timestamp(&alice).await?;
+    let err = alice
+        .as_deployer()
+        .with_constructor(ctr(start, DURATION, &[(alice.address(), 0)]))
+        .deploy()
+        .await
+        .expect_err("should not deploy due to `VestingWalletInvalidShares`");
+
+    assert!(err.reverted_with(
+        VestingWalletShares::VestingWalletInvalidShares {
+            account: alice.address()
+        }
+    ));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn payees_release_their_shares(
+    alice: Account,
+    bob: Account,
+) -> eyre::Result<()> {
+    // Vesting ended, so that the whole balance is vested.
+    let start = block_timestamp(&alice).await? - DURATION;
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(
+            start,
+            DURATION,
+            &[(alice.address(), 1), (bob.address(), 3)],
+        ))
+        .deploy()
+        .await?
+        .address()?;
+    let erc20_address =
+        deploy_erc20(&alice, contract_addr, U256::from(BALANCE)).await?;
+
+    let contract = VestingWalletShares::new(contract_addr, &alice.wallet);
+    let erc20 = ERC20Mock::new(erc20_address, &alice.wallet);
+
+    let releasable = contract
+        .releasable(erc20_address, alice.address())
+        .call()
+        .await?
+        .releasable;
+    assert_eq!(U256::from(250), releasable);
+
+    let receipt = receipt!(contract.release(erc20_address, alice.address()))?;
+    assert!(receipt.emits(VestingWalletShares::ERC20PaymentReleased {
+        token: erc20_address,
+        to: alice.address(),
+        amount: U256::from(250),
+    }));
+
+    watch!(contract.release(erc20_address, bob.address()))?;
+
+    let alice_balance = erc20.balanceOf(alice.address()).call().await?.balance;
+    let bob_balance = erc20.balanceOf(bob.address()).call().await?.balance;
+    let total_released =
+        contract.totalReleased(erc20_address).call().await?.totalReleased;
+    assert_eq!(U256::from(250), alice_balance);
+    assert_eq!(U256::from(750), bob_balance);
+    assert_eq!(U256::from(BALANCE), total_released);
+
+    let err = send!(contract.release(erc20_address, alice.address()))
+        .expect_err("should not release twice");
+    assert!(err.reverted_with(
+        VestingWalletShares::VestingWalletNoPaymentDue {
+            token: erc20_address,
+            account: alice.address()
+        }
+    ));
+
+    Ok(())
+}
@@ -21,7 +21,7 @@ use crate::{
         limb::{Limb, Limbs},
         BigInteger,
     },
-    bits::BitIteratorBE,
+    bits::{BitIteratorBE, BitIteratorLE},
     ct_for, ct_for_unroll6,
 };
 
@@ -209,6 +209,49 @@ impl<const N: usize> Uint<N> {
         num_bits
     }
 
+    /// Return the number of trailing zero bits of `self` (constant).
+    ///
+    /// Returns the total number of bits if `self` is zero.
+    #[must_use]
+    pub const fn ct_trailing_zeros(&self) -> u32 {
+        let mut trailing_zeros = 0;
+
+        // Start with the first (lowest) limb.
+        let mut index = 0;
+        while index < N {
+            let trailing = self.limbs[index].trailing_zeros();
+            trailing_zeros += trailing;
+
+            // If the limb is not empty, stop processing other limbs.
+            if trailing != Limb::BITS {
+                break;
+            }
+            index += 1;
+        }
+
+        trailing_zeros
+    }
+
+    /// Shift `self` right by `rhs` bits, filling with zeros (constant).
+    #[must_use]
+    pub const fn ct_shr(&self, rhs: u32) -> Self {
+        let limb_shift = (rhs / Limb::BITS) as usize;
+        let bit_shift = rhs % Limb::BITS;
+
+        let mut result = Self { limbs: [0; N] };
+        let mut i = 0;
+        while i + limb_shift < N {
+            let mut limb = self.limbs[i + limb_shift] >> bit_shift;
+            if bit_shift != 0 && i + limb_shift + 1 < N {
+                limb |=
+                    self.limbs[i + limb_shift + 1] << (Limb::BITS - bit_shift);
+            }
+            result.limbs[i] = limb;
+            i += 1;
+        }
+        result
+    }
+
     /// Find the `i`-th bit of `self`.
     #[must_use]
     pub const fn ct_get_bit(&self, i: usize) -> bool {
@@ -681,6 +724,12 @@ impl<const N: usize> BitIteratorBE for Uint<N> {
     }
 }
 
+impl<const N: usize> BitIteratorLE for Uint<N> {
+    fn bit_le_iter(&self) -> impl Iterator<Item = bool> {
+        self.as_limbs().iter().flat_map(Limb::bit_le_iter)
+    }
+}
+
 /// Parse a number from a string in a given radix.
 ///
 /// This implementation can be slow on big numbers and possibly fail constant
@@ -880,7 +929,7 @@ mod test {
             uint::{from_str_hex, from_str_radix, Uint, WideUint},
             *,
         },
-        bits::BitIteratorBE,
+        bits::{BitIteratorBE, BitIteratorLE},
     };
 
     #[test]
@@ -937,6 +986,18 @@ mod test {
         assert_eq!(bits, vec![true, true, false, false]);
     }
 
+    #[test]
+    fn uint_bit_iterator_le() {
+        let words: [Limb; 4] = [0, 0b1100, 0, 0];
+        let num = Uint::<4>::new(words);
+
+        assert_eq!(num.bit_le_iter().count(), 256);
+        let bits: Vec<bool> = num.bit_le_trimmed_iter().collect();
+        assert_eq!(bits.len(), 64 + 4);
+        assert_eq!(bits[64..], [false, false, true, true]);
+        assert!(bits[..64].iter().all(|&b| !b));
+    }
+
     #[test]
     fn num_bits() {
         let words: [Limb; 4] = [0b1100, 0, 0, 0];
@@ -952,6 +1013,22 @@ mod test {
         assert_eq!(num.num_bits(), 64 + 64 + 64 + 2);
     }
 
+    #[test]
+    fn trailing_zeros_and_shr() {
+        let num = Uint::<4>::new([0, 0b1100, 0, 0]);
+        assert_eq!(num.ct_trailing_zeros(), 64 + 2);
+        assert_eq!(Uint::<4>::ZERO.ct_trailing_zeros(), 256);
+
+        assert_eq!(num.ct_shr(0), num);
+        assert_eq!(num.ct_shr(2), Uint::new([0, 0b11, 0, 0]));
+        assert_eq!(num.ct_shr(64 + 3), Uint::new([1, 0, 0, 0]));
+        assert_eq!(
+            Uint::<4>::new([0, 0, 0, 1]).ct_shr(1),
+            Uint::new([0, 0, 1 << 63, 0])
+        );
+        assert_eq!(num.ct_shr(256), Uint::ZERO);
+    }
+
     #[test]
     fn ct_rem() {
         let dividend = from_num!("43129923721897334698312931");
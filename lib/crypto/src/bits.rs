@@ -1,5 +1,7 @@
 //! Bit manipulation utilities.
 
+use crate::arithmetic::BigInteger;
+
 /// Iterates over bits in big-endian order.
 pub trait BitIteratorBE {
     /// Returns an iterator over the bits of the integer, starting from the most
@@ -30,12 +32,196 @@ impl_bit_iter_be!(u64);
 impl_bit_iter_be!(u128);
 impl_bit_iter_be!(usize);
 
+/// Iterates over bits in little-endian order.
+pub trait BitIteratorLE {
+    /// Returns an iterator over the bits of the integer, starting from the
+    /// least significant bit.
+    fn bit_le_iter(&self) -> impl Iterator<Item = bool>;
+
+    /// Returns an iterator over the bits of the integer, starting from the
+    /// least significant bit, and without trailing zeroes.
+    fn bit_le_trimmed_iter(&self) -> impl Iterator<Item = bool> {
+        let len = self
+            .bit_le_iter()
+            .enumerate()
+            .filter(|&(_, b)| b)
+            .last()
+            .map_or(0, |(i, _)| i + 1);
+        self.bit_le_iter().take(len)
+    }
+}
+
+macro_rules! impl_bit_iter_le {
+    ($int:ty) => {
+        impl BitIteratorLE for $int {
+            fn bit_le_iter(&self) -> impl Iterator<Item = bool> {
+                (0..<$int>::BITS).map(move |i| self & (1 << i) != 0)
+            }
+        }
+    };
+}
+
+impl_bit_iter_le!(u8);
+impl_bit_iter_le!(u16);
+impl_bit_iter_le!(u32);
+impl_bit_iter_le!(u64);
+impl_bit_iter_le!(u128);
+impl_bit_iter_le!(usize);
+
+/// Iterates over the width-`w` non-adjacent form (wNAF) of a scalar, starting
+/// from the least significant digit.
+///
+/// Every digit is either zero or odd, lies in `(-2^(w-1), 2^(w-1))`, and out
+/// of any `w` consecutive digits at most one is non-zero. The scalar equals the
+/// sum of `digit * 2^i` over the yielded digits, and the last yielded digit is
+/// non-zero, i.e. zero yields no digits.
+#[derive(Clone, Debug)]
+pub struct WnafIterator<B: BigInteger> {
+    scalar: B,
+    width: usize,
+    num_bits: usize,
+    pos: usize,
+    carry: bool,
+    zeros: usize,
+}
+
+impl<B: BigInteger> WnafIterator<B> {
+    /// Creates an iterator over the width-`width` NAF of `scalar`.
+    ///
+    /// # Panics
+    ///
+    /// * If `width` is not in `2..=62`.
+    #[must_use]
+    pub fn new(scalar: B, width: usize) -> Self {
+        assert!((2..=62).contains(&width), "wNAF width should be in 2..=62");
+        Self {
+            scalar,
+            width,
+            num_bits: scalar.num_bits(),
+            pos: 0,
+            carry: false,
+            zeros: 0,
+        }
+    }
+
+    /// Creates an iterator over the (width 2) NAF of `scalar`.
+    #[must_use]
+    pub fn naf(scalar: B) -> Self {
+        Self::new(scalar, 2)
+    }
+
+    /// Returns true if all remaining digits are zero.
+    fn is_done(&self) -> bool {
+        self.pos >= self.num_bits && !self.carry
+    }
+
+    /// Returns the `width` bits of the scalar starting at `self.pos`.
+    fn window(&self) -> i64 {
+        (0..self.width)
+            .filter(|&i| self.scalar.get_bit(self.pos + i))
+            .fold(0, |window, i| window | (1 << i))
+    }
+}
+
+impl<B: BigInteger> Iterator for WnafIterator<B> {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done() {
+            return None;
+        }
+
+        if self.zeros > 0 {
+            self.zeros -= 1;
+            return Some(0);
+        }
+
+        // Even remainder: emit a zero digit and move to the next bit.
+        if self.scalar.get_bit(self.pos) == self.carry {
+            self.pos += 1;
+            return Some(0);
+        }
+
+        // Odd remainder: emit its signed residue modulo `2^width`, carrying
+        // into the next window when the residue is negative.
+        let window = self.window() + i64::from(self.carry);
+        let digit = if window >= 1 << (self.width - 1) {
+            self.carry = true;
+            window - (1 << self.width)
+        } else {
+            self.carry = false;
+            window
+        };
+        self.pos += self.width;
+        self.zeros = self.width - 1;
+        Some(digit)
+    }
+}
+
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use num_traits::ConstOne;
     use proptest::prelude::*;
 
     use super::*;
+    use crate::arithmetic::uint::Uint;
+
+    fn reconstruct(digits: &[i64]) -> i128 {
+        digits.iter().enumerate().fold(0i128, |acc, (i, &digit)| {
+            acc.wrapping_add(
+                i128::from(digit).wrapping_shl(u32::try_from(i).unwrap()),
+            )
+        })
+    }
+
+    #[test]
+    fn wnaf_reconstructs_scalar() {
+        proptest!(|(value: u128, width in 2usize..=8)| {
+            let value = value >> 2;
+            let digits: Vec<i64> =
+                WnafIterator::new(Uint::<2>::from(value), width).collect();
+
+            prop_assert_eq!(reconstruct(&digits), i128::try_from(value).unwrap());
+            prop_assert_ne!(digits.last().copied(), Some(0));
+            let bound = 1i64 << (width - 1);
+            for digit in &digits {
+                prop_assert!(*digit == 0 || digit % 2 != 0);
+                prop_assert!(-bound < *digit && *digit < bound);
+            }
+            for window in digits.windows(width) {
+                prop_assert!(window.iter().filter(|&&d| d != 0).count() <= 1);
+            }
+        });
+    }
+
+    #[test]
+    fn naf_has_no_adjacent_nonzero_digits() {
+        proptest!(|(value: u64)| {
+            let digits: Vec<i64> = WnafIterator::naf(Uint::<1>::from(value)).collect();
+
+            prop_assert_eq!(reconstruct(&digits), i128::from(value));
+            prop_assert!(digits.len() <= 64 + 1);
+            for pair in digits.windows(2) {
+                prop_assert!(pair[0] == 0 || pair[1] == 0);
+            }
+        });
+    }
+
+    #[test]
+    fn naf_known_values() {
+        let naf = |value: u64| -> Vec<i64> {
+            WnafIterator::naf(Uint::<1>::from(value)).collect()
+        };
+        assert_eq!(naf(0), Vec::<i64>::new());
+        assert_eq!(naf(1), [1]);
+        // 7 = 8 - 1
+        assert_eq!(naf(7), [-1, 0, 0, 1]);
+        // u64::MAX = 2^64 - 1, overflows the scalar limbs.
+        let mut expected = vec![0; 65];
+        expected[0] = -1;
+        expected[64] = 1;
+        assert_eq!(naf(u64::MAX), expected);
+    }
 
     #[test]
     fn known_pattern() {
@@ -51,6 +237,44 @@ mod tests {
         let trimmed = value.bit_be_trimmed_iter().collect::<Vec<_>>();
         let expected = vec![true, true, false, false];
         assert_eq!(trimmed, expected);
+
+        let full = value.bit_le_iter().collect::<Vec<_>>();
+        let expected = [false, false, true, true]
+            .iter()
+            .chain(&[false; 60])
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(full, expected);
+
+        let trimmed = value.bit_le_trimmed_iter().collect::<Vec<_>>();
+        let expected = vec![false, false, true, true];
+        assert_eq!(trimmed, expected);
+    }
+
+    macro_rules! le_is_reversed_be {
+        ($ty:ident) => {{
+            proptest!(|(value: $ty)| {
+                let mut be: Vec<bool> = value.bit_be_iter().collect();
+                be.reverse();
+                let le: Vec<bool> = value.bit_le_iter().collect();
+                prop_assert_eq!(&be, &le);
+
+                let mut be: Vec<bool> = value.bit_be_trimmed_iter().collect();
+                be.reverse();
+                let le: Vec<bool> = value.bit_le_trimmed_iter().collect();
+                prop_assert_eq!(be, le);
+            });
+        }};
+    }
+
+    #[test]
+    fn le_is_reversed_be() {
+        le_is_reversed_be!(u8);
+        le_is_reversed_be!(u16);
+        le_is_reversed_be!(u32);
+        le_is_reversed_be!(u64);
+        le_is_reversed_be!(u128);
+        le_is_reversed_be!(usize);
     }
 
     macro_rules! trimmed_is_subset_of_full {
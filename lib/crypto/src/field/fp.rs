@@ -13,19 +13,21 @@
 //! where a particular ordering is required.
 //!
 //! [motgomery form]: https://en.wikipedia.org/wiki/Montgomery_modular_multiplication
-use alloc::string::ToString;
+use alloc::{string::ToString, vec::Vec};
 use core::{
     cmp::Ordering,
     fmt::{Debug, Display, Formatter},
     iter::{Product, Sum},
     marker::PhantomData,
     ops::{
-        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+        Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg,
+        Sub, SubAssign,
     },
 };
 
 use educe::Educe;
 use num_traits::{One, Zero};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use crate::{
     arithmetic::{
@@ -68,6 +70,36 @@ pub trait FpParams<const N: usize>: Send + Sync + 'static + Sized {
         .ct_rem(&Self::MODULUS)
         .ct_wrapping_add(&Uint::ONE);
 
+    /// `MODULUS_MINUS_ONE_DIV_TWO = (MODULUS - 1) / 2`
+    const MODULUS_MINUS_ONE_DIV_TWO: Uint<N> =
+        Self::MODULUS.ct_wrapping_sub(&Uint::ONE).ct_shr(1);
+
+    /// `MODULUS_MINUS_TWO = MODULUS - 2`, the exponent of Fermat inversion.
+    const MODULUS_MINUS_TWO: Uint<N> =
+        Self::MODULUS.ct_wrapping_sub(&Uint::from_u128(2));
+
+    /// `MODULUS_PLUS_ONE_DIV_FOUR = (MODULUS + 1) / 4`
+    ///
+    /// Only meaningful when `MODULUS % 4 == 3`, where it is the exponent of
+    /// square roots.
+    const MODULUS_PLUS_ONE_DIV_FOUR: Uint<N> =
+        Self::MODULUS.ct_shr(2).ct_wrapping_add(&Uint::ONE);
+
+    /// Let `TWO_ADICITY = s`.
+    ///
+    /// Then `s` is the largest integer such that `2^s` divides `MODULUS - 1`.
+    const TWO_ADICITY: u32 =
+        Self::MODULUS.ct_wrapping_sub(&Uint::ONE).ct_trailing_zeros();
+
+    /// `TWO_ADIC_ROOT_OF_UNITY = GENERATOR^t`, where `MODULUS - 1 = 2^s * t`
+    /// and `s` is [`Self::TWO_ADICITY`].
+    ///
+    /// Since [`Self::GENERATOR`] has order `MODULUS - 1`, it has multiplicative
+    /// order `2^s`.
+    const TWO_ADIC_ROOT_OF_UNITY: Fp<Self, N> = Self::GENERATOR.ct_pow(
+        &Self::MODULUS.ct_wrapping_sub(&Uint::ONE).ct_shr(Self::TWO_ADICITY),
+    );
+
     /// Set `a += b`.
     #[inline(always)]
     fn add_assign(a: &mut Fp<Self, N>, b: &Fp<Self, N>) {
@@ -250,7 +282,8 @@ pub const fn inv<T: FpParams<N>, const N: usize>() -> u64 {
 pub struct Fp<P: FpParams<N>, const N: usize> {
     /// Contains the element in Montgomery form for efficient multiplication.
     /// To convert an element to a [`Uint`], use [`FpParams::into_bigint`]
-    /// or `into`.
+    /// or `into`. To access the Montgomery form itself, use
+    /// [`Fp::to_montgomery_repr`] and [`Fp::from_montgomery_repr`].
     montgomery_form: Uint<N>,
     #[doc(hidden)]
     phantom: PhantomData<P>,
@@ -321,6 +354,31 @@ impl<P: FpParams<N>, const N: usize> Fp<P, N> {
         Self { montgomery_form: element, phantom: PhantomData }
     }
 
+    /// Returns the internal representation of `self`, i.e. its value `a`
+    /// in Montgomery form `a * R mod MODULUS`.
+    ///
+    /// Unlike [`PrimeField::into_bigint`], no Montgomery reduction is
+    /// performed. Meant for custom algorithms operating on the Montgomery
+    /// form directly.
+    #[must_use]
+    #[inline(always)]
+    pub const fn to_montgomery_repr(&self) -> Uint<N> {
+        self.montgomery_form
+    }
+
+    /// Construct a new field element from its internal representation, i.e.
+    /// an integer already in Montgomery form, as returned by
+    /// [`Self::to_montgomery_repr`].
+    ///
+    /// Same as [`Self::new_unchecked`]: `repr` should be lower than
+    /// [`PrimeField::MODULUS`], otherwise the element is not canonical. Use
+    /// [`Self::new`] to convert an integer that is not in Montgomery form.
+    #[must_use]
+    #[inline(always)]
+    pub const fn from_montgomery_repr(repr: Uint<N>) -> Self {
+        Self::new_unchecked(repr)
+    }
+
     #[doc(hidden)]
     #[inline(always)]
     #[must_use]
@@ -350,6 +408,127 @@ impl<P: FpParams<N>, const N: usize> Fp<P, N> {
         }
     }
 
+    /// Construct a new field element from the little-endian `limbs` of an
+    /// integer, reduced modulo [`PrimeField::MODULUS`].
+    ///
+    /// Unlike [`Self::new_unchecked`], any `limbs` result in a canonical
+    /// element, which makes it suitable to derive elements deterministically,
+    /// e.g. in tests.
+    #[inline]
+    #[must_use]
+    pub const fn from_u64_array(limbs: [u64; N]) -> Self {
+        Self::new(Uint::new(limbs))
+    }
+
+    /// Returns the multiplicative inverse of `self`, or zero if `self` is
+    /// zero.
+    ///
+    /// Unlike [`Field::inverse`], the result is computed as
+    /// `self^(MODULUS - 2)`, which doesn't branch on the value of `self`.
+    /// Prefer it over `inverse().unwrap_or(Self::ZERO)` where the input is
+    /// secret.
+    #[inline]
+    #[must_use]
+    pub const fn inverse_or_zero(&self) -> Self {
+        self.ct_pow(&P::MODULUS_MINUS_TWO)
+    }
+
+    /// Returns `3 * self`, computed with a doubling and an addition.
+    #[inline]
+    #[must_use]
+    pub fn triple(&self) -> Self {
+        self.double() + self
+    }
+
+    /// Returns `4 * self`, computed with two doublings.
+    #[inline]
+    #[must_use]
+    pub fn quadruple(&self) -> Self {
+        let mut temp = self.double();
+        temp.double_in_place();
+        temp
+    }
+
+    /// Returns `k * self`, computed with doublings and additions only.
+    ///
+    /// Cheaper than a multiplication by `Self::from(k)` for small `k`, e.g.
+    /// the constants of curve formulas.
+    #[must_use]
+    pub fn mul_by_small(&self, k: u64) -> Self {
+        let mut res = Self::ZERO;
+        for bit in (0..u64::BITS - k.leading_zeros()).rev() {
+            res.double_in_place();
+            if (k >> bit) & 1 == 1 {
+                res += self;
+            }
+        }
+        res
+    }
+
+    /// Returns a square root of `self`, or `None` if `self` is not a
+    /// quadratic residue.
+    ///
+    /// When `MODULUS % 4 == 3`, the root is computed directly as
+    /// `self^((MODULUS + 1) / 4)`. Otherwise, the Tonelli-Shanks algorithm is
+    /// used.
+    ///
+    /// NOTE: Either root may be returned, use [`Neg`] to get the other one.
+    #[must_use]
+    pub fn sqrt(&self) -> Option<Self> {
+        if P::MODULUS.limbs[0] & 3 == 3 {
+            let root = self.ct_pow(&P::MODULUS_PLUS_ONE_DIV_FOUR);
+            (root.square() == *self).then_some(root)
+        } else {
+            self.sqrt_tonelli_shanks()
+        }
+    }
+
+    /// Returns a square root of `self` with the Tonelli-Shanks algorithm, or
+    /// `None` if `self` is not a quadratic residue.
+    ///
+    /// Relies on [`FpParams::TWO_ADIC_ROOT_OF_UNITY`] being a power of a
+    /// quadratic non-residue, which holds since [`FpParams::GENERATOR`]
+    /// generates the multiplicative group.
+    fn sqrt_tonelli_shanks(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::ZERO);
+        }
+
+        // `MODULUS - 1 = 2^s * q`, with `q` odd.
+        let q = P::MODULUS.ct_wrapping_sub(&Uint::ONE).ct_shr(P::TWO_ADICITY);
+        // `(q + 1) / 2`, since `q` is odd.
+        let mut root = self.ct_pow(&q.ct_shr(1).ct_wrapping_add(&Uint::ONE));
+        // Invariant: `root^2 = self * residue`, where `residue` has order
+        // `2^order` or less, and `unity` has order exactly `2^order`.
+        let mut residue = self.ct_pow(&q);
+        let mut unity = P::TWO_ADIC_ROOT_OF_UNITY;
+        let mut order = P::TWO_ADICITY;
+
+        while !residue.is_one() {
+            // Find the least `least` with `residue^(2^least) == 1`.
+            let mut least = 0;
+            let mut power = residue;
+            while !power.is_one() {
+                least += 1;
+                if least == order {
+                    return None;
+                }
+                power.square_in_place();
+            }
+
+            let mut factor = unity;
+            for _ in 0..order - least - 1 {
+                factor.square_in_place();
+            }
+            order = least;
+            unity = factor.square();
+            residue *= unity;
+            root *= factor;
+        }
+
+        Some(root)
+    }
+
     /// Multiply `self` to `rhs` and return the result (constant).
     ///
     /// Implements the Montgomery multiplication algorithm [reference].
@@ -365,6 +544,22 @@ impl<P: FpParams<N>, const N: usize> Fp<P, N> {
         }
     }
 
+    /// Raise `self` to the power of `exp` and return the result (constant).
+    ///
+    /// Square and multiply, starting from the most significant bit of `exp`.
+    const fn ct_pow(&self, exp: &Uint<N>) -> Self {
+        let mut res = Self::ONE;
+        let mut i = exp.ct_num_bits();
+        while i > 0 {
+            i -= 1;
+            res = res.ct_mul(&res);
+            if exp.ct_get_bit(i) {
+                res = res.ct_mul(self);
+            }
+        }
+        res
+    }
+
     //// Returns true if this number is zero (constant).
     const fn ct_is_zero(&self) -> bool {
         self.montgomery_form.ct_is_zero()
@@ -459,6 +654,135 @@ impl<P: FpParams<N>, const N: usize> Fp<P, N> {
         }
         Uint::new(limbs)
     }
+
+    /// Returns an iterator over the bits of [`PrimeField::into_bigint`],
+    /// starting from the least significant bit.
+    ///
+    /// Yields `N * 64` bits, the same as
+    /// [`crate::bits::BitIteratorLE::bit_le_iter`] of
+    /// the canonical representation.
+    pub fn to_bits_le(&self) -> impl Iterator<Item = bool> {
+        let bigint = self.into_bigint();
+        (0..N * limb::Limb::BITS as usize).map(move |i| bigint.get_bit(i))
+    }
+
+    /// Returns an iterator over the bits of [`PrimeField::into_bigint`],
+    /// starting from the most significant bit.
+    ///
+    /// Yields `N * 64` bits, the same as
+    /// [`crate::bits::BitIteratorBE::bit_be_iter`] of
+    /// the canonical representation.
+    pub fn to_bits_be(&self) -> impl Iterator<Item = bool> {
+        let bigint = self.into_bigint();
+        (0..N * limb::Limb::BITS as usize).rev().map(move |i| bigint.get_bit(i))
+    }
+
+    /// Returns an iterator over the bits of [`PrimeField::into_bigint`],
+    /// starting from the least significant bit, and without trailing zeroes.
+    ///
+    /// Yields at most [`PrimeField::MODULUS_BIT_SIZE`] bits.
+    pub fn to_bits_le_trimmed(&self) -> impl Iterator<Item = bool> {
+        let bigint = self.into_bigint();
+        (0..bigint.num_bits()).map(move |i| bigint.get_bit(i))
+    }
+
+    /// Returns an iterator over the bits of [`PrimeField::into_bigint`],
+    /// starting from the most significant bit, and without leading zeroes.
+    ///
+    /// Yields at most [`PrimeField::MODULUS_BIT_SIZE`] bits.
+    pub fn to_bits_be_trimmed(&self) -> impl Iterator<Item = bool> {
+        let bigint = self.into_bigint();
+        (0..bigint.num_bits()).rev().map(move |i| bigint.get_bit(i))
+    }
+
+    /// Returns the little-endian encoding of [`PrimeField::into_bigint`], on
+    /// `B = N * 8` bytes.
+    ///
+    /// # Panics
+    ///
+    /// * At compile time, if `B` is not `N * 8`.
+    #[must_use]
+    pub fn to_bytes_le<const B: usize>(&self) -> [u8; B] {
+        const { assert!(B == N * 8, "should encode on `N * 8` bytes") };
+        let mut bytes = [0u8; B];
+        for (chunk, limb) in
+            bytes.chunks_exact_mut(8).zip(self.into_bigint().as_limbs())
+        {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Returns the big-endian encoding of [`PrimeField::into_bigint`], on
+    /// `B = N * 8` bytes.
+    ///
+    /// # Panics
+    ///
+    /// * At compile time, if `B` is not `N * 8`.
+    #[must_use]
+    pub fn to_bytes_be<const B: usize>(&self) -> [u8; B] {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Decodes a field element from its canonical little-endian encoding, on
+    /// `B = N * 8` bytes.
+    ///
+    /// Returns `None` if the encoded integer is not lower than
+    /// [`PrimeField::MODULUS`], instead of reducing it.
+    ///
+    /// # Panics
+    ///
+    /// * At compile time, if `B` is not `N * 8`.
+    #[must_use]
+    pub fn from_bytes_le<const B: usize>(bytes: &[u8; B]) -> Option<Self> {
+        const { assert!(B == N * 8, "should decode from `N * 8` bytes") };
+        let bigint = Uint::<N>::ct_from_le_slice(bytes);
+        if bigint.ct_ge(&P::MODULUS) {
+            None
+        } else {
+            Some(P::from_bigint(bigint))
+        }
+    }
+
+    /// Decodes a field element from its canonical big-endian encoding, on
+    /// `B = N * 8` bytes.
+    ///
+    /// Returns `None` if the encoded integer is not lower than
+    /// [`PrimeField::MODULUS`], instead of reducing it.
+    ///
+    /// # Panics
+    ///
+    /// * At compile time, if `B` is not `N * 8`.
+    #[must_use]
+    pub fn from_bytes_be<const B: usize>(bytes: &[u8; B]) -> Option<Self> {
+        let mut bytes = *bytes;
+        bytes.reverse();
+        Self::from_bytes_le(&bytes)
+    }
+
+    /// Converts `bigints` to field elements, in the same order.
+    ///
+    /// Returns `None` if any of `bigints` is not lower than
+    /// [`PrimeField::MODULUS`], instead of reducing it. The conversion stops
+    /// at the first such integer.
+    #[must_use]
+    pub fn from_bigints(bigints: &[Uint<N>]) -> Option<Vec<Self>> {
+        bigints
+            .iter()
+            .map(|bigint| {
+                (!bigint.ct_ge(&P::MODULUS)).then(|| P::from_bigint(*bigint))
+            })
+            .collect()
+    }
+
+    /// Converts `bigints` to field elements, in the same order, reducing
+    /// each of them modulo [`PrimeField::MODULUS`].
+    #[must_use]
+    pub fn from_bigints_mod_order(bigints: &[Uint<N>]) -> Vec<Self> {
+        bigints.iter().map(|&bigint| Self::new(bigint)).collect()
+    }
 }
 
 impl<P: FpParams<N>, const N: usize> Debug for Fp<P, N> {
@@ -475,7 +799,9 @@ impl<P: FpParams<N>, const N: usize> Zero for Fp<P, N> {
 
     #[inline]
     fn is_zero(&self) -> bool {
-        *self == Self::ZERO
+        // Montgomery form is always reduced, so zero is represented only by
+        // zero limbs.
+        self.ct_is_zero()
     }
 }
 
@@ -487,7 +813,8 @@ impl<P: FpParams<N>, const N: usize> One for Fp<P, N> {
 
     #[inline]
     fn is_one(&self) -> bool {
-        *self == Self::ONE
+        // One in Montgomery form is `R`, so no conversion is needed.
+        self.montgomery_form.ct_eq(&P::R)
     }
 }
 
@@ -551,7 +878,11 @@ impl<P: FpParams<N>, const N: usize> PrimeField for Fp<P, N> {
     type BigInt = Uint<N>;
 
     const MODULUS: Self::BigInt = P::MODULUS;
-    const MODULUS_BIT_SIZE: usize = <Uint<N> as BigInteger>::BITS;
+    const MODULUS_BIT_SIZE: usize = P::MODULUS.ct_num_bits();
+    const MODULUS_MINUS_ONE_DIV_TWO: Self::BigInt =
+        P::MODULUS_MINUS_ONE_DIV_TWO;
+    const TWO_ADICITY: u32 = P::TWO_ADICITY;
+    const TWO_ADIC_ROOT_OF_UNITY: Self = P::TWO_ADIC_ROOT_OF_UNITY;
 
     #[inline]
     fn from_bigint(repr: Self::BigInt) -> Self {
@@ -956,6 +1287,70 @@ impl<P: FpParams<N>, const N: usize> zeroize::Zeroize for Fp<P, N> {
     }
 }
 
+/// A prime field element holding secret data, e.g. a private key or a nonce.
+///
+/// Unlike [`Fp`], the wrapped element is zeroized when dropped, so it doesn't
+/// linger in memory after use.
+/// Field operations are available through [`Deref`] to [`Fp`].
+///
+/// Plain [`Fp`] is kept as is for performance, so that zeroization is opt-in.
+#[derive(Educe)]
+#[educe(Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SecretFp<P: FpParams<N>, const N: usize>(Fp<P, N>);
+
+impl<P: FpParams<N>, const N: usize> SecretFp<P, N> {
+    /// Wrap `secret` field element to be zeroized on drop.
+    #[must_use]
+    pub const fn new(secret: Fp<P, N>) -> Self {
+        Self(secret)
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> Debug for SecretFp<P, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        // Secret value should never be printed.
+        f.write_str("SecretFp(..)")
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> From<Fp<P, N>> for SecretFp<P, N> {
+    #[inline]
+    fn from(secret: Fp<P, N>) -> Self {
+        Self::new(secret)
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> Deref for SecretFp<P, N> {
+    type Target = Fp<P, N>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> DerefMut for SecretFp<P, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> Zeroize for SecretFp<P, N> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> Drop for SecretFp<P, N> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> ZeroizeOnDrop for SecretFp<P, N> {}
+
 impl<P: FpParams<N>, const N: usize> From<Fp<P, N>> for Uint<N> {
     #[inline]
     fn from(fp: Fp<P, N>) -> Self {
@@ -995,11 +1390,14 @@ mod tests {
     use super::*;
     use crate::{
         arithmetic::uint::U64,
+        bits::{BitIteratorBE, BitIteratorLE},
         field::{
             fp::{Fp64, FpParams, LIMBS_64},
             group::AdditiveGroup,
+            instance::{FpVesta, VestaParam},
         },
-        fp_from_num, from_num,
+        fp_from_hex, fp_from_num, from_num,
+        test_helpers::iter_deterministic,
     };
 
     type Field64 = Fp64<Fp64Param>;
@@ -1011,6 +1409,119 @@ mod tests {
 
     const MODULUS: i128 = 1000003; // Prime number
 
+    #[test]
+    fn to_bits_reconstructs_value() {
+        proptest!(|(a: u64)| {
+            let a = Field64::from(a);
+            let bigint = a.into_bigint();
+
+            let from_le = a
+                .to_bits_le()
+                .enumerate()
+                .fold(0u64, |acc, (i, b)| acc | (u64::from(b) << i));
+            let from_be = a
+                .to_bits_be()
+                .fold(0u64, |acc, b| (acc << 1) | u64::from(b));
+            prop_assert_eq!(from_le, bigint.limbs[0]);
+            prop_assert_eq!(from_be, bigint.limbs[0]);
+
+            prop_assert_eq!(a.to_bits_le().count(), 64);
+            prop_assert_eq!(a.to_bits_be().count(), 64);
+            prop_assert!(a.to_bits_le().eq(bigint.bit_le_iter()));
+            prop_assert!(a.to_bits_be().eq(bigint.bit_be_iter()));
+
+            let trimmed: Vec<bool> = a.to_bits_be_trimmed().collect();
+            prop_assert!(trimmed.len() <= Field64::MODULUS_BIT_SIZE);
+            prop_assert!(trimmed.iter().rev().copied().eq(a.to_bits_le_trimmed()));
+        });
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        proptest!(|(a: u64)| {
+            let a = Field64::from(a);
+            let value = a.into_bigint().limbs[0];
+
+            let le: [u8; 8] = a.to_bytes_le();
+            let be: [u8; 8] = a.to_bytes_be();
+            prop_assert_eq!(le, value.to_le_bytes());
+            prop_assert_eq!(be, value.to_be_bytes());
+            prop_assert_eq!(Field64::from_bytes_le(&le), Some(a));
+            prop_assert_eq!(Field64::from_bytes_be(&be), Some(a));
+        });
+    }
+
+    #[test]
+    fn from_bytes_rejects_non_canonical_encodings() {
+        let modulus = Field64::MODULUS.limbs[0];
+        assert_eq!(Field64::from_bytes_le(&modulus.to_le_bytes()), None);
+        assert_eq!(Field64::from_bytes_be(&modulus.to_be_bytes()), None);
+        assert_eq!(Field64::from_bytes_le(&u64::MAX.to_le_bytes()), None);
+
+        let max = modulus - 1;
+        assert_eq!(
+            Field64::from_bytes_le(&max.to_le_bytes()),
+            Some(-Field64::ONE)
+        );
+        assert_eq!(
+            Field64::from_bytes_be(&max.to_be_bytes()),
+            Some(-Field64::ONE)
+        );
+    }
+
+    #[test]
+    fn from_u64_array_reduces_modulo_modulus() {
+        let modulus = Field64::MODULUS.limbs[0];
+        assert_eq!(Field64::from_u64_array([modulus]), Field64::ZERO);
+        assert_eq!(Field64::from_u64_array([modulus + 1]), Field64::ONE);
+        assert_eq!(
+            Field64::from_u64_array([u64::MAX]).into_bigint().limbs[0],
+            u64::MAX % modulus
+        );
+        proptest!(|(a: u64)| {
+            prop_assert_eq!(Field64::from_u64_array([a]), Field64::from(a));
+        });
+    }
+
+    #[test]
+    fn iter_deterministic_is_stable_and_canonical() {
+        let sequence: Vec<Field64> = iter_deterministic(42).take(4).collect();
+        assert_eq!(
+            sequence,
+            iter_deterministic(42).take(4).collect::<Vec<_>>()
+        );
+        assert_ne!(
+            sequence,
+            iter_deterministic(43).take(4).collect::<Vec<_>>()
+        );
+        let values: Vec<u64> =
+            sequence.iter().map(|a| a.into_bigint().limbs[0]).collect();
+        // Reference values of SplitMix64 seeded with `42`, reduced modulo
+        // `MODULUS`.
+        assert_eq!(values, [791898, 164266, 771887, 217601]);
+
+        let vesta: Vec<FpVesta> = iter_deterministic(42).take(64).collect();
+        for a in &vesta {
+            assert!(a.into_bigint() < FpVesta::MODULUS);
+        }
+        assert_eq!(
+            vesta[0],
+            fp_from_hex!("181ce1ff0e4ae39447526757130f9f5206a94a37a8d24826319047052feb6e94")
+        );
+    }
+
+    #[test]
+    fn to_bits_trimmed_len_matches_modulus_bit_size() {
+        let max = -Field64::ONE;
+        assert_eq!(max.to_bits_le_trimmed().count(), Field64::MODULUS_BIT_SIZE);
+        assert_eq!(max.to_bits_be_trimmed().count(), Field64::MODULUS_BIT_SIZE);
+        assert_eq!(Field64::ZERO.to_bits_le_trimmed().count(), 0);
+        assert_eq!(
+            Field64::ONE.to_bits_be_trimmed().collect::<Vec<_>>(),
+            [true]
+        );
+    }
+
     #[test]
     fn add() {
         proptest!(|(a: i64, b: i64)| {
@@ -1032,6 +1543,55 @@ mod tests {
         })
     }
 
+    #[test]
+    fn from_bigints() {
+        proptest!(|(limbs: Vec<u64>)| {
+            let reduced: Vec<Uint<1>> = limbs
+                .iter()
+                .map(|&limb| Uint::new([limb % Field64::MODULUS.limbs[0]]))
+                .collect();
+            let elems = Field64::from_bigints(&reduced)
+                .expect("should convert canonical integers");
+            prop_assert_eq!(elems.len(), reduced.len());
+            for (elem, bigint) in elems.iter().zip(&reduced) {
+                prop_assert_eq!(elem.into_bigint(), *bigint);
+            }
+
+            let elems = Field64::from_bigints_mod_order(
+                &limbs.iter().map(|&limb| Uint::new([limb])).collect::<Vec<_>>(),
+            );
+            for (elem, bigint) in elems.iter().zip(&reduced) {
+                prop_assert_eq!(elem.into_bigint(), *bigint);
+            }
+        })
+    }
+
+    #[test]
+    fn from_bigints_rejects_out_of_range() {
+        let modulus = Field64::MODULUS;
+        let bigints = [Uint::new([1]), modulus, Uint::new([2])];
+        assert_eq!(Field64::from_bigints(&bigints), None);
+        assert_eq!(Field64::from_bigints(&[Uint::new([u64::MAX])]), None);
+        assert_eq!(Field64::from_bigints(&[]), Some(vec![]));
+
+        assert_eq!(
+            Field64::from_bigints_mod_order(&bigints),
+            [Field64::ONE, Field64::ZERO, Field64::from(2u64)]
+        );
+    }
+
+    #[test]
+    fn small_multiples() {
+        proptest!(|(a: i64, k: u64)| {
+            let a = Field64::from(a);
+            prop_assert_eq!(a.triple(), a + a + a);
+            prop_assert_eq!(a.quadruple(), a.double().double());
+            prop_assert_eq!(a.mul_by_small(k), a * Field64::from(k));
+            prop_assert_eq!(a.mul_by_small(3), a.triple());
+            prop_assert_eq!(a.mul_by_small(0), Field64::ZERO);
+        })
+    }
+
     #[test]
     fn sub() {
         proptest!(|(a: i64, b: i64)| {
@@ -1088,6 +1648,59 @@ mod tests {
         })
     }
 
+    #[test]
+    fn inverse_or_zero() {
+        assert_eq!(Field64::ZERO.inverse_or_zero(), Field64::ZERO);
+        assert_eq!(FpVesta::ZERO.inverse_or_zero(), FpVesta::ZERO);
+        assert_eq!(Field64::ONE.inverse_or_zero(), Field64::ONE);
+        proptest!(|(a in non_zero_modulo_i64())| {
+            let a = Field64::from(a);
+            prop_assert_eq!(a.inverse_or_zero(), a.inverse().unwrap());
+        });
+        let vesta: Vec<FpVesta> = iter_deterministic(7).take(16).collect();
+        for a in vesta {
+            assert_eq!(a.inverse_or_zero(), a.inverse().unwrap());
+            assert_eq!(
+                a.pow(VestaParam::MODULUS_MINUS_TWO),
+                a.inverse().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn modulus_exponents() {
+        let modulus = Field64::MODULUS.limbs[0];
+        assert_eq!(Fp64Param::MODULUS_MINUS_TWO.limbs[0], modulus - 2);
+        assert_eq!(
+            Fp64Param::MODULUS_PLUS_ONE_DIV_FOUR.limbs[0],
+            (modulus + 1) / 4
+        );
+    }
+
+    #[test]
+    fn sqrt_fast_path_matches_tonelli_shanks() {
+        // `MODULUS % 4 == 3`, so the fast path is taken.
+        assert_eq!(Field64::MODULUS.limbs[0] % 4, 3);
+        assert_eq!(Field64::ZERO.sqrt(), Some(Field64::ZERO));
+        assert_eq!(Fp64Param::GENERATOR.sqrt(), None);
+        assert_eq!(Fp64Param::GENERATOR.sqrt_tonelli_shanks(), None);
+
+        proptest!(|(a: i64)| {
+            let a = Field64::from(a);
+            match (a.sqrt(), a.sqrt_tonelli_shanks()) {
+                (Some(fast), Some(slow)) => {
+                    prop_assert_eq!(fast.square(), a);
+                    prop_assert!(fast == slow || fast == -slow);
+                }
+                (None, None) => {}
+                roots => prop_assert!(false, "roots of {a} differ: {roots:?}"),
+            }
+
+            let root = a.square().sqrt().unwrap();
+            prop_assert!(root == a || root == -a);
+        });
+    }
+
     /// Compute a^b in an expensive and iterative way.
     fn dumb_pow(a: i128, b: i128) -> i128 {
         (0..b).fold(1, |acc, _| (acc * a).rem_euclid(MODULUS))
@@ -1114,6 +1727,43 @@ mod tests {
         })
     }
 
+    #[test]
+    fn montgomery_repr_round_trip() {
+        proptest!(|(a: u64)| {
+            let a = Field64::from(a);
+            let repr = a.to_montgomery_repr();
+
+            prop_assert_eq!(Field64::from_montgomery_repr(repr), a);
+            prop_assert_eq!(Field64::new_unchecked(repr), a);
+            prop_assert!(repr < Field64::MODULUS);
+            // `new` takes the integer itself, rather than its Montgomery form.
+            prop_assert_eq!(Field64::new(a.into_bigint()), a);
+        });
+
+        assert_eq!(Field64::ZERO.to_montgomery_repr(), U64::ZERO);
+        assert_eq!(Field64::ONE.to_montgomery_repr(), Fp64Param::R);
+        assert_ne!(Field64::new(Fp64Param::R), Field64::ONE);
+    }
+
+    #[test]
+    fn neg_zero_is_zero() {
+        use std::hash::{BuildHasher, RandomState};
+
+        // Negating zero must give the canonical zero, rather than `MODULUS`.
+        let zero = Field64::zero();
+        let neg_zero = -zero;
+
+        assert_eq!(neg_zero.into_bigint(), U64::ZERO);
+        assert!(neg_zero.is_zero());
+        assert_eq!(neg_zero, zero);
+        let state = RandomState::new();
+        assert_eq!(state.hash_one(neg_zero), state.hash_one(zero));
+
+        let mut in_place = Field64::zero();
+        in_place.neg_in_place();
+        assert_eq!(in_place, zero);
+    }
+
     #[test]
     fn one() {
         proptest!(|(a: i64)| {
@@ -1141,4 +1791,60 @@ mod tests {
             prop_assert_eq!(res, a.rem_euclid(MODULUS));
         })
     }
+
+    #[test]
+    fn is_zero_and_is_one_match_canonical_form() {
+        proptest!(|(a: i64, b: i64)| {
+            for value in [
+                Field64::from(a),
+                Field64::from(a) - Field64::from(a),
+                Field64::from(a) * Field64::from(b),
+                Field64::from(b).inverse().unwrap_or(Field64::ONE) * Field64::from(b),
+            ] {
+                let bigint = value.into_bigint();
+                prop_assert_eq!(value.is_zero(), bigint.is_zero());
+                prop_assert_eq!(value.is_one(), bigint == U64::ONE);
+            }
+        });
+
+        assert!(Field64::ZERO.is_zero());
+        assert!(Field64::from(MODULUS).is_zero());
+        assert!(!Field64::ONE.is_zero());
+        assert!(Field64::ONE.is_one());
+        assert!(Field64::from(MODULUS + 1).is_one());
+        assert!(!Field64::from(2).is_one());
+    }
+
+    #[test]
+    fn secret_derefs_to_field_ops() {
+        proptest!(|(a: i64, b: i64)| {
+            let secret = SecretFp::new(Field64::from(a));
+            let res = *secret * Field64::from(b);
+            prop_assert_eq!(res, Field64::from(a) * Field64::from(b));
+        })
+    }
+
+    #[test]
+    fn secret_is_zeroized_on_drop() {
+        let mut secret =
+            core::mem::MaybeUninit::new(SecretFp::new(Field64::from(42)));
+        assert_ne!(
+            unsafe { secret.as_ptr().cast::<Field64>().read() },
+            Field64::zero()
+        );
+
+        // SAFETY: `secret` is initialized and is not used after being dropped,
+        // except for inspecting the underlying memory.
+        unsafe { secret.assume_init_drop() };
+
+        // `SecretFp` is `repr(transparent)`, so its memory is a valid `Fp`.
+        let dropped = unsafe { secret.as_ptr().cast::<Field64>().read() };
+        assert_eq!(dropped, Field64::zero());
+    }
+
+    #[test]
+    fn secret_debug_hides_value() {
+        let secret = SecretFp::new(Field64::from(42));
+        assert_eq!(format!("{secret:?}"), "SecretFp(..)");
+    }
 }
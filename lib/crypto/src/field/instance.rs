@@ -49,3 +49,193 @@ impl FpParams<LIMBS_256> for PallasParam {
     const GENERATOR: Fp256<PallasParam> = fp_from_num!("5");
     const MODULUS: U256 = from_num!("28948022309329048855892746252171976963363056481941560715954676764349967630337");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::FpVesta;
+    use crate::{
+        arithmetic::{uint::from_str_hex, BigInteger},
+        field::{fp::Fp, prime::PrimeField, Field},
+    };
+
+    // Known-answer vectors over the Vesta base field, reduced modulo
+    // `VestaParam::MODULUS` with arbitrary precision integers.
+    #[rustfmt::skip]
+    mod vectors {
+        // (a, b, a + b, a - b, a * b)
+        pub(super) const BINARY_VECTORS: [[&str; 5]; 8] = [
+            [
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            ],
+            [
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000000",
+            ],
+            [
+                "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000000",
+                "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000000",
+                "40000000000000000000000000000000224698fc0994a8dd8c46eb20ffffffff",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+            ],
+            [
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "2000000000000000000000000000000011234c7e04ca546ec623759080000001",
+                "2000000000000000000000000000000011234c7e04ca546ec623759080000003",
+                "2000000000000000000000000000000011234c7e04ca546ec623759080000002",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+            ],
+            [
+                "157e33ba8c6fbdecc7668606cfb64d0162b771965a291abfea2067744bce48a1",
+                "033565c8b61061ebe3149115a69a647d760d8ecbdd64b1744da83d8bc5c3bd48",
+                "18b3998342801fd8aa7b171c7650b17ed8c50062378dcc3437c8a500119205e9",
+                "1248cdf1d65f5c00e451f4f1291be883eca9e2ca7cc4694b9c7829e8860a8b59",
+                "3e89cf4f259e269e47768d13fa6fca68f5daf5258e6c73111bc6cec9d29ef86b",
+            ],
+            [
+                "2e5b31ac0175af7e8dd5400f47e0a4894c5ed13c22f751c3c564d7f2c2cac605",
+                "1d4f30cf173ed1bf94647846f43b5a987cce2695874dfa9392c4f38a0807d103",
+                "0baa627b18b4813e2239b8563c1bff21a6e65ed5a0b0a379cbe2e05bcad29707",
+                "110c00dcea36ddbef970c7c853a549f0cf90aaa69ba95730329fe468bac2f502",
+                "115059a775a27eae887a684f15679f68fa08640e52fe0c4eb92f9c74b303d9b0",
+            ],
+            [
+                "34a32fdd95d9fc5bc1538eb10443ab004320f1946faa60854db9f5d79bc280bf",
+                "28f1873ec32cb6bcbde52aa2e746d34a70c631dac94e8b8b8736ab165eeb9af2",
+                "1d94b71c5906b3187f38b953eb8a7e4a91a08a732f64433348a9b5ccfaae1bb0",
+                "0bb1a89ed2ad459f036e640e1cfcd7b5d25abfb9a65bd4f9c6834ac13cd6e5cd",
+                "358ffffb2c96ca4a21eaa3aff7ff161fc61e9d49033588c615762c75c4c62bad",
+            ],
+            [
+                "37c8a394c689397488443825e77b62a69aec5b93c6bd926f5a9dbd51bdfdf19b",
+                "2540940a561f11cb7ad4063ad7cae0711f85e0193917c96b8728f958b4041c8f",
+                "1d09379f1ca84b4003183e60bf464317982ba2b0f640b2fd557fcb8972020e29",
+                "12880f8a706a27a90d7031eb0fb082357b667b7a8da5c903d374c3f909f9d50c",
+                "1c8fde71b3314e96f419b1371af0cf0feda874796378e3c0706c2d3e94552638",
+            ],
+        ];
+
+        // (a, a^2, a^-1)
+        pub(super) const UNARY_VECTORS: [[&str; 3]; 7] = [
+            [
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+            ],
+            [
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000004",
+                "2000000000000000000000000000000011234c7e04ca546ec623759080000001",
+            ],
+            [
+                "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000000",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000000",
+            ],
+            [
+                "19ffd1ff8e4e3789a9a52c393256539e7f4f5bec394a7ca64c5544a0d550c33e",
+                "22c772ba807d1a7ab3b78288258e5fe02334235767bceeb0565ffea80b9efaac",
+                "2195935875d0ad0cbdac1da93264ea6afb623ccf9430a3ac5c9548a376c1b0dd",
+            ],
+            [
+                "21a3004b0ffe43ff92ce3fb0a44b053256935d8c1b8920f2594e72cb7a047c76",
+                "3ebfb3b1a579bdfbbf3afd58507280d93393ff28b547f0b3c6faee5a7e6303ed",
+                "36762d4ff4ce4734bf55ddee95633e464db9ab45ef9e4c985bb66e4ed5a72555",
+            ],
+            [
+                "1cc5c559a66d22be59fcf85a7eab97f0dbd91fb100d6b9e0f7625f41e4ec9d00",
+                "3457f52f05f1d5ac856c5e0e2557f2e48b4829563b8a1190e40658d2d51f2204",
+                "07956e8afeceb7cba33586f9b38ef57f70c0f91a7dac81f0976abfd26d5dc9ff",
+            ],
+            [
+                "170f74ad653de7e302967d02de2839d9f537d616bc1e5b7510febe7fcf75a0b3",
+                "14b7d318bdb8ff82aa31ab60f6e409aed35f037638030716f74d9569d4a1bce6",
+                "253798d93890b4f90b2e865f4cb9eac5275c02535611f6232bb93b91ffb884f3",
+            ],
+        ];
+
+        // (a, sqrt(a))
+        pub(super) const SQRT_VECTORS: [[&str; 2]; 4] = [
+            [
+                "3c9c355b8e6eb3e2bdcfc2ac9db8691567552d57983b33796887b0cc335feb8a",
+                "152e584d57cc520a990d079b1ff6f29f6a9e74c1e647238f67b87a378127ab48",
+            ],
+            [
+                "359f6d93929a4279e808935a495d27c0278bd44440e42afb88521d0e9ef6b7bb",
+                "3ba2d0d556087a25d3f4745b32bea5e3bd9de3efaf5cf551c80d05806bfc0c50",
+            ],
+            [
+                "0d70f6282f2a3a85d548ee6b1887af994e6b88eeb5d3eae4a3e19f25da650563",
+                "29bff67b4e51a410c0722e092068d109b200f0d8bd518afe8cfe14b58abcff55",
+            ],
+            [
+                "2a9e9d4b9964e796a4e818d803fe74c26a956fa38dbfe639b9bdbc6d851c019b",
+                "2b4d3fe3a8e257431d75cbd3fbed0f84edc56a58a6a1b812251f9de1bed27038",
+            ],
+        ];
+    }
+
+    fn fp(hex: &str) -> FpVesta {
+        Fp::new(from_str_hex(hex))
+    }
+
+    #[test]
+    fn vesta_binary_ops() {
+        for [a, b, sum, diff, product] in vectors::BINARY_VECTORS {
+            let (a, b) = (fp(a), fp(b));
+            assert_eq!(a + b, fp(sum), "{a} + {b}");
+            assert_eq!(a - b, fp(diff), "{a} - {b}");
+            assert_eq!(a * b, fp(product), "{a} * {b}");
+        }
+    }
+
+    #[test]
+    fn vesta_unary_ops() {
+        assert_eq!(fp(vectors::BINARY_VECTORS[0][0]).inverse(), None);
+        for [a, square, inverse] in vectors::UNARY_VECTORS {
+            let a = fp(a);
+            assert_eq!(a.square(), fp(square), "{a}^2");
+            assert_eq!(a.inverse(), Some(fp(inverse)), "{a}^-1");
+        }
+    }
+
+    #[test]
+    fn vesta_square_roots() {
+        for [a, root] in vectors::SQRT_VECTORS {
+            let (a, root) = (fp(a), fp(root));
+            assert_eq!(root.square(), a, "sqrt({a})");
+            assert_eq!((-root).square(), a, "sqrt({a})");
+            let computed = a.sqrt().expect("should be a quadratic residue");
+            assert!(computed == root || computed == -root, "sqrt({a})");
+        }
+    }
+
+    #[test]
+    fn vesta_modulus_minus_one_div_two() {
+        let half = FpVesta::MODULUS_MINUS_ONE_DIV_TWO;
+        let (double, overflow) = half.ct_checked_add(&half);
+        assert!(!overflow);
+        assert_eq!(double.ct_add(&BigInteger::ONE), FpVesta::MODULUS);
+    }
+
+    #[test]
+    fn vesta_two_adic_root_of_unity() {
+        assert_eq!(FpVesta::TWO_ADICITY, 32);
+
+        // The root of unity has order exactly `2^TWO_ADICITY`.
+        let mut root = FpVesta::TWO_ADIC_ROOT_OF_UNITY;
+        for _ in 0..FpVesta::TWO_ADICITY - 1 {
+            assert_ne!(root, FpVesta::ONE);
+            root.square_in_place();
+        }
+        assert_eq!(root, -FpVesta::ONE);
+        assert_eq!(root.square(), FpVesta::ONE);
+    }
+}
@@ -16,6 +16,15 @@ pub trait PrimeField:
     /// The size of the modulus in bits.
     const MODULUS_BIT_SIZE: usize;
 
+    /// The value `(p - 1) / 2`.
+    const MODULUS_MINUS_ONE_DIV_TWO: Self::BigInt;
+
+    /// The largest integer `s` such that `2^s` divides `p - 1`.
+    const TWO_ADICITY: u32;
+
+    /// A primitive `2^s`-th root of unity, where `s` is [`Self::TWO_ADICITY`].
+    const TWO_ADIC_ROOT_OF_UNITY: Self;
+
     /// Returns the characteristic of the field,
     /// in little-endian representation.
     #[must_use]
@@ -35,6 +35,7 @@ pub mod keccak;
 pub mod merkle;
 pub mod poseidon2;
 
+pub use bits::{BitIteratorBE, BitIteratorLE, WnafIterator};
 pub use keccak::KeccakBuilder;
 
 #[cfg(all(test, feature = "std"))]
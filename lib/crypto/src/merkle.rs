@@ -4,12 +4,18 @@
 //! [merkle tree library](https://github.com/OpenZeppelin/merkle-tree). You will
 //! find a quickstart guide in its README.
 //!
+//! With the `std` feature, [`generate_root`], [`generate_proof`] and
+//! [`generate_multi_proof`] build the same trees and proofs from Rust, e.g.
+//! for tests and tooling.
+//!
 //! WARNING: You should avoid using leaf values that are 64 bytes long
 //! prior to hashing, or use a hash function other than keccak256 for
 //! hashing leaves. This is because the concatenation of a sorted pair
 //! of internal nodes in the Merkle tree could be reinterpreted as a
 //! leaf value. `OpenZeppelin`'s JavaScript library generates Merkle trees
 //! that are safe against this attack out of the box.
+#[cfg(feature = "std")]
+use alloc::vec;
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 
@@ -360,6 +366,162 @@ impl core::fmt::Display for MultiProofError {
     }
 }
 
+/// A multi-proof generated by [`generate_multi_proof`], to be checked with
+/// [`Verifier::verify_multi_proof`].
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiProof {
+    /// Leaves being proven, in the order expected by the verifier.
+    pub leaves: Vec<Bytes32>,
+    /// Sibling hashes needed to rebuild the root.
+    pub proof: Vec<Bytes32>,
+    /// Whether each step hashes two computed nodes rather than a `proof`
+    /// member.
+    pub proof_flags: Vec<bool>,
+}
+
+/// Returns the nodes of the complete Merkle tree of `leaves`, root first.
+///
+/// Same layout as `OpenZeppelin`'s merkle tree library: the leaves are stored
+/// in reverse order at the end, and the node at `i` is the sorted hash of
+/// its children at `2 * i + 1` and `2 * i + 2`.
+#[cfg(feature = "std")]
+fn build_tree(leaves: &[Bytes32]) -> Vec<Bytes32> {
+    assert!(!leaves.is_empty(), "should build a tree from at least one leaf");
+
+    let len = 2 * leaves.len() - 1;
+    let mut tree = vec![[0u8; 32]; len];
+    for (i, &leaf) in leaves.iter().enumerate() {
+        tree[len - 1 - i] = leaf;
+    }
+    for i in (0..len - leaves.len()).rev() {
+        tree[i] = commutative_hash_pair(
+            &tree[2 * i + 1],
+            &tree[2 * i + 2],
+            KeccakBuilder.build_hasher(),
+        );
+    }
+    tree
+}
+
+/// Returns the index of the tree node of the leaf at `index`.
+#[cfg(feature = "std")]
+fn tree_index(leaves: &[Bytes32], index: usize) -> usize {
+    assert!(index < leaves.len(), "leaf index should be in bounds");
+    2 * leaves.len() - 2 - index
+}
+
+/// Returns the index of the sibling of the tree node at `i`.
+#[cfg(feature = "std")]
+fn sibling(i: usize) -> usize {
+    if i % 2 == 0 {
+        i - 1
+    } else {
+        i + 1
+    }
+}
+
+/// Returns the root of the `keccak256` Merkle tree of `leaves`.
+///
+/// # Arguments
+///
+/// * `leaves` - Hashes of the leaves, in tree order.
+///
+/// # Panics
+///
+/// * If `leaves` is empty.
+#[cfg(feature = "std")]
+#[must_use]
+pub fn generate_root(leaves: &[Bytes32]) -> Bytes32 {
+    build_tree(leaves)[0]
+}
+
+/// Returns the proof of the leaf at `index` in the `keccak256` Merkle tree of
+/// `leaves`, to be checked with [`Verifier::verify`].
+///
+/// # Arguments
+///
+/// * `leaves` - Hashes of the leaves, in tree order.
+/// * `index` - Index of the leaf to prove in `leaves`.
+///
+/// # Panics
+///
+/// * If `index` is out of bounds for `leaves`.
+#[cfg(feature = "std")]
+#[must_use]
+pub fn generate_proof(leaves: &[Bytes32], index: usize) -> Vec<Bytes32> {
+    let tree = build_tree(leaves);
+    let mut i = tree_index(leaves, index);
+    let mut proof = Vec::new();
+    while i > 0 {
+        proof.push(tree[sibling(i)]);
+        i = (i - 1) / 2;
+    }
+    proof
+}
+
+/// Returns the multi-proof of the leaves at `indices` in the `keccak256`
+/// Merkle tree of `leaves`, to be checked with
+/// [`Verifier::verify_multi_proof`].
+///
+/// The leaves of the returned [`MultiProof`] are ordered as the verifier
+/// expects them, which may differ from the order of `indices`.
+///
+/// # Arguments
+///
+/// * `leaves` - Hashes of the leaves, in tree order.
+/// * `indices` - Indices of the leaves to prove in `leaves`.
+///
+/// # Panics
+///
+/// * If any of `indices` is out of bounds for `leaves`.
+/// * If `indices` contains duplicates.
+#[cfg(feature = "std")]
+#[must_use]
+pub fn generate_multi_proof(
+    leaves: &[Bytes32],
+    indices: &[usize],
+) -> MultiProof {
+    let tree = build_tree(leaves);
+    let mut tree_indices: Vec<usize> =
+        indices.iter().map(|&index| tree_index(leaves, index)).collect();
+    // Deepest and rightmost nodes are consumed first.
+    tree_indices.sort_unstable_by(|a, b| b.cmp(a));
+    assert!(
+        tree_indices.windows(2).all(|pair| pair[0] != pair[1]),
+        "leaf indices should not contain duplicates"
+    );
+
+    let mut queue: std::collections::VecDeque<usize> =
+        tree_indices.iter().copied().collect();
+    let mut proof = Vec::new();
+    let mut proof_flags = Vec::new();
+    while let Some(&i) = queue.front() {
+        if i == 0 {
+            break;
+        }
+        queue.pop_front();
+        let s = sibling(i);
+        if queue.front() == Some(&s) {
+            proof_flags.push(true);
+            queue.pop_front();
+        } else {
+            proof_flags.push(false);
+            proof.push(tree[s]);
+        }
+        queue.push_back((i - 1) / 2);
+    }
+    if indices.is_empty() {
+        proof.push(tree[0]);
+    }
+
+    MultiProof {
+        leaves: tree_indices.iter().map(|&i| tree[i]).collect(),
+        proof,
+        proof_flags,
+    }
+}
+
 #[cfg(all(test, feature = "std"))]
 mod tests {
     //! NOTE: The values used as input for these tests were all generated using
@@ -368,8 +530,15 @@ mod tests {
     use proptest::{prelude::*, prop_compose};
     use rand::{rng, RngCore};
 
-    use super::{Bytes32, KeccakBuilder, Verifier};
-    use crate::hash::{commutative_hash_pair, BuildHasher};
+    use super::{
+        generate_multi_proof, generate_proof, generate_root, Bytes32,
+        KeccakBuilder, Verifier,
+    };
+    use crate::{
+        field::instance::FpBN256,
+        hash::{commutative_hash_pair, BuildHasher, Hasher},
+        poseidon2::{instance::bn256::BN256Params, Poseidon2Builder},
+    };
 
     /// Shorthand for declaring variables converted from a hex literal to a
     /// fixed 32-byte slice.
@@ -630,6 +799,130 @@ mod tests {
         assert!(verification.unwrap());
     }
 
+    /// Returns the sorted leaf hashes of a `StandardMerkleTree` of single
+    /// character strings.
+    fn standard_leaves(elements: &str) -> Vec<Bytes32> {
+        let keccak = |input: &[u8]| {
+            let mut hasher = KeccakBuilder.build_hasher();
+            hasher.update(input);
+            hasher.finalize()
+        };
+        let mut leaves: Vec<Bytes32> = elements
+            .bytes()
+            .map(|element| {
+                // `abi.encode(string)` of a single character.
+                let mut encoded = [0u8; 96];
+                encoded[31] = 0x20;
+                encoded[63] = 1;
+                encoded[64] = element;
+                keccak(&keccak(&encoded))
+            })
+            .collect();
+        leaves.sort_unstable();
+        leaves
+    }
+
+    #[test]
+    fn generates_proofs_matching_merkle_tree_library() {
+        // ```js
+        // const merkleTree = StandardMerkleTree.of(toElements('abc'), ['string']);
+        //
+        // const root = merkleTree.root;
+        // const hash = merkleTree.leafHash(['a']);
+        // const proof = merkleTree.getProof(['a']);
+        // ```
+        bytes! {
+            root = "f2129b5a697531ef818f644564a6552b35c549722385bc52aa7fe46c0b5f46b1";
+            leaf_a = "9c15a6a0eaeed500fd9eed4cbeab71f797cefcc67bfd46683e4d2e6ff7f06d1c";
+        };
+        let proof = bytes_array! {
+            "19ba6c6333e0e9a15bf67523e0676e2f23eb8e574092552d5e888c64a4bb3681",
+            "9cf5a63718145ba968a01c1d557020181c5b252f665cf7386d370eddb176517b",
+        };
+
+        let leaves = standard_leaves("abc");
+        let index = leaves.iter().position(|&leaf| leaf == leaf_a).unwrap();
+        assert_eq!(root, generate_root(&leaves));
+        assert_eq!(proof.to_vec(), generate_proof(&leaves, index));
+    }
+
+    #[test]
+    fn generates_multi_proofs_matching_merkle_tree_library() {
+        // ```js
+        // const merkleTree = StandardMerkleTree.of(toElements('abcdef'), ['string']);
+        //
+        // const root = merkleTree.root;
+        // const { proof, proofFlags, leaves } = merkleTree.getMultiProof(toElements('bdf'));
+        // const hashes = leaves.map(e => merkleTree.leafHash(e));
+        // ```
+        bytes! {
+            root = "6deb52b5da8fd108f79fab00341f38d2587896634c646ee52e49f845680a70c8";
+        };
+        let hashes = bytes_array! {
+            "19ba6c6333e0e9a15bf67523e0676e2f23eb8e574092552d5e888c64a4bb3681",
+            "c62a8cfa41edc0ef6f6ae27a2985b7d39c7fea770787d7e104696c6e81f64848",
+            "eba909cf4bb90c6922771d7f126ad0fd11dfde93f3937a196274e1ac20fd2f5b",
+        };
+        let proof = bytes_array! {
+            "9a4f64e953595df82d1b4f570d34c4f4f0cfaf729a61e9d60e83e579e1aa283e",
+            "8076923e76cf01a7c048400a2304c9a9c23bbbdac3a98ea3946340fdafbba34f",
+        };
+
+        let leaves = standard_leaves("abcdef");
+        let indices: Vec<usize> = hashes
+            .iter()
+            .map(|hash| leaves.iter().position(|leaf| leaf == hash).unwrap())
+            .collect();
+        let multi_proof = generate_multi_proof(&leaves, &indices);
+
+        assert_eq!(root, generate_root(&leaves));
+        assert_eq!(hashes.to_vec(), multi_proof.leaves);
+        assert_eq!(proof.to_vec(), multi_proof.proof);
+        assert_eq!(vec![false, true, false, true], multi_proof.proof_flags);
+    }
+
+    #[test]
+    fn generated_proofs_verify() {
+        proptest!(|(
+            leaves in prop::collection::vec(any::<[u8; 32]>(), 1..64),
+            index: prop::sample::Index,
+        )| {
+            let index = index.index(leaves.len());
+            let root = generate_root(&leaves);
+            let proof = generate_proof(&leaves, index);
+            prop_assert!(Verifier::verify(&proof, root, leaves[index]));
+        });
+    }
+
+    #[test]
+    fn generated_multi_proofs_verify() {
+        proptest!(|(
+            leaves in prop::collection::vec(any::<[u8; 32]>(), 1..64),
+            selected: Vec<bool>,
+        )| {
+            let indices: Vec<usize> = (0..leaves.len())
+                .filter(|&i| selected.get(i).copied().unwrap_or_default())
+                .collect();
+            let root = generate_root(&leaves);
+            let multi_proof = generate_multi_proof(&leaves, &indices);
+            prop_assert_eq!(indices.len(), multi_proof.leaves.len());
+            let verification = Verifier::verify_multi_proof(
+                &multi_proof.proof,
+                &multi_proof.proof_flags,
+                root,
+                &multi_proof.leaves,
+            );
+            prop_assert!(verification.unwrap());
+        });
+    }
+
+    #[test]
+    #[should_panic = "leaf indices should not contain duplicates"]
+    fn generate_multi_proof_panics_on_duplicate_indices() {
+        let leaves = standard_leaves("abc");
+        _ = generate_multi_proof(&leaves, &[1, 1]);
+    }
+
     #[test]
     fn rejects_invalid_multi_proof() {
         // ```js
@@ -779,4 +1072,61 @@ mod tests {
         );
         assert!(verification.is_err());
     }
+
+    /// Builds the tree of four leaves with `builder`, checks a single proof
+    /// and a multi-proof against it, and returns its root.
+    fn four_leaves_tree_verifies<B>(builder: &B) -> Bytes32
+    where
+        B: BuildHasher,
+        B::Hasher: Hasher<Output = Bytes32>,
+    {
+        let leaves: [Bytes32; 4] = [[1; 32], [2; 32], [3; 32], [4; 32]];
+        let pair = |a: &Bytes32, b: &Bytes32| {
+            commutative_hash_pair(a, b, builder.build_hasher())
+        };
+        let left = pair(&leaves[0], &leaves[1]);
+        let right = pair(&leaves[2], &leaves[3]);
+        let root = pair(&left, &right);
+
+        assert!(Verifier::<B>::verify_with_builder(
+            &[leaves[1], right],
+            root,
+            leaves[0],
+            builder
+        ));
+        assert!(!Verifier::<B>::verify_with_builder(
+            &[leaves[2], right],
+            root,
+            leaves[0],
+            builder
+        ));
+
+        let verification = Verifier::<B>::verify_multi_proof_with_builder(
+            &[right],
+            &[true, false],
+            root,
+            &leaves[..2],
+            builder,
+        );
+        assert!(verification.unwrap());
+
+        root
+    }
+
+    #[test]
+    fn verifies_keccak_and_poseidon_trees() {
+        let keccak_root = four_leaves_tree_verifies(&KeccakBuilder);
+        assert_eq!(
+            keccak_root,
+            generate_root(&[[1; 32], [2; 32], [3; 32], [4; 32]])
+        );
+
+        let poseidon_root = four_leaves_tree_verifies(&Poseidon2Builder::<
+            BN256Params,
+            FpBN256,
+        >::new(
+            b"OZ-MERKLE-TEST"
+        ));
+        assert_ne!(keccak_root, poseidon_root);
+    }
 }
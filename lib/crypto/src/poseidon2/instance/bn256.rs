@@ -381,4 +381,41 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn permute_state() {
+        let mut state: Vec<Scalar> =
+            (0..BN256Params::T).map(|i| Scalar::from(i as u64)).collect();
+        Poseidon2::<BN256Params, _>::permute_state(&mut state);
+        assert_eq!(
+            state,
+            [
+                fp_from_hex!("0bb61d24daca55eebcb1929a82650f328134334da98ea4f847f760054f4a3033"),
+                fp_from_hex!("303b6f7c86d043bfcbcc80214f26a30277a15d3f74ca654992defe7ff8d03570"),
+                fp_from_hex!("1ed25194542b12eef8617361c3ba7c52e660b145994427cc86296242cf766ec8"),
+            ]
+        );
+    }
+
+    #[test]
+    fn sponge_matches_manual_permutation() {
+        let inputs = [Scalar::from(7u64), Scalar::from(11u64)];
+
+        let mut poseidon2 = Poseidon2::<BN256Params, _>::new();
+        poseidon2.absorb_batch(&inputs);
+        let squeezed = poseidon2.squeeze();
+
+        // Inputs are absorbed after the capacity element.
+        let mut state = vec![Scalar::ZERO; BN256Params::T];
+        state[BN256Params::CAPACITY..].copy_from_slice(&inputs);
+        Poseidon2::<BN256Params, _>::permute_state(&mut state);
+        assert_eq!(squeezed, state[BN256Params::CAPACITY]);
+    }
+
+    #[test]
+    #[should_panic = "state should have `PoseidonParams::T` elements"]
+    fn permute_state_panics_on_wrong_size() {
+        let mut state = vec![Scalar::ZERO; BN256Params::T + 1];
+        Poseidon2::<BN256Params, _>::permute_state(&mut state);
+    }
 }
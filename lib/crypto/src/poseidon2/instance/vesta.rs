@@ -372,4 +372,53 @@ mod tests {
         assert_eq!(perm[0], fp_from_hex!("2c76327e0b7653873263158cf8545c282364b183880fcdea93ca8526d518c66f"));
         assert_eq!(perm[1], fp_from_hex!("262316c0ce5244838c75873299b59d763ae0849d2dd31bdc95caf7db1c2901bf"));
     }
+
+    const DST: &[u8] = b"OZ-POSEIDON2-VESTA-TEST";
+
+    #[test]
+    fn hash_bytes_to_field() {
+        let hash = |bytes: &[u8]| {
+            Poseidon2::<VestaParams, Scalar>::hash_bytes_to_field(bytes, DST)
+        };
+        let bytes = [0xabu8; 40];
+
+        assert_eq!(Poseidon2::<VestaParams, Scalar>::bytes_per_element(), 31);
+        assert_eq!(hash(&bytes), hash(&bytes));
+
+        let mut flipped = bytes;
+        flipped[39] ^= 1;
+        assert_ne!(hash(&bytes), hash(&flipped));
+
+        // Length prefix tells trailing zero bytes apart.
+        assert_ne!(hash(&[]), hash(&[0]));
+        assert_ne!(hash(&[1]), hash(&[1, 0]));
+
+        assert_ne!(
+            hash(&bytes),
+            Poseidon2::<VestaParams, Scalar>::hash_bytes_to_field(
+                &bytes,
+                b"OTHER-DST"
+            )
+        );
+    }
+
+    #[test]
+    fn hash_bytes_to_field_kat() {
+        let hash = Poseidon2::<VestaParams, Scalar>::hash_bytes_to_field(
+            b"The quick brown fox jumps over the lazy dog",
+            DST,
+        );
+        assert_eq!(hash, fp_from_hex!("3c5806dee7f5a65ac8cf66b8eb1ee862db4092f7873006216e4f32ebbfdddeaf"));
+
+        // Length prefixes and little-endian chunks of 31 bytes.
+        let mut poseidon2 = Poseidon2::<VestaParams, _>::new();
+        poseidon2.absorb_batch(&[
+            fp_from_hex!("0000000000000000000000000000000000000000000000000000000000000017"),
+            fp_from_hex!("000000000000000000545345542d41545345562d324e4f444945534f502d5a4f"),
+            fp_from_hex!("000000000000000000000000000000000000000000000000000000000000002b"),
+            fp_from_hex!("00207265766f2073706d756a20786f66206e776f7262206b6369757120656854"),
+            fp_from_hex!("0000000000000000000000000000000000000000676f6420797a616c20656874"),
+        ]);
+        assert_eq!(hash, poseidon2.squeeze());
+    }
 }
@@ -13,7 +13,12 @@ pub mod params;
 
 use alloc::{boxed::Box, vec, vec::Vec};
 
-use crate::{field::prime::PrimeField, poseidon2::params::PoseidonParams};
+use crate::{
+    arithmetic::BigInteger,
+    field::prime::PrimeField,
+    hash::{BuildHasher, Hasher},
+    poseidon2::params::PoseidonParams,
+};
 
 /// Determines whether poseidon sponge in absorbing or squeezing state.
 /// In squeezing state, sponge can only squeeze elements.
@@ -105,6 +110,52 @@ impl<P: PoseidonParams<F>, F: PrimeField> Poseidon2<P, F> {
         }
     }
 
+    /// Number of bytes packed into a single field element by
+    /// [`Self::absorb_bytes`].
+    ///
+    /// It is the largest number of bytes always lower than the modulus, e.g.
+    /// 31 bytes for a 255 bits modulus.
+    #[must_use]
+    pub const fn bytes_per_element() -> usize {
+        (F::MODULUS_BIT_SIZE - 1) / 8
+    }
+
+    /// Absorb arbitrary `bytes` into the sponge.
+    ///
+    /// The length of `bytes` is absorbed first, followed by `bytes` split
+    /// into chunks of [`Self::bytes_per_element`] bytes. Each chunk is read as
+    /// a little-endian integer, the last one being shorter if needed, so that
+    /// every chunk is a canonical field element. The length prefix tells
+    /// apart inputs only differing by trailing zero bytes.
+    ///
+    /// # Panics
+    ///
+    /// May panic if absorbing while squeezing.
+    pub fn absorb_bytes(&mut self, bytes: &[u8]) {
+        self.absorb(&F::from(bytes.len() as u64));
+
+        let mut buf = vec![0u8; F::BigInt::BITS / 8];
+        for chunk in bytes.chunks(Self::bytes_per_element()) {
+            buf.fill(0);
+            buf[..chunk.len()].copy_from_slice(chunk);
+            self.absorb(&F::from_bigint(F::BigInt::from_bytes_le(&buf)));
+        }
+    }
+
+    /// Hash arbitrary `bytes` to a field element, under the domain separation
+    /// tag `dst`.
+    ///
+    /// Both `dst` and `bytes` are absorbed with [`Self::absorb_bytes`] into a
+    /// new sponge, then a single element is squeezed. Distinct `dst` values
+    /// make hashes of the same `bytes` unrelated across protocols.
+    #[must_use]
+    pub fn hash_bytes_to_field(bytes: &[u8], dst: &[u8]) -> F {
+        let mut sponge = Self::new();
+        sponge.absorb_bytes(dst);
+        sponge.absorb_bytes(bytes);
+        sponge.squeeze()
+    }
+
     /// Permute elements in the sponge.
     #[inline]
     pub fn permute(&mut self) {
@@ -127,6 +178,28 @@ impl<P: PoseidonParams<F>, F: PrimeField> Poseidon2<P, F> {
         }
     }
 
+    /// Apply the Poseidon2 permutation to `state` in place.
+    ///
+    /// This is the raw permutation used by the sponge, for protocols building
+    /// their own construction on top of it. Callers are responsible for the
+    /// padding of their inputs and for keeping the capacity elements out of
+    /// their outputs.
+    ///
+    /// # Panics
+    ///
+    /// * If the length of `state` is not [`Self::state_size`].
+    pub fn permute_state(state: &mut [F]) {
+        assert_eq!(
+            state.len(),
+            Self::state_size(),
+            "state should have `PoseidonParams::T` elements"
+        );
+        let mut sponge = Self::new();
+        sponge.state.copy_from_slice(state);
+        sponge.permute();
+        state.copy_from_slice(&sponge.state);
+    }
+
     /// Apply external round to the state.
     #[inline]
     fn external_round(&mut self, round: usize) {
@@ -313,3 +386,73 @@ impl<P: PoseidonParams<F>, F: PrimeField> Poseidon2<P, F> {
         self.state[0] += P::ROUND_CONSTANTS[round][0];
     }
 }
+
+/// A [`BuildHasher`] of [`Poseidon2Hasher`]s, to use Poseidon2 in place of
+/// `keccak256`, e.g. with [`crate::merkle::Verifier::verify_with_builder`].
+///
+/// Every hasher built hashes its input with
+/// [`Poseidon2::hash_bytes_to_field`] under the builder's domain separation
+/// tag.
+pub struct Poseidon2Builder<P: PoseidonParams<F>, F: PrimeField> {
+    phantom: core::marker::PhantomData<(P, F)>,
+    dst: &'static [u8],
+}
+
+impl<P: PoseidonParams<F>, F: PrimeField> Poseidon2Builder<P, F> {
+    /// Create a new builder of hashers using the domain separation tag `dst`.
+    ///
+    /// # Panics
+    ///
+    /// * If elements of `F` don't fit into 32 bytes.
+    #[must_use]
+    pub fn new(dst: &'static [u8]) -> Self {
+        assert!(
+            F::BigInt::BITS <= 256,
+            "field elements should fit into 32 bytes"
+        );
+        Self { phantom: core::marker::PhantomData, dst }
+    }
+}
+
+impl<P: PoseidonParams<F>, F: PrimeField> BuildHasher
+    for Poseidon2Builder<P, F>
+{
+    type Hasher = Poseidon2Hasher<P, F>;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Poseidon2Hasher {
+            phantom: core::marker::PhantomData,
+            dst: self.dst,
+            input: Vec::new(),
+        }
+    }
+}
+
+/// A [`Hasher`] built by [`Poseidon2Builder`].
+///
+/// The input is buffered until [`Hasher::finalize`], since
+/// [`Poseidon2::absorb_bytes`] absorbs its length first. The output is the
+/// little-endian encoding of the resulting field element.
+pub struct Poseidon2Hasher<P: PoseidonParams<F>, F: PrimeField> {
+    phantom: core::marker::PhantomData<(P, F)>,
+    dst: &'static [u8],
+    input: Vec<u8>,
+}
+
+impl<P: PoseidonParams<F>, F: PrimeField> Hasher for Poseidon2Hasher<P, F> {
+    type Output = [u8; 32];
+
+    fn update(&mut self, input: impl AsRef<[u8]>) {
+        self.input.extend_from_slice(input.as_ref());
+    }
+
+    fn finalize(self) -> Self::Output {
+        let elem =
+            Poseidon2::<P, F>::hash_bytes_to_field(&self.input, self.dst);
+        let bytes = elem.into_bigint().into_bytes_le();
+        let mut output = [0u8; 32];
+        output[..bytes.len()].copy_from_slice(&bytes);
+        output
+    }
+}
@@ -1,8 +1,32 @@
 use proptest::prelude::*;
 
+use crate::field::fp::{Fp, FpParams};
+
 pub(crate) fn non_empty_u8_vec_strategy() -> impl Strategy<Value = Vec<u8>> {
     prop::collection::vec(
         any::<u8>(),
         1..ProptestConfig::default().max_default_size_range,
     )
 }
+
+/// Returns a reproducible sequence of pseudo-random field elements derived
+/// from `seed`.
+///
+/// Limbs are drawn from a [SplitMix64] generator, then reduced with
+/// [`Fp::from_u64_array`]. Not suitable for anything but tests.
+///
+/// [SplitMix64]: https://prng.di.unimi.it/splitmix64.c
+pub(crate) fn iter_deterministic<P: FpParams<N>, const N: usize>(
+    seed: u64,
+) -> impl Iterator<Item = Fp<P, N>> {
+    let mut state = seed;
+    core::iter::repeat_with(move || {
+        Fp::from_u64_array(core::array::from_fn(|_| {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        }))
+    })
+}
@@ -1,4 +1,6 @@
-use alloy::{rpc::types::eth::TransactionReceipt, sol_types::SolEvent};
+use alloy::{
+    primitives::Log, rpc::types::eth::TransactionReceipt, sol_types::SolEvent,
+};
 
 /// Extension trait for asserting an event gets emitted.
 pub trait Ext<E> {
@@ -13,11 +15,26 @@ where
 {
     fn emits(&self, expected: E) -> bool {
         // Extract all events that are the expected type.
+        self.decode_logs::<E>().into_iter().any(|log| expected == log.data)
+    }
+}
+
+/// Extension trait for decoding emitted logs into typed events.
+pub trait Logs {
+    /// Returns all logs of the [`TransactionReceipt`] that decode as event
+    /// `E`, in emission order.
+    ///
+    /// Each decoded log keeps the address of the emitting contract.
+    fn decode_logs<E: SolEvent>(&self) -> Vec<Log<E>>;
+}
+
+impl Logs for TransactionReceipt {
+    fn decode_logs<E: SolEvent>(&self) -> Vec<Log<E>> {
         self.inner
             .logs()
             .iter()
-            .filter_map(|log| log.log_decode().ok())
-            .map(|log| log.inner.data)
-            .any(|event| expected == event)
+            .filter_map(|log| log.log_decode::<E>().ok())
+            .map(|log| log.inner)
+            .collect()
     }
 }
@@ -11,7 +11,7 @@ mod system;
 pub use account::Account;
 pub use e2e_proc::test;
 pub use error::{Panic, PanicCode, Revert};
-pub use event::Ext as EventExt;
+pub use event::{Ext as EventExt, Logs as LogsExt};
 pub use receipt::Ext as ReceiptExt;
 pub use system::{fund_account, Wallet};
 